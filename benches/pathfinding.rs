@@ -0,0 +1,191 @@
+//! Benchmarks for the searches in `resources::dungeon::pathfinding`, on rooms of 34x18 squares.
+//!
+//! The game is a binary crate, so the modules the search depends on are included by path.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[path = "../src/config.rs"]
+mod config;
+
+// The inline modules keep the `crate::` paths of the included files, while their `#[path]` points
+// the modules inside them at the game's source directories.
+#[path = "../src/plugins"]
+mod plugins {
+    pub mod combat {
+        pub mod geometry;
+    }
+    pub mod game_ui {
+        pub mod translate;
+    }
+}
+
+#[path = "../src/resources"]
+mod resources {
+    pub mod dungeon {
+        pub mod grid_occupancy;
+        pub mod grid_pos;
+        pub mod pathfinding;
+    }
+}
+
+use resources::dungeon::{
+    grid_occupancy::{GridOccupancy, Terrain},
+    grid_pos::GridPos,
+    pathfinding::{find_path, reachable, MoveBudget},
+};
+
+const ROOM_WIDTH: i32 = 34;
+const ROOM_HEIGHT: i32 = 18;
+
+/// Entities 0 and 1 are the mover and its ally. Every other creature is an enemy.
+const MOVER: u32 = 0;
+const ALLY: u32 = 1;
+
+/// A room with a wall around the edge and nothing inside it.
+fn empty_room() -> GridOccupancy {
+    let mut occupancy = GridOccupancy::default();
+    for x in 0..ROOM_WIDTH {
+        for y in 0..ROOM_HEIGHT {
+            let square = GridPos::new(x, y);
+            if x == 0 || y == 0 || x == ROOM_WIDTH - 1 || y == ROOM_HEIGHT - 1 {
+                occupancy.set_terrain(square, Terrain::Wall);
+            } else {
+                occupancy.add_floor(square);
+            }
+        }
+    }
+    occupancy
+}
+
+/// A room split by walls running across it from alternate sides, so the path has to wind back and
+/// forth through every part of the room. Monsters stand in some of the gaps and allies in others.
+fn winding_room() -> GridOccupancy {
+    let mut occupancy = empty_room();
+    for (index, x) in (4..ROOM_WIDTH - 4).step_by(4).enumerate() {
+        let gap = if index % 2 == 0 { ROOM_HEIGHT - 2 } else { 1 };
+        for y in 1..ROOM_HEIGHT - 1 {
+            if y != gap && y != gap - 1 && y != gap + 1 {
+                occupancy.set_terrain(GridPos::new(x, y), Terrain::Wall);
+            }
+        }
+        let creature = Entity::from_raw(ALLY + 1 + index as u32);
+        occupancy.place(creature, vec![GridPos::new(x + 1, gap)]);
+    }
+    occupancy.place(Entity::from_raw(ALLY), vec![GridPos::new(2, 2)]);
+    occupancy
+}
+
+/// A room scattered with patches of difficult terrain and pillars, so the cheapest path has to
+/// weigh going around them against wading through.
+fn rough_room() -> GridOccupancy {
+    let mut occupancy = empty_room();
+    for x in 1..ROOM_WIDTH - 1 {
+        for y in 1..ROOM_HEIGHT - 1 {
+            let terrain = match (x * 7 + y * 3) % 11 {
+                0..=2 => Terrain::Rubble,
+                3 => Terrain::ShallowWater,
+                5 => Terrain::Pillar,
+                _ => continue,
+            };
+            occupancy.set_terrain(GridPos::new(x, y), terrain);
+        }
+    }
+    occupancy
+}
+
+fn is_enemy(entity: Entity) -> bool {
+    entity.index() > ALLY
+}
+
+fn bench_pathfinding(c: &mut Criterion) {
+    let start = GridPos::new(1, 1);
+    let goal = GridPos::new(ROOM_WIDTH - 2, ROOM_HEIGHT - 2);
+
+    let empty = empty_room();
+    c.bench_function("find_path empty 34x18", |b| {
+        b.iter(|| {
+            find_path(
+                black_box(&empty),
+                Entity::from_raw(MOVER),
+                is_enemy,
+                start,
+                goal,
+                MoveBudget::unlimited(),
+            )
+        })
+    });
+
+    let winding = winding_room();
+    c.bench_function("find_path winding 34x18", |b| {
+        b.iter(|| {
+            find_path(
+                black_box(&winding),
+                Entity::from_raw(MOVER),
+                is_enemy,
+                start,
+                goal,
+                MoveBudget::unlimited(),
+            )
+        })
+    });
+
+    let rough = rough_room();
+    c.bench_function("find_path rough 34x18", |b| {
+        b.iter(|| {
+            find_path(
+                black_box(&rough),
+                Entity::from_raw(MOVER),
+                is_enemy,
+                start,
+                goal,
+                MoveBudget::unlimited(),
+            )
+        })
+    });
+
+    // The goal is unreachable, so every square within the budget is searched.
+    let walled_off = GridPos::new(ROOM_WIDTH - 1, ROOM_HEIGHT - 1);
+    c.bench_function("find_path unreachable 34x18", |b| {
+        b.iter(|| {
+            find_path(
+                black_box(&winding),
+                Entity::from_raw(MOVER),
+                is_enemy,
+                start,
+                walled_off,
+                MoveBudget::unlimited(),
+            )
+        })
+    });
+
+    c.bench_function("find_path double move budget 34x18", |b| {
+        b.iter(|| {
+            find_path(
+                black_box(&winding),
+                Entity::from_raw(MOVER),
+                is_enemy,
+                start,
+                goal,
+                MoveBudget::feet(60),
+            )
+        })
+    });
+
+    c.bench_function("reachable double move 34x18", |b| {
+        b.iter(|| {
+            reachable(
+                black_box(&winding),
+                Entity::from_raw(MOVER),
+                is_enemy,
+                start,
+                MoveBudget::feet(60),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, bench_pathfinding);
+criterion_main!(benches);
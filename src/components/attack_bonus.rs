@@ -11,6 +11,12 @@ pub struct AttackBonus(isize);
 #[derive(Component, Clone, Copy, Debug, Serialize, Deserialize, Deref)]
 pub struct BaseAttackBonus(isize);
 
+impl BaseAttackBonus {
+    pub fn new(val: isize) -> Self {
+        BaseAttackBonus(val)
+    }
+}
+
 impl AttackBonus {
     pub fn new() -> Self {
         AttackBonus(isize::default())
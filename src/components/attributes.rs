@@ -1,14 +1,97 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::slice::Iter;
+
+use crate::resources::glossary::{Glossary, Translation};
 
 #[derive(Bundle, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct AttributeBundle {
     strength: Strength,
     dexterity: Dexterity,
-    // constitution: Constitution,
-    // intelligence: Intelligence,
-    // wisdom: Wisdom,
-    // charisma: Charisma,
+    constitution: Constitution,
+    intelligence: Intelligence,
+    wisdom: Wisdom,
+    charisma: Charisma,
+}
+
+impl AttributeBundle {
+    /// Builds the bundle from six scores, in the order of `Ability::iterator`.
+    pub fn new(scores: [usize; 6]) -> Self {
+        let [strength, dexterity, constitution, intelligence, wisdom, charisma] = scores;
+        Self {
+            strength: Strength(strength),
+            dexterity: Dexterity(dexterity),
+            constitution: Constitution(constitution),
+            intelligence: Intelligence(intelligence),
+            wisdom: Wisdom(wisdom),
+            charisma: Charisma(charisma),
+        }
+    }
+
+    /// The six scores, in the order of `Ability::iterator`.
+    pub fn scores(&self) -> [usize; 6] {
+        [
+            *self.strength,
+            *self.dexterity,
+            *self.constitution,
+            *self.intelligence,
+            *self.wisdom,
+            *self.charisma,
+        ]
+    }
+
+    pub fn score(&self, ability: Ability) -> usize {
+        self.scores()[ability as usize]
+    }
+
+    /// The bonus of an ability score, e.g. +2 for a score of 14 or 15.
+    pub fn bonus(&self, ability: Ability) -> isize {
+        ability_bonus(self.score(ability))
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// The six ability scores, in the order they are usually listed.
+pub enum Ability {
+    Strength,
+    Dexterity,
+    Constitution,
+    Intelligence,
+    Wisdom,
+    Charisma,
+}
+
+impl Ability {
+    pub fn iterator() -> Iter<'static, Ability> {
+        [
+            Ability::Strength,
+            Ability::Dexterity,
+            Ability::Constitution,
+            Ability::Intelligence,
+            Ability::Wisdom,
+            Ability::Charisma,
+        ]
+        .iter()
+    }
+}
+
+impl Translation for Ability {
+    fn to_string_glossary(self, glossary: &Glossary) -> String {
+        let abilities = &glossary.abilities;
+        match self {
+            Ability::Strength => abilities.strength.clone(),
+            Ability::Dexterity => abilities.dexterity.clone(),
+            Ability::Constitution => abilities.constitution.clone(),
+            Ability::Intelligence => abilities.intelligence.clone(),
+            Ability::Wisdom => abilities.wisdom.clone(),
+            Ability::Charisma => abilities.charisma.clone(),
+        }
+    }
+}
+
+/// The bonus of an ability score, e.g. +2 for a score of 14 or 15.
+pub fn ability_bonus(score: usize) -> isize {
+    score as isize / 2 - 5
 }
 
 #[derive(Component, Clone, Copy, Debug, Deref, DerefMut, Serialize, Deserialize)]
@@ -19,6 +102,15 @@ impl From<Strength> for usize {
     }
 }
 impl Attribute for Strength {}
+impl Strength {
+    /// The score with any temporary adjustments applied.
+    pub fn adjusted(self, adjustments: Option<&AbilityAdjustments>) -> Self {
+        Self(AbilityAdjustments::adjust(
+            self.0,
+            adjustments.map_or(0, |adjustments| adjustments.strength),
+        ))
+    }
+}
 
 #[derive(Component, Clone, Copy, Debug, Deref, DerefMut, Serialize, Deserialize)]
 pub struct Dexterity(usize);
@@ -28,6 +120,15 @@ impl From<Dexterity> for usize {
     }
 }
 impl Attribute for Dexterity {}
+impl Dexterity {
+    /// The score with any temporary adjustments applied.
+    pub fn adjusted(self, adjustments: Option<&AbilityAdjustments>) -> Self {
+        Self(AbilityAdjustments::adjust(
+            self.0,
+            adjustments.map_or(0, |adjustments| adjustments.dexterity),
+        ))
+    }
+}
 
 #[derive(Component, Clone, Copy, Debug, Deref, DerefMut, Serialize, Deserialize)]
 pub struct Constitution(usize);
@@ -65,13 +166,27 @@ impl From<Charisma> for usize {
 }
 impl Attribute for Charisma {}
 
+#[derive(Component, Clone, Copy, Debug, Default)]
+/// Temporary changes to ability scores, such as the bonus to Strength of a rage or the penalties of
+/// fatigue. Combat uses the adjusted scores, while feat prerequisites and ability increases at
+/// level up see the scores themselves.
+pub struct AbilityAdjustments {
+    pub strength: isize,
+    pub dexterity: isize,
+}
+
+impl AbilityAdjustments {
+    fn adjust(score: usize, adjustment: isize) -> usize {
+        score.saturating_add_signed(adjustment)
+    }
+}
+
 pub trait Attribute: Clone + Copy
 where
     usize: std::convert::From<Self>,
 {
     fn bonus(&self) -> isize {
-        let i_num: isize = usize::from(*self) as isize;
-        i_num / 2 - 5
+        ability_bonus(usize::from(*self))
     }
 }
 
@@ -0,0 +1,246 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::equipment::{
+    armor::{Armor, ArmorCategory},
+    weapon::{Weapon, WeaponGroup},
+};
+
+use super::saving_throw::{Save, SaveDescriptor};
+
+#[derive(Component, Clone, Copy, Debug, Deref, PartialEq, Eq, Serialize, Deserialize)]
+/// The Fighter's bonus on Will saves against fear.
+pub struct Bravery(pub isize);
+
+impl Bravery {
+    pub fn bonus_vs(&self, save: Save, descriptors: &[SaveDescriptor]) -> isize {
+        if save == Save::Will && descriptors.contains(&SaveDescriptor::Fear) {
+            **self
+        } else {
+            0
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Deref, PartialEq, Eq, Serialize, Deserialize)]
+/// The Fighter's Armor Training rank. Each rank reduces the armor check penalty of worn armor by 1
+/// and raises its maximum Dexterity bonus by 1. At rank 1 the Fighter moves at full speed in
+/// medium armor, and at rank 2 in heavy armor.
+pub struct ArmorTraining(pub isize);
+
+impl ArmorTraining {
+    /// Shields are not affected by Armor Training.
+    pub fn armor_check_penalty(&self, armor: &Armor) -> isize {
+        if armor.is_shield() {
+            armor.armor_check_penalty()
+        } else {
+            (armor.armor_check_penalty() + **self).min(0)
+        }
+    }
+
+    pub fn max_dex(&self, armor: &Armor) -> Option<isize> {
+        if armor.is_shield() {
+            armor.max_dex()
+        } else {
+            armor.max_dex().map(|max_dex| max_dex + **self)
+        }
+    }
+
+    pub fn ignores_speed_penalty(&self, category: ArmorCategory) -> bool {
+        match category {
+            ArmorCategory::Medium => **self >= 1,
+            ArmorCategory::Heavy => **self >= 2,
+            ArmorCategory::Light | ArmorCategory::Shield => true,
+        }
+    }
+}
+
+#[derive(Component, Clone, Debug, Default, Deref, PartialEq, Eq, Serialize, Deserialize)]
+/// The weapon groups a Fighter has trained in, with the bonus on attack and damage rolls for each.
+pub struct WeaponTraining(Vec<(WeaponGroup, isize)>);
+
+impl WeaponTraining {
+    /// Groups chosen earlier have had more time to improve, so each group's bonus is one higher
+    /// than the group chosen after it.
+    pub fn new(groups: &[WeaponGroup]) -> Self {
+        let total = groups.len() as isize;
+        Self(
+            groups
+                .iter()
+                .enumerate()
+                .map(|(i, group)| (*group, total - i as isize))
+                .collect(),
+        )
+    }
+
+    /// The highest bonus of any group the weapon belongs to.
+    pub fn bonus(&self, weapon: &Weapon) -> Option<isize> {
+        self.iter()
+            .filter(|(group, _)| weapon.weapon_groups().contains(group))
+            .map(|(_, bonus)| *bonus)
+            .max()
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Default, Deref, PartialEq, Eq, Serialize, Deserialize)]
+/// The number of bonus feats the creature has gained from its class, e.g. the Fighter's bonus
+/// combat feats.
+pub struct BonusFeats(pub usize);
+
+#[derive(Component, Clone, Copy, Debug, Deref, PartialEq, Eq, Serialize, Deserialize)]
+/// The number of d6 the Rogue's sneak attack deals. Sneak attack is precision damage, added when
+/// the target is flat-footed or flanked, see `damage_modifier::add_sneak_attack`.
+pub struct SneakAttack(pub usize);
+
+#[derive(Component, Clone, Copy, Debug, Deref, PartialEq, Eq, Serialize, Deserialize)]
+/// The Rogue's bonus on checks to find and disarm traps, equal to half their level (minimum 1).
+// TODO: Apply this to Perception and Disable Device checks once creatures have skills.
+pub struct Trapfinding(pub isize);
+
+impl Trapfinding {
+    pub fn new(level: usize) -> Self {
+        Self((level as isize / 2).max(1))
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// A creature with Evasion takes no damage on a successful Reflex save against an effect which
+/// would normally deal half damage on a successful save.
+pub struct Evasion;
+
+impl Evasion {
+    /// The damage taken from an effect which deals half damage on a successful Reflex save.
+    pub fn reflex_damage(evasion: Option<&Evasion>, damage: usize, saved: bool) -> usize {
+        match (saved, evasion) {
+            (false, _) => damage,
+            (true, Some(_)) => 0,
+            (true, None) => damage / 2,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+/// The talents a Rogue picks at every even level.
+/// - `CombatTrick`: gain a bonus combat feat.
+/// - `TrapSpotter`: notice traps within 10ft without searching for them.
+pub enum RogueTalent {
+    CombatTrick,
+    TrapSpotter,
+}
+
+#[derive(Component, Clone, Debug, Default, Deref, PartialEq, Eq, Serialize, Deserialize)]
+/// The rogue talents a creature has gained.
+pub struct RogueTalents(pub Vec<RogueTalent>);
+
+impl RogueTalents {
+    /// Each Combat Trick grants one bonus feat.
+    pub fn bonus_feats(&self) -> usize {
+        self.iter()
+            .filter(|talent| **talent == RogueTalent::CombatTrick)
+            .count()
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// The Barbarian's +10ft to base speed, which is lost while wearing heavy armor.
+pub struct FastMovement;
+
+impl FastMovement {
+    /// The bonus to base speed in feet, which is added before any reduction for armor.
+    pub fn speed_bonus(wearing_heavy_armor: bool) -> usize {
+        if wearing_heavy_armor {
+            0
+        } else {
+            10
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// A creature with Uncanny Dodge keeps its Dexterity bonus to AC while flat-footed, so attacks
+/// against it never target its flat-footed AC.
+pub struct UncannyDodge;
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+/// The powers a Barbarian picks at every even level, which only apply while raging.
+/// - `RollingDodge`: a dodge bonus to AC against ranged attacks.
+/// - `Superstition`: a morale bonus on saving throws against spells.
+pub enum RagePower {
+    RollingDodge,
+    Superstition,
+}
+
+#[derive(Component, Clone, Copy, Debug, Deref, PartialEq, Eq, Serialize, Deserialize)]
+/// The dodge bonus to AC against ranged attacks from the Rolling Dodge rage power, +1 and another
+/// +1 for every 6 levels.
+pub struct RollingDodge(pub isize);
+
+impl RollingDodge {
+    pub fn new(level: usize) -> Self {
+        Self(1 + level as isize / 6)
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Deref, PartialEq, Eq, Serialize, Deserialize)]
+/// The morale bonus on saving throws against spells from the Superstition rage power, +2 and
+/// another +1 for every 4 levels.
+pub struct Superstition(pub isize);
+
+impl Superstition {
+    pub fn new(level: usize) -> Self {
+        Self(2 + level as isize / 4)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+/// The domains a Cleric chooses from their deity, each of which grants a power.
+/// - `Healing`: from 6th level, cure spells heal 50% more.
+/// - `Sun`: channeled energy which harms undead deals extra damage equal to the cleric's level.
+pub enum Domain {
+    Healing,
+    Sun,
+}
+
+#[derive(Component, Clone, Debug, Default, Deref, PartialEq, Eq, Serialize, Deserialize)]
+/// The domains a creature has gained.
+pub struct Domains(pub Vec<Domain>);
+
+impl Domains {
+    /// The healing of a cure spell after the Healing domain's power.
+    pub fn cure_amount(&self, amount: usize, level: usize) -> usize {
+        if self.contains(&Domain::Healing) && level >= 6 {
+            amount + amount / 2
+        } else {
+            amount
+        }
+    }
+
+    /// The extra damage of channeled energy against undead from the Sun domain's power.
+    pub fn channel_bonus_vs_undead(&self, level: usize) -> usize {
+        if self.contains(&Domain::Sun) {
+            level
+        } else {
+            0
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// A Cleric can spend any spell slot to cast a cure spell of the same level, whether or not it
+/// was prepared.
+pub struct SpontaneousCasting;
+
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// The choices a character makes for its class features, in the order they are made. Each choice
+/// only takes effect once the class feature has been gained.
+pub struct ClassChoices {
+    #[serde(default)]
+    pub weapon_training: Vec<WeaponGroup>,
+    #[serde(default)]
+    pub rogue_talents: Vec<RogueTalent>,
+    #[serde(default)]
+    pub rage_powers: Vec<RagePower>,
+    /// A Cleric chooses two domains.
+    #[serde(default)]
+    pub domains: Vec<Domain>,
+}
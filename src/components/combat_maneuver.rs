@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    attack_bonus::BaseAttackBonus,
+    attributes::{Attribute, Dexterity, Strength},
+    size::Size,
+};
+
+#[derive(Component, Clone, Copy, Debug, Default, Deref, DerefMut, Serialize, Deserialize)]
+/// The bonus a creature adds to its d20 roll when attempting a combat maneuver.
+/// CMB = BAB + Strength bonus + special size modifier
+pub struct CombatManeuverBonus(isize);
+
+impl CombatManeuverBonus {
+    pub fn new(bab: Option<&BaseAttackBonus>, strength: Strength, size: Size) -> Self {
+        Self(bab.map_or(0, |bab| **bab) + strength.bonus() + size.special_modifier())
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Default, Deref, DerefMut, Serialize, Deserialize)]
+/// The target number a combat maneuver must meet or beat to succeed against a creature.
+/// CMD = 10 + BAB + Strength bonus + Dexterity bonus + special size modifier
+pub struct CombatManeuverDefense(isize);
+
+impl CombatManeuverDefense {
+    pub fn new(
+        bab: Option<&BaseAttackBonus>,
+        strength: Strength,
+        dexterity: Dexterity,
+        size: Size,
+    ) -> Self {
+        Self(
+            10 + bab.map_or(0, |bab| **bab)
+                + strength.bonus()
+                + dexterity.bonus()
+                + size.special_modifier(),
+        )
+    }
+}
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize)]
+/// A creature which is flat-footed, e.g. because it has not yet acted in combat or was surprised.
+/// Attacks against a flat-footed creature target its flat-footed AC.
+pub struct FlatFooted;
+
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize)]
+/// A creature lying on the ground, e.g. after being tripped. A prone creature takes a -4 penalty
+/// on melee attack rolls and a -4 penalty to AC against melee attacks.
+pub struct Prone;
+
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize)]
+/// A creature held in a grapple. Both the grappling creature and its target are grappled, and
+/// take a -2 penalty on attack rolls.
+pub struct Grappled {
+    /// The other creature in the grapple.
+    pub with: Entity,
+}
+
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize)]
+/// An item which has been damaged, e.g. by a sunder maneuver. A broken weapon takes a -2 penalty
+/// on attack and damage rolls. A broken item which is broken again is destroyed.
+pub struct Broken;
+
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize)]
+/// A creature whose nonlethal damage equals its current hit points, or which is at exactly 0 hit
+/// points. A staggered creature can take only a single move or standard action each turn.
+pub struct Staggered;
+
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize)]
+/// A creature knocked out by nonlethal damage greater than its current hit points, or which is
+/// dying. An unconscious creature is helpless.
+pub struct Unconscious;
+
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize)]
+/// A tired creature, e.g. after a barbarian's rage ends. A fatigued creature takes a -2 penalty to
+/// Strength and Dexterity, and cannot rage.
+pub struct Fatigued;
+
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize)]
+/// A creature whose hit points have fallen to its negative death threshold.
+pub struct Dead;
@@ -6,3 +6,54 @@ use serde::{Deserialize, Serialize};
 /// This can be helpful for queries on entities that can be attacked or interacted with as
 /// creatures, as opposed to items or ground tiles.
 pub struct Creature;
+
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize)]
+/// Undead creatures are harmed by positive energy, e.g. cure spells and channeled energy, instead
+/// of being healed by it.
+pub struct Undead;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Creature subtypes which some abilities depend on, e.g. a dwarf's Hatred of orcs and goblinoids.
+pub enum Subtype {
+    Orc,
+    Goblinoid,
+    Giant,
+}
+
+#[derive(Component, Clone, Debug, Default, Deref, Serialize, Deserialize)]
+pub struct Subtypes(pub Vec<Subtype>);
+
+#[derive(Component, Clone, Copy, Debug, Deref, Serialize, Deserialize)]
+/// The base land speed of a creature in feet, before armor and class features.
+pub struct BaseSpeed(pub usize);
+
+#[derive(Component, Clone, Copy, Debug, Deref, Serialize, Deserialize)]
+/// The land speed of a creature in feet, after armor and class features. This is how far the
+/// creature can move with a single move action.
+pub struct LandSpeed(pub usize);
+
+impl LandSpeed {
+    /// Speeds are kept as a multiple of a 30ft base speed, so 20ft is 2/3 of the base speed.
+    pub fn multiplier(&self) -> f32 {
+        self.0 as f32 / 30.0
+    }
+}
+
+impl Default for LandSpeed {
+    fn default() -> Self {
+        LandSpeed(30)
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// The side a creature fights on. Creatures of the same faction are allies, e.g. for flanking.
+pub enum Faction {
+    Heroes,
+    Monsters,
+}
+
+impl Faction {
+    pub fn is_ally(self, other: Faction) -> bool {
+        self == other
+    }
+}
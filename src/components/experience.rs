@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The experience needed to reach each level on the medium advancement track, starting at level 1.
+pub const MEDIUM_XP_TRACK: [usize; 20] = [
+    0, 2_000, 5_000, 9_000, 15_000, 23_000, 35_000, 51_000, 75_000, 105_000, 155_000, 220_000,
+    315_000, 445_000, 635_000, 890_000, 1_300_000, 1_800_000, 2_550_000, 3_600_000,
+];
+
+/// The experience awarded for defeating a creature of each challenge rating, starting at CR 1.
+pub const XP_BY_CHALLENGE_RATING: [usize; 20] = [
+    400, 600, 800, 1_200, 1_600, 2_400, 3_200, 4_800, 6_400, 9_600, 12_800, 19_200, 25_600, 38_400,
+    51_200, 76_800, 102_400, 153_600, 204_800, 307_200,
+];
+
+#[derive(Component, Clone, Copy, Debug, Default, Deref, DerefMut, Serialize, Deserialize)]
+/// The experience a character has earned.
+pub struct Experience(pub usize);
+
+impl Experience {
+    /// The highest level this much experience reaches on the medium track.
+    pub fn level(&self) -> usize {
+        MEDIUM_XP_TRACK
+            .iter()
+            .take_while(|threshold| **threshold <= **self)
+            .count()
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Deref, PartialEq, Eq, Serialize, Deserialize)]
+/// How dangerous a creature is, which sets the experience for defeating it.
+// TODO: Add fractional challenge ratings for creatures weaker than CR 1.
+pub struct ChallengeRating(pub usize);
+
+impl ChallengeRating {
+    pub fn experience(&self) -> usize {
+        let index = self.0.clamp(1, XP_BY_CHALLENGE_RATING.len()) - 1;
+        XP_BY_CHALLENGE_RATING[index]
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+/// Marks a defeated creature whose experience has already been awarded.
+pub struct ExperienceAwarded;
@@ -83,3 +83,48 @@ impl ImprovedCritical {
         }
     }
 }
+
+#[derive(Component, Clone, Deref, DerefMut)]
+/// +2 damage with the chosen weapons.
+pub struct WeaponSpecialization {
+    weapons: Vec<WeaponName>,
+}
+
+impl WeaponSpecialization {
+    pub fn new(weapons: Vec<WeaponName>) -> Self {
+        Self { weapons }
+    }
+
+    pub fn bonus(&self) -> isize {
+        2
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+/// +1 dodge bonus to AC.
+pub struct Dodge;
+
+#[derive(Component, Clone, Copy, Debug)]
+/// +4 bonus on initiative checks.
+pub struct ImprovedInitiative;
+
+#[derive(Component, Clone, Copy, Debug)]
+/// +3 hit points, or +1 hit point per level once the creature has more than 3 levels.
+/// `granted_hit_points` is how many of these hit points have been added to the creature so far.
+pub struct Toughness {
+    pub granted_hit_points: usize,
+}
+
+impl Toughness {
+    pub fn bonus_hit_points(level: usize) -> usize {
+        level.max(3)
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+/// Lets the creature trade attack bonus for damage.
+pub struct PowerAttack;
+
+#[derive(Component, Clone, Copy, Debug)]
+/// Lets the creature trade attack bonus for a dodge bonus to AC.
+pub struct CombatExpertise;
@@ -1 +1,25 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::feat::{FeatName, FeatSelection};
+
 pub mod combat_feats;
+
+#[derive(Component, Clone, Debug, Default, Deref, Serialize, Deserialize)]
+/// Every feat a creature has taken. The components which enable the modifiers of each feat, e.g.
+/// `combat_feats::WeaponFocus`, are inserted from this list by `feats::apply_feats`.
+pub struct Feats(Vec<FeatSelection>);
+
+impl Feats {
+    pub fn new(feats: Vec<FeatSelection>) -> Self {
+        Self(feats)
+    }
+
+    pub fn contains(&self, name: FeatName) -> bool {
+        self.iter().any(|feat| feat.name == name)
+    }
+
+    pub fn add(&mut self, feat: FeatSelection) {
+        self.0.push(feat);
+    }
+}
@@ -1,5 +1,119 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Component, Clone, Copy, Deref, DerefMut, Serialize, Deserialize)]
-pub struct HitPoints(isize);
+use super::attributes::Constitution;
+
+/// How far below 0 hit points a creature without a Constitution score can fall before it dies.
+pub const DEATH_THRESHOLD: isize = 10;
+
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize)]
+/// The health of a creature, kept in separate buckets:
+/// - `max`: The hit points of the creature when it is unharmed.
+/// - `current`: The hit points left after lethal damage. This may fall below 0.
+/// - `nonlethal`: Nonlethal damage taken, which does not reduce `current`. The creature is
+///   staggered when this equals `current`, and unconscious when it is greater.
+/// - `temporary`: Temporary hit points, which are lost before any other hit points. Temporary hit
+///   points from different sources do not stack, so only the highest is kept.
+pub struct HitPoints {
+    max: isize,
+    current: isize,
+    #[serde(default)]
+    nonlethal: isize,
+    #[serde(default)]
+    temporary: isize,
+}
+
+impl HitPoints {
+    pub fn new(max: isize) -> Self {
+        HitPoints {
+            max,
+            current: max,
+            nonlethal: 0,
+            temporary: 0,
+        }
+    }
+
+    /// Raises maximum hit points, e.g. from the Toughness feat. Current hit points rise by the
+    /// same amount.
+    pub fn increase_max(&mut self, amount: usize) {
+        self.max += amount as isize;
+        self.current += amount as isize;
+    }
+
+    /// Lowers maximum hit points, e.g. when the bonus hit points of a rage are lost. Current hit
+    /// points fall by the same amount, which may leave the creature dying.
+    pub fn decrease_max(&mut self, amount: usize) {
+        self.max -= amount as isize;
+        self.current -= amount as isize;
+    }
+
+    /// Applies lethal or nonlethal damage, taking it from temporary hit points first. Nonlethal
+    /// damage beyond the creature's maximum hit points is dealt as lethal damage instead.
+    pub fn take_damage(&mut self, amount: usize, nonlethal: bool) {
+        let amount = amount as isize;
+        let absorbed = amount.min(self.temporary);
+        self.temporary -= absorbed;
+        let remaining = amount - absorbed;
+
+        if nonlethal {
+            let nonlethal_taken = remaining.min(self.max - self.nonlethal).max(0);
+            self.nonlethal += nonlethal_taken;
+            self.current -= remaining - nonlethal_taken;
+        } else {
+            self.current -= remaining;
+        }
+    }
+
+    /// Heals lethal damage, up to the creature's maximum hit points. Healing also removes an equal
+    /// amount of nonlethal damage.
+    pub fn heal(&mut self, amount: usize) {
+        let amount = amount as isize;
+        self.current = (self.current + amount).min(self.max);
+        self.heal_nonlethal(amount as usize);
+    }
+
+    /// Heals only nonlethal damage, e.g. from resting.
+    pub fn heal_nonlethal(&mut self, amount: usize) {
+        self.nonlethal = (self.nonlethal - amount as isize).max(0);
+    }
+
+    /// Gains temporary hit points. These do not stack with any temporary hit points the creature
+    /// already has, so only the higher amount is kept.
+    pub fn add_temporary(&mut self, amount: usize) {
+        self.temporary = self.temporary.max(amount as isize);
+    }
+
+    /// A creature dies when its hit points fall to the negative of its Constitution score.
+    pub fn state(&self, constitution: Option<&Constitution>) -> HealthState {
+        let death_threshold =
+            constitution.map_or(DEATH_THRESHOLD, |constitution| **constitution as isize);
+        if self.current <= -death_threshold {
+            HealthState::Dead
+        } else if self.current < 0 {
+            HealthState::Dying
+        } else if self.current == 0 {
+            HealthState::Disabled
+        } else if self.nonlethal > self.current {
+            HealthState::Unconscious
+        } else if self.nonlethal == self.current {
+            HealthState::Staggered
+        } else {
+            HealthState::Healthy
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The condition of a creature due to the damage it has taken.
+pub enum HealthState {
+    Healthy,
+    /// Nonlethal damage equal to current hit points.
+    Staggered,
+    /// Nonlethal damage greater than current hit points.
+    Unconscious,
+    /// Exactly 0 hit points. A disabled creature is staggered.
+    Disabled,
+    /// Below 0 hit points. A dying creature is unconscious.
+    Dying,
+    Dead,
+}
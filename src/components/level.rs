@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::hero::hero_class::HeroClass;
+
+#[derive(Component, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// The class and level of a character.
+pub struct CharacterLevel {
+    pub class: HeroClass,
+    pub level: usize,
+}
+
+impl CharacterLevel {
+    pub fn new(class: HeroClass) -> Self {
+        Self { class, level: 1 }
+    }
+}
@@ -1,9 +1,18 @@
 pub mod alignment;
-pub mod armor_class;
 pub mod attack_bonus;
 pub mod attributes;
+pub mod class_features;
+pub mod combat_maneuver;
+pub mod conditions;
 pub mod creature;
+pub mod experience;
 pub mod feats;
 pub mod hitpoints;
+pub mod level;
 pub mod player;
 pub mod player_animation;
+pub mod proficiencies;
+pub mod racial_traits;
+pub mod saving_throw;
+pub mod size;
+pub mod spell_slots;
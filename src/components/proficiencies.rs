@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::equipment::{
+    armor::{Armor, ArmorCategory, ArmorName},
+    weapon::{Proficiency, Weapon, WeaponName},
+};
+
+use super::racial_traits::WeaponFamiliarity;
+
+/// The penalty on attack rolls with a weapon the wielder is not proficient with.
+pub const NONPROFICIENT_WEAPON_PENALTY: isize = -4;
+
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// The weapons and armor a creature is trained to use, from its class and race. Creatures
+/// without `Proficiencies`, e.g. monsters, can use any gear they are given.
+pub struct Proficiencies {
+    /// Every weapon of these categories, e.g. all martial weapons.
+    #[serde(default)]
+    pub weapon_categories: Vec<Proficiency>,
+    /// Single weapons outside of those categories, e.g. the Rogue's rapier.
+    #[serde(default)]
+    pub weapons: Vec<WeaponName>,
+    /// Proficiency with shields does not include the tower shield, see `tower_shield`.
+    #[serde(default)]
+    pub armor: Vec<ArmorCategory>,
+    #[serde(default)]
+    pub tower_shield: bool,
+}
+
+impl Proficiencies {
+    /// Adds single weapon proficiencies, e.g. from the creature's race.
+    pub fn with_weapons(mut self, weapons: &[WeaponName]) -> Self {
+        for weapon in weapons {
+            if !self.weapons.contains(weapon) {
+                self.weapons.push(*weapon);
+            }
+        }
+        self
+    }
+
+    pub fn weapon(&self, weapon: &Weapon, familiarity: Option<&WeaponFamiliarity>) -> bool {
+        self.weapons.contains(&weapon.weapon_name)
+            || self
+                .weapon_categories
+                .contains(&weapon.proficiency(familiarity))
+    }
+
+    pub fn armor(&self, armor: &Armor) -> bool {
+        if armor.armor_name == ArmorName::TowerShield {
+            self.tower_shield
+        } else {
+            self.armor.contains(&armor.category)
+        }
+    }
+}
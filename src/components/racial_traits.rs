@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    plugins::combat::maneuver::CombatManeuver, resources::equipment::weapon::RacialWeapon,
+};
+
+use super::{creature::Subtype, saving_throw::SaveDescriptor};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// The traits a creature has from its race.
+pub enum RacialTrait {
+    /// An extra feat at 1st level.
+    BonusFeat,
+    /// +2 racial bonus on saving throws against enchantment spells and effects.
+    ElvenImmunities,
+    // TODO: Add a bonus to Perception once there are skills.
+    KeenSenses,
+    /// Speed is never reduced by armor.
+    SlowAndSteady,
+    /// +2 racial bonus on saving throws against poison and spells.
+    Hardy,
+    /// +4 racial bonus to CMD against bull rush and trip attempts.
+    Stability,
+    /// +1 racial bonus on attack rolls against orcs and goblinoids.
+    Hatred,
+    /// +4 dodge bonus to AC against giants.
+    DefensiveTraining,
+    /// +2 racial bonus on saving throws against fear, which stacks with Halfling Luck.
+    Fearless,
+    /// +1 racial bonus on all saving throws.
+    HalflingLuck,
+    // TODO: Let the creature fight on for one more round when brought below 0 hit points, once
+    // there is a disabled state.
+    OrcFerocity,
+}
+
+#[derive(Component, Clone, Debug, Default, Deref, Serialize, Deserialize)]
+/// The racial traits of a creature, see `RacialTrait`.
+pub struct RacialTraits(pub Vec<RacialTrait>);
+
+impl RacialTraits {
+    pub fn has(&self, racial_trait: RacialTrait) -> bool {
+        self.contains(&racial_trait)
+    }
+
+    /// The extra feat slots from the creature's race.
+    pub fn bonus_feats(&self) -> usize {
+        if self.has(RacialTrait::BonusFeat) {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// The racial bonus on a saving throw against an effect with the given descriptors.
+    pub fn save_bonus(&self, descriptors: &[SaveDescriptor]) -> isize {
+        self.iter()
+            .map(|racial_trait| match racial_trait {
+                RacialTrait::HalflingLuck => 1,
+                RacialTrait::Fearless if descriptors.contains(&SaveDescriptor::Fear) => 2,
+                RacialTrait::Hardy
+                    if descriptors.contains(&SaveDescriptor::Spell)
+                        || descriptors.contains(&SaveDescriptor::Poison) =>
+                {
+                    2
+                }
+                RacialTrait::ElvenImmunities
+                    if descriptors.contains(&SaveDescriptor::Enchantment) =>
+                {
+                    2
+                }
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// The racial bonus to CMD against a combat maneuver.
+    pub fn cmd_bonus(&self, maneuver: CombatManeuver) -> isize {
+        match maneuver {
+            CombatManeuver::BullRush | CombatManeuver::Trip if self.has(RacialTrait::Stability) => {
+                4
+            }
+            _ => 0,
+        }
+    }
+
+    /// The racial bonus on attack rolls against a creature with the given subtypes.
+    pub fn attack_bonus_vs(&self, subtypes: &[Subtype]) -> isize {
+        let hated = subtypes
+            .iter()
+            .any(|subtype| matches!(subtype, Subtype::Orc | Subtype::Goblinoid));
+        if hated && self.has(RacialTrait::Hatred) {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// The dodge bonus to AC against a creature with the given subtypes.
+    pub fn ac_bonus_vs(&self, subtypes: &[Subtype]) -> isize {
+        if subtypes.contains(&Subtype::Giant) && self.has(RacialTrait::DefensiveTraining) {
+            4
+        } else {
+            0
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Deref, Serialize, Deserialize)]
+/// The range a creature can see in the dark, in feet.
+pub struct Darkvision(pub usize);
+
+#[derive(Component, Clone, Copy, Debug, Deref, Serialize, Deserialize)]
+/// A creature with weapon familiarity treats the exotic weapons of its race as martial weapons.
+pub struct WeaponFamiliarity(pub RacialWeapon);
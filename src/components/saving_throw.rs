@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::plugins::combat::rage::{Raging, RAGE_WILL_BONUS};
+
+use super::{
+    attributes::{AbilityAdjustments, Attribute, Constitution, Dexterity, Wisdom},
+    class_features::{Bravery, Superstition},
+    racial_traits::RacialTraits,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Save {
+    Fortitude,
+    Reflex,
+    Will,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Tags on the effect a saving throw is made against, which some bonuses depend on, e.g.
+/// Bravery only applies against fear.
+pub enum SaveDescriptor {
+    Fear,
+    Trap,
+    Spell,
+    Poison,
+    Enchantment,
+}
+
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// The base saving throw bonuses of a creature, from its class progression.
+pub struct SavingThrows {
+    pub fortitude: isize,
+    pub reflex: isize,
+    pub will: isize,
+}
+
+impl SavingThrows {
+    pub fn base(&self, save: Save) -> isize {
+        match save {
+            Save::Fortitude => self.fortitude,
+            Save::Reflex => self.reflex,
+            Save::Will => self.will,
+        }
+    }
+
+    /// The total bonus on a saving throw against an effect with the given descriptors.
+    pub fn total(&self, save: Save, descriptors: &[SaveDescriptor], bonuses: SaveBonuses) -> isize {
+        let attribute_bonus = match save {
+            Save::Fortitude => {
+                let rage_bonus = if bonuses.raging.is_some() {
+                    Raging::constitution_bonus(bonuses.constitution)
+                } else {
+                    0
+                };
+                bonuses
+                    .constitution
+                    .map_or(0, |constitution| constitution.bonus())
+                    + rage_bonus
+            }
+            Save::Reflex => bonuses.dexterity.map_or(0, |dexterity| {
+                dexterity.adjusted(bonuses.adjustments).bonus()
+            }),
+            Save::Will => bonuses.wisdom.map_or(0, |wisdom| wisdom.bonus()),
+        };
+        let bravery_bonus = bonuses
+            .bravery
+            .map_or(0, |bravery| bravery.bonus_vs(save, descriptors));
+        let racial_bonus = bonuses
+            .racial_traits
+            .map_or(0, |racial_traits| racial_traits.save_bonus(descriptors));
+        self.base(save)
+            + attribute_bonus
+            + bravery_bonus
+            + racial_bonus
+            + bonuses.morale(save, descriptors)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// The components of a creature which can change its saving throws.
+pub struct SaveBonuses<'a> {
+    pub dexterity: Option<&'a Dexterity>,
+    pub adjustments: Option<&'a AbilityAdjustments>,
+    pub constitution: Option<&'a Constitution>,
+    pub wisdom: Option<&'a Wisdom>,
+    pub bravery: Option<&'a Bravery>,
+    pub raging: Option<&'a Raging>,
+    pub superstition: Option<&'a Superstition>,
+    pub racial_traits: Option<&'a RacialTraits>,
+}
+
+impl SaveBonuses<'_> {
+    /// Morale bonuses do not stack, so only the highest applies. Both of these only apply while
+    /// raging.
+    fn morale(&self, save: Save, descriptors: &[SaveDescriptor]) -> isize {
+        if self.raging.is_none() {
+            return 0;
+        }
+        let rage = if save == Save::Will {
+            RAGE_WILL_BONUS
+        } else {
+            0
+        };
+        let superstition = match self.superstition {
+            Some(superstition) if descriptors.contains(&SaveDescriptor::Spell) => **superstition,
+            _ => 0,
+        };
+        rage.max(superstition)
+    }
+}
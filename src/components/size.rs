@@ -0,0 +1,155 @@
+use bevy::{prelude::*, sprite::Anchor};
+use serde::{Deserialize, Serialize};
+
+use crate::config::TILE_SIZE;
+
+#[derive(
+    Component,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+/// The size category of a creature. Creatures without a `Size` component are treated as `Medium`.
+/// A creature's `Transform` is at the centre of the top-left square of its space, and larger
+/// creatures take up more squares to the right and below it.
+pub enum Size {
+    Fine,
+    Diminutive,
+    Tiny,
+    Small,
+    #[default]
+    Medium,
+    Large,
+    Huge,
+    Gargantuan,
+    Colossal,
+}
+
+impl Size {
+    /// The special size modifier used for combat maneuver bonus and combat maneuver defense.
+    pub fn special_modifier(self) -> isize {
+        match self {
+            Size::Fine => -8,
+            Size::Diminutive => -4,
+            Size::Tiny => -2,
+            Size::Small => -1,
+            Size::Medium => 0,
+            Size::Large => 1,
+            Size::Huge => 2,
+            Size::Gargantuan => 4,
+            Size::Colossal => 8,
+        }
+    }
+
+    /// The size modifier on attack rolls and to AC. Smaller creatures are harder to hit, and
+    /// larger creatures are easier to hit.
+    pub fn size_modifier(self) -> isize {
+        -self.special_modifier()
+    }
+
+    // TODO: Apply this to Stealth checks once creatures have skills.
+    /// The size modifier on Stealth checks.
+    #[allow(dead_code)]
+    pub fn stealth_modifier(self) -> isize {
+        match self {
+            Size::Fine => 16,
+            Size::Diminutive => 12,
+            Size::Tiny => 8,
+            Size::Small => 4,
+            Size::Medium => 0,
+            Size::Large => -4,
+            Size::Huge => -8,
+            Size::Gargantuan => -12,
+            Size::Colossal => -16,
+        }
+    }
+
+    /// The natural reach of a tall creature of this size, in feet.
+    pub fn natural_reach(self) -> usize {
+        match self {
+            Size::Fine | Size::Diminutive | Size::Tiny => 0,
+            Size::Small | Size::Medium => 5,
+            Size::Large => 10,
+            Size::Huge => 15,
+            Size::Gargantuan => 20,
+            Size::Colossal => 30,
+        }
+    }
+
+    /// The number of squares along each side of the creature's space. Creatures smaller than
+    /// Small still take up a whole square.
+    pub fn space(self) -> usize {
+        match self {
+            Size::Large => 2,
+            Size::Huge => 3,
+            Size::Gargantuan => 4,
+            Size::Colossal => 6,
+            _ => 1,
+        }
+    }
+
+    /// The centres of the squares in the space of a creature at `position`.
+    pub fn squares(self, position: Vec2) -> Vec<Vec2> {
+        let space = self.space();
+        (0..space)
+            .flat_map(|row| {
+                (0..space).map(move |column| {
+                    position + Vec2::new(column as f32 * TILE_SIZE, -(row as f32) * TILE_SIZE)
+                })
+            })
+            .collect()
+    }
+
+    /// The lower and upper corners of the space of a creature at `position`.
+    pub fn space_bounds(self, position: Vec2) -> (Vec2, Vec2) {
+        let extent = (self.space() - 1) as f32 * TILE_SIZE;
+        let half = TILE_SIZE / 2.0;
+        (
+            Vec2::new(position.x - half, position.y - extent - half),
+            Vec2::new(position.x + extent + half, position.y + half),
+        )
+    }
+
+    /// The centre of the space of a creature at `position`.
+    pub fn space_center(self, position: Vec2) -> Vec2 {
+        let (lower, upper) = self.space_bounds(position);
+        (lower + upper) / 2.0
+    }
+
+    /// The distance in pixels between the nearest squares of two creatures' spaces, counting
+    /// diagonals the same as straight lines. Adjacent creatures are `TILE_SIZE` apart, and
+    /// creatures sharing a square are 0 apart.
+    pub fn distance(self, position: Vec2, other: Size, other_position: Vec2) -> f32 {
+        let (lower, upper) = self.space_bounds(position);
+        let (other_lower, other_upper) = other.space_bounds(other_position);
+        let gap = (other_lower - upper).max(lower - other_upper);
+        (gap + Vec2::splat(TILE_SIZE)).max(Vec2::ZERO).max_element()
+    }
+
+    /// How many times larger than a Medium creature's sprite the creature's sprite is drawn.
+    pub fn sprite_scale(self) -> f32 {
+        match self {
+            Size::Fine | Size::Diminutive | Size::Tiny => 0.5,
+            _ => self.space() as f32,
+        }
+    }
+
+    /// The anchor which centres a sprite of `sprite_size` over the creature's space, with its
+    /// bottom edge in the bottom row of the space. For creatures which take up one square this is
+    /// the same as `Anchor::BottomCenter`.
+    pub fn sprite_anchor(self, sprite_size: Vec2) -> Anchor {
+        let extent = (self.space() - 1) as f32 * TILE_SIZE;
+        Anchor::Custom(Vec2::new(
+            -extent / 2.0 / sprite_size.x,
+            extent / sprite_size.y - 0.5,
+        ))
+    }
+}
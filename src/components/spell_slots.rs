@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// The spells per day of a caster for each spell level, starting at level 0, and how many of them
+/// have been cast today.
+pub struct SpellSlots {
+    per_day: Vec<usize>,
+    used: Vec<usize>,
+}
+
+impl SpellSlots {
+    pub fn new(per_day: Vec<usize>) -> Self {
+        let used = vec![0; per_day.len()];
+        Self { per_day, used }
+    }
+
+    /// Gaining a level keeps the slots already used today.
+    pub fn set_per_day(&mut self, per_day: Vec<usize>) {
+        self.used.resize(per_day.len(), 0);
+        self.per_day = per_day;
+    }
+
+    pub fn remaining(&self, level: usize) -> usize {
+        match (self.per_day.get(level), self.used.get(level)) {
+            (Some(per_day), Some(used)) => per_day.saturating_sub(*used),
+            _ => 0,
+        }
+    }
+
+    /// Uses a slot of the given level, returning false if there are none left.
+    pub fn expend(&mut self, level: usize) -> bool {
+        if self.remaining(level) > 0 {
+            self.used[level] += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -54,6 +54,8 @@ fn main() {
         .add_plugins(scenes::main_menu_scene::MainMenuScenePlugin)
         .add_plugins(scenes::game_mode_select::GameModeSelectScenePlugin)
         .add_plugins(scenes::hero_select_scene::HeroSelectScenePlugin)
+        .add_plugins(scenes::character_creation_scene::CharacterCreationScenePlugin)
+        .add_plugins(scenes::victory_scene::VictoryScenePlugin)
         .add_plugins(plugins::classic_mode::ClassicModePlugin)
         .add_plugins(plugins::player::PlayerPlugin)
         .add_plugins(plugins::input::InputHandlePlugin)
@@ -61,8 +63,14 @@ fn main() {
         .add_plugins(plugins::interact::InteractionPlugin)
         .add_plugins(plugins::monster::MonsterPlugin)
         .add_plugins(plugins::combat::CombatPlugin)
+        .add_plugins(plugins::health::HealthPlugin)
+        .add_plugins(plugins::feats::FeatPlugin)
+        .add_plugins(plugins::classes::ClassPlugin)
+        .add_plugins(plugins::spells::SpellPlugin)
+        .add_plugins(plugins::leveling::LevelingPlugin)
         .add_plugins(plugins::combat_mode::CombatModePlugin)
         .add_plugins(plugins::actions::ActionPlugin)
+        .add_plugins(plugins::item::ItemPlugin)
         .add_plugins(DebugPlugin)
         .run();
 }
@@ -22,4 +22,9 @@ pub struct DungeonMaterials {
     pub door_top_part: Handle<Image>,
     pub door_opened: Handle<Image>,
     pub door_closed: Handle<Image>,
+    pub rubble: Handle<Image>,
+    pub shallow_water: Handle<Image>,
+    pub pit: Handle<Image>,
+    pub pillar: Handle<Image>,
+    pub low_wall: Handle<Image>,
 }
@@ -9,6 +9,12 @@ pub struct HeroesMaterials {
     pub male_wizard: Handle<Image>,
     pub female_fighter: Handle<Image>,
     pub female_wizard: Handle<Image>,
+    pub male_rogue: Handle<Image>,
+    pub female_rogue: Handle<Image>,
+    pub male_barbarian: Handle<Image>,
+    pub female_barbarian: Handle<Image>,
+    pub male_cleric: Handle<Image>,
+    pub female_cleric: Handle<Image>,
 }
 
 impl HeroesMaterials {
@@ -22,6 +28,18 @@ impl HeroesMaterials {
                 Gender::Male => self.male_wizard.clone(),
                 Gender::Female => self.female_wizard.clone(),
             },
+            HeroClass::Rogue => match gender {
+                Gender::Male => self.male_rogue.clone(),
+                Gender::Female => self.female_rogue.clone(),
+            },
+            HeroClass::Barbarian => match gender {
+                Gender::Male => self.male_barbarian.clone(),
+                Gender::Female => self.female_barbarian.clone(),
+            },
+            HeroClass::Cleric => match gender {
+                Gender::Male => self.male_cleric.clone(),
+                Gender::Female => self.female_cleric.clone(),
+            },
         }
     }
 }
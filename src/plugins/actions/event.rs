@@ -1,11 +1,13 @@
 use bevy::prelude::*;
 
-use crate::plugins::game_ui::action_bar::submenu_button::SelectedSubMenu;
+use crate::plugins::game_ui::action_bar::submenu_button::MoveButton;
 
 use super::{ActionStatus, TurnAction, TurnActionStatus};
 
-#[derive(Event, Copy, Clone, Debug)]
-pub struct MoveActionEvent;
+#[derive(Event, Copy, Clone, Debug, Deref)]
+/// A move in combat has finished, using up the turn action of the `MoveButton` which was selected
+/// when the move started.
+pub struct MoveActionEvent(pub MoveButton);
 
 #[derive(Event, Copy, Clone, Debug, PartialEq, Eq)]
 pub struct TurnActionEvent {
@@ -16,13 +18,12 @@ pub struct TurnActionEvent {
 /// Read events from MoveActionEvent and send TurnActionEvent to update the status of available
 /// turn actions.
 pub fn update_move_actions(
-    selected_submenu: Res<SelectedSubMenu>,
     mut event_reader: EventReader<MoveActionEvent>,
     mut event_writer: EventWriter<TurnActionEvent>,
 ) {
-    for _event in event_reader.iter() {
+    for event in event_reader.iter() {
         event_writer.send(TurnActionEvent {
-            turn_action: TurnAction::from(selected_submenu.move_submenu),
+            turn_action: TurnAction::from(**event),
             status: TurnActionStatus::Used,
         })
     }
@@ -9,8 +9,7 @@ use crate::plugins::actions::event::update_move_actions;
 use super::{
     combat_mode::state::CombatMode,
     game_ui::{
-        action_bar::submenu_button::{MoveButton, SelectedSubMenu},
-        combat_mode::CombatModeRes,
+        action_bar::submenu_button::MoveButton, combat_mode::CombatModeRes,
         turn_actions::TurnActionButton,
     },
 };
@@ -29,9 +28,7 @@ impl Plugin for ActionPlugin {
                     setup.run_if(resource_exists_and_equals(CombatModeRes(
                         CombatMode::InCombat,
                     ))),
-                    update_move_actions
-                        .run_if(resource_exists::<SelectedSubMenu>())
-                        .run_if(on_event::<MoveActionEvent>()),
+                    update_move_actions.run_if(on_event::<MoveActionEvent>()),
                     update_turn_actions
                         .run_if(resource_exists::<ActionStatus>())
                         .run_if(on_event::<TurnActionEvent>()),
@@ -0,0 +1,196 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::{
+        attack_bonus::BaseAttackBonus,
+        attributes::{AbilityAdjustments, Attribute, Charisma, Constitution},
+        class_features::{
+            ArmorTraining, BonusFeats, Bravery, ClassChoices, Domains, Evasion, FastMovement,
+            RagePower, RollingDodge, SneakAttack, SpontaneousCasting, Superstition, Trapfinding,
+            UncannyDodge, WeaponTraining,
+        },
+        level::CharacterLevel,
+        spell_slots::SpellSlots,
+    },
+    plugins::{
+        combat::{
+            attack_damage::{
+                damage_reduction::{DRSource, DRTypes, DamageReduction},
+                damage_reduction_modifier::BarbarianDR,
+            },
+            rage::Rage,
+        },
+        spells::ChannelEnergy,
+    },
+    resources::class_progression::{ClassFeature, ClassProgressionLibrary},
+    scenes::SceneState,
+};
+
+pub struct ClassPlugin;
+
+impl Plugin for ClassPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (apply_class_progression, apply_deferred)
+                .chain()
+                .in_set(ClassProgression)
+                .run_if(in_state(SceneState::InGameClassicMode)),
+        );
+    }
+}
+
+#[derive(Clone, Hash, Debug, PartialEq, Eq, SystemSet)]
+/// Sets the base attack bonus, saving throws and class features of characters whose level has
+/// changed. Systems which read these, e.g. feat prerequisites, should run after this set.
+pub struct ClassProgression;
+
+/// Sets the base attack bonus, saving throws and class features of a character from the
+/// progression table of its class, whenever its level changes.
+pub fn apply_class_progression(
+    mut commands: Commands,
+    mut character_query: Query<
+        (
+            Entity,
+            &CharacterLevel,
+            Option<&ClassChoices>,
+            Option<&mut Rage>,
+            Option<&mut ChannelEnergy>,
+            Option<&mut SpellSlots>,
+            (Option<&Constitution>, Option<&Charisma>),
+        ),
+        Changed<CharacterLevel>,
+    >,
+    progression: Res<ClassProgressionLibrary>,
+) {
+    let debug = false;
+    for (
+        entity,
+        character_level,
+        choices,
+        rage,
+        channel_energy,
+        spell_slots,
+        (constitution, charisma),
+    ) in character_query.iter_mut()
+    {
+        let (class, level) = (&character_level.class, character_level.level);
+        let Some(entry) = progression.entry(class, level) else {
+            if debug {
+                println!(
+                    "debug | classes::apply_class_progression | no progression for {:?} level {}",
+                    class, level
+                );
+            }
+            continue;
+        };
+        let rank = |feature: ClassFeature| progression.rank(class, level, feature);
+
+        let rogue_talents = progression.rogue_talents(class, level, choices);
+        let constitution_bonus = constitution.map_or(0, |constitution| constitution.bonus());
+        let charisma_bonus = charisma.map_or(0, |charisma| charisma.bonus());
+
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.insert((
+            BaseAttackBonus::new(entry.base_attack_bonus),
+            entry.saving_throws(),
+            BonusFeats(progression.bonus_feats(class, level, choices)),
+        ));
+
+        let bravery = rank(ClassFeature::Bravery);
+        if bravery > 0 {
+            entity_commands.insert(Bravery(bravery as isize));
+        }
+        let armor_training = rank(ClassFeature::ArmorTraining);
+        if armor_training > 0 {
+            entity_commands.insert(ArmorTraining(armor_training as isize));
+        }
+        let weapon_training = rank(ClassFeature::WeaponTraining);
+        if weapon_training > 0 {
+            let chosen = choices.map_or(&[][..], |choices| &choices.weapon_training[..]);
+            entity_commands.insert(WeaponTraining::new(
+                &chosen[..weapon_training.min(chosen.len())],
+            ));
+        }
+
+        let sneak_attack = rank(ClassFeature::SneakAttack);
+        if sneak_attack > 0 {
+            entity_commands.insert(SneakAttack(sneak_attack));
+        }
+        if rank(ClassFeature::Trapfinding) > 0 {
+            entity_commands.insert(Trapfinding::new(level));
+        }
+        if rank(ClassFeature::Evasion) > 0 {
+            entity_commands.insert(Evasion);
+        }
+        if !rogue_talents.is_empty() {
+            entity_commands.insert(rogue_talents);
+        }
+
+        if rank(ClassFeature::FastMovement) > 0 {
+            entity_commands.insert(FastMovement);
+        }
+        if rank(ClassFeature::Rage) > 0 {
+            match rage {
+                Some(mut rage) => rage.set_level(level, constitution_bonus),
+                None => {
+                    entity_commands.insert((
+                        Rage::new(level, constitution_bonus),
+                        AbilityAdjustments::default(),
+                    ));
+                }
+            }
+        }
+        if rank(ClassFeature::UncannyDodge) > 0 {
+            entity_commands.insert(UncannyDodge);
+        }
+        let rage_powers = choices.map_or(&[][..], |choices| &choices.rage_powers[..]);
+        for power in rage_powers.iter().take(rank(ClassFeature::RagePower)) {
+            match power {
+                RagePower::RollingDodge => entity_commands.insert(RollingDodge::new(level)),
+                RagePower::Superstition => entity_commands.insert(Superstition::new(level)),
+            };
+        }
+        let damage_reduction = rank(ClassFeature::DamageReduction);
+        if damage_reduction > 0 {
+            entity_commands.insert(BarbarianDR::new(DamageReduction {
+                dr_val: damage_reduction,
+                reduction_source: DRSource::Barbarian,
+                damage_types: DRTypes::all(),
+                overcome: None,
+            }));
+        }
+
+        if !entry.spells_per_day.is_empty() {
+            match spell_slots {
+                Some(mut spell_slots) => spell_slots.set_per_day(entry.spells_per_day.clone()),
+                None => {
+                    entity_commands.insert(SpellSlots::new(entry.spells_per_day.clone()));
+                }
+            }
+        }
+        let channel_dice = rank(ClassFeature::ChannelEnergy);
+        if channel_dice > 0 {
+            match channel_energy {
+                Some(mut channel_energy) => channel_energy.set_level(channel_dice, charisma_bonus),
+                None => {
+                    entity_commands.insert(ChannelEnergy::new(channel_dice, charisma_bonus));
+                }
+            }
+        }
+        if rank(ClassFeature::Domains) > 0 {
+            let chosen = choices.map_or(Vec::new(), |choices| choices.domains.clone());
+            entity_commands.insert(Domains(chosen.into_iter().take(2).collect()));
+        }
+        if rank(ClassFeature::SpontaneousCasting) > 0 {
+            entity_commands.insert(SpontaneousCasting);
+        }
+
+        if debug {
+            println!(
+                "debug | classes::apply_class_progression | {:?} level {}: {:?}",
+                class, level, entry
+            );
+        }
+    }
+}
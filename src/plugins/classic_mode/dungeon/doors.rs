@@ -3,14 +3,12 @@ use bevy::prelude::*;
 use crate::config::*;
 use crate::materials::ingame::InGameMaterials;
 use crate::plugins::classic_mode::ClassicModeData;
+use crate::plugins::game_ui::translate::{START_X, START_Y};
 use crate::resources::dungeon::door::{Door, HorizontalDoor, VerticaltDoor};
 use crate::resources::dungeon::doors::Doors;
 use crate::resources::dungeon::Dungeon;
 use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
 
-const START_Y: f32 = 0.0 + WINDOW_HEIGHT / 2.0 - TILE_SIZE / 2.0;
-const START_X: f32 = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + TILE_SIZE / 2.0;
-
 pub fn doors(
     mut commands: Commands,
     ingame_materials: Res<InGameMaterials>,
@@ -1,11 +1,22 @@
 use bevy::prelude::*;
 
+use crate::components::player::PlayerComponent;
 use crate::config::*;
 use crate::materials::ingame::InGameMaterials;
+use crate::plugins::classic_mode::dungeon::navigation::{despawn_room, EnterRoomEvent};
 use crate::plugins::classic_mode::dungeon::{TOTAL_TILE_HEIGHT, TOTAL_TILE_WIDTH};
 use crate::plugins::classic_mode::ClassicModeData;
+use crate::plugins::combat::rage::Rage;
+use crate::plugins::input::movement::path_move::MovementPath;
+use crate::plugins::input::movement::Movement;
+use crate::plugins::interact::{Interactable, InteractingPos, InteractingType, InteractionActive};
 use crate::resources::dungeon::end_point::EndPoint;
+use crate::resources::dungeon::grid_occupancy::GridOccupancy;
+use crate::resources::dungeon::grid_pos::GridPos;
 use crate::resources::dungeon::Dungeon;
+use crate::resources::monster::Monster;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+use crate::scenes::SceneState;
 
 /// Spawns the end_point for a dungeon floor. By default the end_point is
 /// hidden, and can easily be made visible by
@@ -21,6 +32,9 @@ pub fn end_point(
     let center_row = TOTAL_TILE_HEIGHT / 2;
     let center_column = TOTAL_TILE_WIDTH / 2;
 
+    let x = start_x + center_column as f32 * TILE_SIZE;
+    let y = start_y - center_row as f32 * TILE_SIZE;
+
     let end_point = commands
         .spawn(SpriteBundle {
             sprite: Sprite {
@@ -28,11 +42,7 @@ pub fn end_point(
                 ..Default::default()
             },
             transform: Transform {
-                translation: Vec3::new(
-                    start_x + center_column as f32 * TILE_SIZE,
-                    start_y - center_row as f32 * TILE_SIZE,
-                    0.1,
-                ),
+                translation: Vec3::new(x, y, 0.1),
                 ..Default::default()
             },
             texture: ingame_materials.dungeon_materials.ladder.clone(),
@@ -40,23 +50,33 @@ pub fn end_point(
             ..Default::default()
         })
         .insert(EndPoint)
+        .insert(Interactable::new_from_trans(
+            Vec2::new(x - TILE_SIZE / 2.0, y - TILE_SIZE / 2.0),
+            Vec2::new(x + TILE_SIZE / 2.0, y + TILE_SIZE / 2.0),
+            InteractingType::EndPoint,
+        ))
         .insert(Name::new("EndPoint"))
         .id();
 
     data.end_point = Some(end_point);
 }
 
-/// Makes the end_point image visible when in the last room of a dungeon.
+/// Makes the end_point image visible, and lets it be clicked, when in the last room of a dungeon.
 pub fn end_point_handle_system(
-    mut query: Query<(&mut Visibility, &mut Handle<Image>), With<EndPoint>>,
+    mut query: Query<(&mut Visibility, &mut Handle<Image>, &mut Interactable), With<EndPoint>>,
     ingame_materials: Res<InGameMaterials>,
     dungeon: Res<Dungeon>,
 ) {
-    for (mut visibility, mut handle_image) in query.iter_mut() {
+    for (mut visibility, mut handle_image, mut interactable) in query.iter_mut() {
         let current_position = dungeon.current_floor.current_position;
         let end_room_position = dungeon.current_floor.end_room_position;
 
-        if end_room_position == current_position {
+        let in_end_room = end_room_position == current_position;
+        if interactable.active != in_end_room {
+            interactable.active = in_end_room;
+        }
+
+        if in_end_room {
             *visibility = Visibility::Visible;
             if dungeon.current_floor.is_last_floor {
                 *handle_image = ingame_materials.dungeon_materials.treasure.clone();
@@ -68,3 +88,89 @@ pub fn end_point_handle_system(
         }
     }
 }
+
+/// Uses the end_point when the player clicks it while standing on or next to it. The ladder takes
+/// the player down to the start room of the next floor, keeping the hero as they are apart from a
+/// rest which restores the rounds of rage, and the treasure on the last floor wins the run.
+pub fn use_end_point(
+    mut commands: Commands,
+    button: Res<Input<MouseButton>>,
+    interacting_pos: Res<InteractingPos>,
+    interaction_active: Res<InteractionActive>,
+    mut player_query: Query<(&mut Transform, Option<&mut Rage>), With<PlayerComponent>>,
+    end_point_query: Query<&Transform, (With<EndPoint>, Without<PlayerComponent>)>,
+    monster_query: Query<Entity, With<Monster>>,
+    movement: Res<Movement>,
+    movement_path: Option<Res<MovementPath>>,
+    mut dungeon: ResMut<Dungeon>,
+    mut player_dungeon_stats: ResMut<PlayerDungeonStats>,
+    mut occupancy: ResMut<GridOccupancy>,
+    data: Res<ClassicModeData>,
+    mut state: ResMut<NextState<SceneState>>,
+    mut event_writer: EventWriter<EnterRoomEvent>,
+) {
+    let debug = false;
+    if !button.just_pressed(MouseButton::Left)
+        || **interaction_active
+        || interacting_pos.interacting_type != InteractingType::EndPoint
+        || movement.moving
+        || movement_path.is_some()
+    {
+        return;
+    }
+    let (Ok((mut player_transform, rage)), Ok(end_point_transform)) =
+        (player_query.get_single_mut(), end_point_query.get_single())
+    else {
+        return;
+    };
+    let player_square = GridPos::from_world(player_transform.translation.truncate());
+    let end_point_square = GridPos::from_world(end_point_transform.translation.truncate());
+    let offset = end_point_square - player_square;
+    if offset.x.abs() > 1 || offset.y.abs() > 1 {
+        return;
+    }
+
+    if dungeon.current_floor.is_last_floor {
+        if debug {
+            println!("debug | end_point::use_end_point | treasure reached, the run is won");
+        }
+        state.set(SceneState::VictoryScene);
+        return;
+    }
+
+    let next_floor_index = player_dungeon_stats.current_floor_index + 1;
+    let Some(next_floor) = dungeon.floors.get(next_floor_index).cloned() else {
+        if debug {
+            println!(
+                "debug | end_point::use_end_point | no floor after floor {}",
+                player_dungeon_stats.current_floor_index
+            );
+        }
+        return;
+    };
+    if debug {
+        println!(
+            "debug | end_point::use_end_point | down to floor {}: {}",
+            next_floor_index, next_floor.map_name
+        );
+    }
+    let start_position = next_floor.start_room_position;
+    player_dungeon_stats.current_floor_index = next_floor_index;
+    player_dungeon_stats.current_room_position = start_position;
+    player_dungeon_stats.is_room_cleared =
+        next_floor.cleared_positions.contains_key(&start_position);
+    dungeon.current_floor = next_floor;
+
+    despawn_room(&mut commands, &data, &monster_query, &mut occupancy);
+
+    // The player arrives at the foot of the ladder, in the centre of the room.
+    let arrival =
+        GridPos::new(TOTAL_TILE_WIDTH as i32 / 2, TOTAL_TILE_HEIGHT as i32 / 2).to_world();
+    player_transform.translation.x = arrival.x;
+    player_transform.translation.y = arrival.y;
+    if let Some(mut rage) = rage {
+        rage.refresh();
+    }
+
+    event_writer.send(EnterRoomEvent);
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bevy::utils::HashSet;
 
 use crate::config::*;
 use crate::materials::ingame::InGameMaterials;
@@ -6,6 +7,7 @@ use crate::plugins::classic_mode::dungeon::{TOTAL_TILE_HEIGHT, TOTAL_TILE_WIDTH}
 use crate::plugins::classic_mode::ClassicModeData;
 use crate::plugins::input::movement::map::MapGrid;
 use crate::plugins::interact::{Interactable, InteractingType};
+use crate::resources::dungeon::grid_pos::GridPos;
 use crate::resources::dungeon::grid_square::GridSquare;
 use crate::resources::dungeon::ground::Ground;
 use crate::resources::dungeon::layer::Layer;
@@ -16,10 +18,8 @@ pub fn ground(
     mut data: ResMut<ClassicModeData>,
 ) {
     let tile_offset = TILE_SIZE / 2.0;
-    let start_y: f32 = 0.0 + WINDOW_HEIGHT / 2.0 - tile_offset;
-    let start_x: f32 = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + tile_offset;
 
-    let mut grid_positions: Vec<Vec2> = vec![];
+    let mut grid_positions: HashSet<GridPos> = HashSet::new();
 
     let ground = commands
         .spawn(SpriteBundle {
@@ -34,15 +34,13 @@ pub fn ground(
             for row_index in 0..TOTAL_TILE_HEIGHT {
                 for column_index in 0..TOTAL_TILE_WIDTH {
                     if row_index >= 1 && column_index > 0 && column_index < 15 {
-                        // let offset_x = column_index as f32 * TILE_SIZE;
-                        // let offset_y = row_index as f32 * TILE_SIZE;
-                        let x = start_x + column_index as f32 * TILE_SIZE;
-                        let y = start_y - row_index as f32 * TILE_SIZE;
+                        let grid_pos = GridPos::new(column_index as i32, row_index as i32);
+                        let Vec2 { x, y } = grid_pos.to_world();
 
                         let box_lower_tr: Vec2 = Vec2::new(x - tile_offset, y - tile_offset);
                         let box_upper_tr: Vec2 = Vec2::new(x + tile_offset, y + tile_offset);
 
-                        grid_positions.push(Vec2::new(x, y));
+                        grid_positions.insert(grid_pos);
 
                         parent
                             .spawn(SpriteBundle {
@@ -71,6 +69,7 @@ pub fn ground(
                             })
                             .insert(Layer)
                             .insert(GridSquare)
+                            .insert(grid_pos)
                             .insert(Interactable::new_from_trans(
                                 box_lower_tr,
                                 box_upper_tr,
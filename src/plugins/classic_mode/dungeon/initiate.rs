@@ -2,6 +2,7 @@ use bevy::prelude::*;
 
 use crate::config::*;
 use crate::plugins::classic_mode::ClassicModeData;
+use crate::resources::dungeon::grid_occupancy::GridOccupancy;
 use crate::resources::dungeon::Dungeon;
 use crate::resources::game_mode::GameMode;
 use crate::resources::monster::monster_spawn_controller::MonsterSpawnController;
@@ -17,11 +18,14 @@ pub fn initiate_classic_mode(mut commands: Commands, mut state: ResMut<NextState
         // is_room_cleared: true,
         is_room_cleared: false,
         current_floor_index: 0,
+        rooms_cleared: 0,
+        monsters_defeated: 0,
     };
 
     let classic_mode_data = ClassicModeData {
         doors: None,
         walls: None,
+        terrain: None,
         ground: None,
         end_point: None,
     };
@@ -50,6 +54,7 @@ pub fn initiate_classic_mode(mut commands: Commands, mut state: ResMut<NextState
     commands.insert_resource(player_dungeon_stats);
     commands.insert_resource(classic_mode_data);
     commands.insert_resource(monster_spawn_controller);
+    commands.insert_resource(GridOccupancy::default());
 
     state.set(SceneState::InGameClassicMode);
 }
@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+use crate::plugins::combat::concealment::Lighting;
+use crate::resources::dungeon::rooms::Rooms;
+use crate::resources::dungeon::Dungeon;
+
+/// Sets the light level to the one of the room at the current position.
+pub fn lighting(dungeon: Res<Dungeon>, rooms: Res<Rooms>, mut lighting: ResMut<Lighting>) {
+    let current_floor = &dungeon.current_floor;
+    let current_position = current_floor.current_position;
+
+    let room_id = current_floor.map[current_position.row_index][current_position.column_index];
+
+    *lighting = rooms.get_room(room_id).lighting;
+}
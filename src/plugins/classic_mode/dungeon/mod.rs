@@ -2,6 +2,10 @@ pub mod doors;
 pub mod end_point;
 pub mod ground;
 pub mod initiate;
+pub mod lighting;
+pub mod navigation;
+pub mod occupancy;
+pub mod terrain;
 pub mod walls;
 
 pub const TOTAL_TILE_WIDTH: usize = 16;
@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+
+use crate::components::conditions::Dead;
+use crate::components::player::PlayerComponent;
+use crate::plugins::classic_mode::ClassicModeData;
+use crate::plugins::input::movement::path_move::MovementPath;
+use crate::plugins::input::movement::Movement;
+use crate::resources::dungeon::door::Door;
+use crate::resources::dungeon::grid_occupancy::GridOccupancy;
+use crate::resources::dungeon::grid_pos::GridPos;
+use crate::resources::dungeon::Dungeon;
+use crate::resources::monster::Monster;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+
+#[derive(Event, Clone, Copy, Debug)]
+/// Sent when the player moves into another room, through a door or down to a new floor, once the
+/// floor has moved on to it. The ground, walls, terrain and monsters of the new room are spawned
+/// in response.
+pub struct EnterRoomEvent;
+
+/// Despawns the ground, walls, terrain and monsters of the current room, so that the next room
+/// can be spawned in its place. The player is kept as they are.
+pub fn despawn_room(
+    commands: &mut Commands,
+    data: &ClassicModeData,
+    monster_query: &Query<Entity, With<Monster>>,
+    occupancy: &mut GridOccupancy,
+) {
+    for entity in [data.ground, data.walls, data.terrain]
+        .into_iter()
+        .flatten()
+    {
+        commands.entity(entity).despawn_recursive();
+    }
+    for monster in monster_query.iter() {
+        commands.entity(monster).despawn_recursive();
+    }
+    occupancy.clear_terrain();
+}
+
+/// Moves the player into the next room when they stop in the doorway of an open door. The
+/// current room is despawned, and the player is placed inside the opposite door of the new room.
+pub fn walk_through_doors(
+    mut commands: Commands,
+    mut player_query: Query<&mut Transform, With<PlayerComponent>>,
+    monster_query: Query<Entity, With<Monster>>,
+    movement: Res<Movement>,
+    movement_path: Option<Res<MovementPath>>,
+    mut dungeon: ResMut<Dungeon>,
+    mut player_dungeon_stats: ResMut<PlayerDungeonStats>,
+    mut occupancy: ResMut<GridOccupancy>,
+    data: Res<ClassicModeData>,
+    mut event_writer: EventWriter<EnterRoomEvent>,
+) {
+    let debug = false;
+    // Doors are only open once the room is cleared.
+    if !player_dungeon_stats.is_room_cleared || movement.moving || movement_path.is_some() {
+        return;
+    }
+    let Ok(mut transform) = player_query.get_single_mut() else {
+        return;
+    };
+    let square = GridPos::from_world(transform.translation.truncate());
+    let Some((door, next_position)) = Door::iterator()
+        .filter(|door| door.threshold().contains(&square))
+        .find_map(|door| {
+            dungeon
+                .current_floor
+                .room_through(door)
+                .map(|position| (door.clone(), position))
+        })
+    else {
+        return;
+    };
+
+    let floor = &mut dungeon.current_floor;
+    floor.current_position = next_position;
+    player_dungeon_stats.current_room_position = next_position;
+    player_dungeon_stats.is_room_cleared = floor.cleared_positions.contains_key(&next_position);
+    if debug {
+        println!(
+            "debug | navigation::walk_through_doors | {:?} door to {:?}, cleared: {}",
+            door, next_position, player_dungeon_stats.is_room_cleared
+        );
+    }
+
+    despawn_room(&mut commands, &data, &monster_query, &mut occupancy);
+
+    let arrival = door.arrival(square).to_world();
+    transform.translation.x = arrival.x;
+    transform.translation.y = arrival.y;
+
+    event_writer.send(EnterRoomEvent);
+}
+
+/// Counts the monsters the player defeats, and marks the current room as cleared once its last
+/// monster dies, which opens the doors. Cleared rooms are remembered in
+/// `Floor::cleared_positions`, so their monsters are not spawned again.
+pub fn check_room_cleared(
+    killed_query: Query<(), (With<Monster>, Added<Dead>)>,
+    alive_query: Query<(), (With<Monster>, Without<Dead>)>,
+    mut dungeon: ResMut<Dungeon>,
+    mut player_dungeon_stats: ResMut<PlayerDungeonStats>,
+) {
+    let killed = killed_query.iter().count();
+    if killed == 0 {
+        return;
+    }
+    player_dungeon_stats.monsters_defeated += killed;
+    if player_dungeon_stats.is_room_cleared || !alive_query.is_empty() {
+        return;
+    }
+    let floor = &mut dungeon.current_floor;
+    let cleared = floor.cleared_positions.len();
+    floor
+        .cleared_positions
+        .entry(floor.current_position)
+        .or_insert(cleared);
+    player_dungeon_stats.is_room_cleared = true;
+    player_dungeon_stats.rooms_cleared += 1;
+}
+
+/// Whether the player is in a room which has been cleared, so no monsters should be spawned.
+pub fn room_cleared(player_dungeon_stats: Option<Res<PlayerDungeonStats>>) -> bool {
+    player_dungeon_stats.is_some_and(|stats| stats.is_room_cleared)
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::{creature::Creature, size::Size},
+    resources::dungeon::{
+        block_type::BlockType,
+        grid_occupancy::{GridOccupancy, Terrain},
+        grid_pos::GridPos,
+        grid_square::GridSquare,
+    },
+};
+
+/// Adds the floor, wall and terrain feature squares of the room to the `GridOccupancy` as they
+/// are spawned.
+pub fn track_terrain(
+    mut occupancy: ResMut<GridOccupancy>,
+    ground_query: Query<&GridPos, Added<GridSquare>>,
+    block_query: Query<(&BlockType, &Transform), Added<BlockType>>,
+    terrain_query: Query<(&Terrain, &GridPos), Added<Terrain>>,
+) {
+    for grid_pos in ground_query.iter() {
+        occupancy.add_floor(*grid_pos);
+    }
+    for (terrain, grid_pos) in terrain_query.iter() {
+        occupancy.set_terrain(*grid_pos, *terrain);
+    }
+    for (block_type, transform) in block_query.iter() {
+        if let Some(square) = block_type.blocked_square(transform.translation) {
+            occupancy.set_terrain(GridPos::from_world(square), Terrain::Wall);
+        }
+    }
+}
+
+/// Moves creatures in the `GridOccupancy` whenever they move or change size.
+pub fn track_creatures(
+    mut occupancy: ResMut<GridOccupancy>,
+    creature_query: Query<
+        (Entity, &Transform, Option<&Size>),
+        (With<Creature>, Or<(Changed<Transform>, Changed<Size>)>),
+    >,
+) {
+    let debug = false;
+    for (entity, transform, size) in creature_query.iter() {
+        let squares: Vec<GridPos> = size
+            .copied()
+            .unwrap_or_default()
+            .squares(transform.translation.truncate())
+            .into_iter()
+            .map(GridPos::from_world)
+            .collect();
+        if debug {
+            println!(
+                "debug | occupancy::track_creatures | {:?} takes up {:?}",
+                entity, squares
+            );
+        }
+        occupancy.place(entity, squares);
+    }
+}
+
+/// Removes creatures from the `GridOccupancy` when they are despawned.
+pub fn remove_creatures(
+    mut occupancy: ResMut<GridOccupancy>,
+    mut removed: RemovedComponents<Creature>,
+) {
+    for entity in removed.iter() {
+        occupancy.remove(entity);
+    }
+}
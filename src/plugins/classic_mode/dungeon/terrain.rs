@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+
+use crate::config::*;
+use crate::materials::ingame::InGameMaterials;
+use crate::plugins::classic_mode::ClassicModeData;
+use crate::resources::dungeon::grid_occupancy::Terrain;
+use crate::resources::dungeon::grid_pos::GridPos;
+use crate::resources::dungeon::rooms::Rooms;
+use crate::resources::dungeon::Dungeon;
+
+/// Above the floor tiles and below the reachable squares overlay.
+const TERRAIN_Z: f32 = 0.05;
+
+/// Spawns the terrain features of the room, such as rubble and pillars, on top of the floor.
+/// Each one has a `Terrain` and a `GridPos`, which `occupancy::track_terrain` adds to the
+/// `GridOccupancy`.
+pub fn terrain(
+    mut commands: Commands,
+    dungeon: Res<Dungeon>,
+    rooms: Res<Rooms>,
+    ingame_materials: Res<InGameMaterials>,
+    mut data: ResMut<ClassicModeData>,
+) {
+    let current_floor = &dungeon.current_floor;
+    let current_position = current_floor.current_position;
+    let room_id = current_floor.map[current_position.row_index][current_position.column_index];
+    let room = rooms.get_room(room_id);
+
+    let terrain = commands
+        .spawn(SpatialBundle::default())
+        .with_children(|parent| {
+            for (row_index, row) in room.tilemap.iter().enumerate() {
+                for (column_index, value) in row.iter().enumerate() {
+                    if let Some(terrain) = Terrain::from_tile(*value) {
+                        let grid_pos = GridPos::new(column_index as i32, row_index as i32);
+                        terrain_feature(parent, grid_pos, terrain, &ingame_materials);
+                    }
+                }
+            }
+        })
+        .insert(Name::new("Terrain"))
+        .id();
+
+    data.terrain = Some(terrain);
+}
+
+fn terrain_feature(
+    parent: &mut ChildBuilder,
+    grid_pos: GridPos,
+    terrain: Terrain,
+    ingame_materials: &InGameMaterials,
+) {
+    let dungeon_materials = &ingame_materials.dungeon_materials;
+    let image = match terrain {
+        Terrain::Rubble => dungeon_materials.rubble.clone(),
+        Terrain::ShallowWater => dungeon_materials.shallow_water.clone(),
+        Terrain::Pit => dungeon_materials.pit.clone(),
+        Terrain::Pillar => dungeon_materials.pillar.clone(),
+        Terrain::LowWall => dungeon_materials.low_wall.clone(),
+        Terrain::Floor | Terrain::Wall => {
+            panic!("Not a terrain feature: {:?}", terrain)
+        }
+    };
+
+    parent.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(TILE_SIZE, TILE_SIZE)),
+                ..Default::default()
+            },
+            transform: Transform::from_translation(grid_pos.to_world().extend(TERRAIN_Z)),
+            texture: image,
+            ..Default::default()
+        },
+        terrain,
+        grid_pos,
+        Name::new(format!("{:?} ({}, {})", terrain, grid_pos.x, grid_pos.y)),
+    ));
+}
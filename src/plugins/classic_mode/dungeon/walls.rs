@@ -4,7 +4,9 @@ use crate::config::*;
 use crate::materials::ingame::InGameMaterials;
 use crate::plugins::classic_mode::dungeon::TOTAL_TILE_HEIGHT;
 use crate::plugins::classic_mode::ClassicModeData;
+use crate::plugins::game_ui::translate::{START_X, START_Y};
 use crate::resources::dungeon::block_type::BlockType;
+use crate::resources::dungeon::grid_occupancy::Terrain;
 use crate::resources::dungeon::rooms::Rooms;
 use crate::resources::dungeon::wall::Wall;
 use crate::resources::dungeon::wall_type::WallType;
@@ -12,11 +14,9 @@ use crate::resources::dungeon::walls::Walls;
 use crate::resources::dungeon::Dungeon;
 use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
 
-const START_Y: f32 = 0.0 + WINDOW_HEIGHT / 2.0 - TILE_SIZE / 2.0;
-const START_X: f32 = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + TILE_SIZE / 2.0;
-
 /// Spawns the wall sprites for dungeon rooms.
-/// Assumes that if a tile value is either 0 or the tile is a wall.
+/// Assumes that a tile is a wall unless its value is 0 or a terrain feature, which are spawned by
+/// `terrain::terrain` instead.
 pub fn walls(
     mut commands: Commands,
     dungeon: Res<Dungeon>,
@@ -28,12 +28,7 @@ pub fn walls(
     let current_position = current_floor.current_position;
 
     let room_id = current_floor.map[current_position.row_index][current_position.column_index];
-
-    // FIXME:
-    // The original code is kind of strange:
-    // This ensures that only the first room is used.
-    // let room = rooms.get_room(if room_id == 1.0 { 1.0 } else { 1.0 });
-    let room = rooms.get_room(1.0);
+    let room = rooms.get_room(room_id);
 
     let walls = commands
         .spawn(SpriteBundle {
@@ -42,7 +37,7 @@ pub fn walls(
         .with_children(|parent| {
             for (row_index, row) in room.tilemap.iter().enumerate() {
                 for (column_index, column) in row.iter().enumerate() {
-                    if *column != 0 {
+                    if *column != 0 && Terrain::from_tile(*column).is_none() {
                         wall(parent, row_index, column_index, *column, &ingame_materials);
                     }
                 }
@@ -141,10 +136,12 @@ fn wall(
 
 pub fn temporary_walls_system(
     mut wall_query: Query<(&Wall, &mut Visibility)>,
+    added_query: Query<(), Added<Wall>>,
     player_dungeon_stats: Res<PlayerDungeonStats>,
     dungeon: Res<Dungeon>,
 ) {
-    if player_dungeon_stats.is_changed() {
+    // The walls of the next room are spawned after the player moves into it.
+    if player_dungeon_stats.is_changed() || !added_query.is_empty() {
         let current_floor = dungeon.current_floor.clone();
         let current_position = current_floor.current_position;
 
@@ -1,8 +1,10 @@
 use bevy::prelude::*;
 
+use crate::resources::dungeon::grid_occupancy::GridOccupancy;
 use crate::resources::game_data::PauseSceneData;
 use crate::scenes::SceneState;
 
+use self::dungeon::navigation::EnterRoomEvent;
 use super::input::movement::map::MapGrid;
 
 pub mod dungeon;
@@ -17,11 +19,14 @@ pub struct ClassicModeData {
     pub doors: Option<Entity>,
     pub ground: Option<Entity>,
     pub walls: Option<Entity>,
+    pub terrain: Option<Entity>,
     pub end_point: Option<Entity>,
 }
 
 impl Plugin for ClassicModePlugin {
     fn build(&self, app: &mut App) {
+        app.add_event::<EnterRoomEvent>();
+
         app.add_systems(
             OnEnter(SceneState::PreClassicMode),
             dungeon::initiate::initiate_classic_mode,
@@ -33,7 +38,9 @@ impl Plugin for ClassicModePlugin {
                 dungeon::ground::ground,
                 dungeon::doors::doors,
                 dungeon::walls::walls,
+                dungeon::terrain::terrain,
                 dungeon::end_point::end_point,
+                dungeon::lighting::lighting,
             ),
         );
 
@@ -44,6 +51,9 @@ impl Plugin for ClassicModePlugin {
                 dungeon::doors::vertical_doors_system,
                 dungeon::walls::temporary_walls_system,
                 dungeon::end_point::end_point_handle_system,
+                dungeon::navigation::walk_through_doors,
+                dungeon::navigation::check_room_cleared,
+                dungeon::end_point::use_end_point,
             )
                 .run_if(
                     in_state(SceneState::InGameClassicMode)
@@ -51,6 +61,32 @@ impl Plugin for ClassicModePlugin {
                 ),
         );
 
+        // The next room is spawned as the player walks into it, or climbs down to the next floor.
+        app.add_systems(
+            Update,
+            (
+                dungeon::ground::ground,
+                dungeon::walls::walls,
+                dungeon::terrain::terrain,
+                dungeon::lighting::lighting,
+            )
+                .after(dungeon::navigation::walk_through_doors)
+                .after(dungeon::end_point::use_end_point)
+                .run_if(
+                    in_state(SceneState::InGameClassicMode).and_then(on_event::<EnterRoomEvent>()),
+                ),
+        );
+
+        app.add_systems(
+            Update,
+            (
+                dungeon::occupancy::track_terrain,
+                dungeon::occupancy::track_creatures,
+                dungeon::occupancy::remove_creatures,
+            )
+                .run_if(in_state(SceneState::InGameClassicMode)),
+        );
+
         app.add_systems(OnExit(SceneState::InGameClassicMode), clean_up_classic_mode);
     }
 }
@@ -64,6 +100,10 @@ fn clean_up_classic_mode(mut commands: Commands, classic_mode_data: Res<ClassicM
         .entity(classic_mode_data.walls.unwrap())
         .despawn_recursive();
 
+    commands
+        .entity(classic_mode_data.terrain.unwrap())
+        .despawn_recursive();
+
     commands
         .entity(classic_mode_data.end_point.unwrap())
         .despawn_recursive();
@@ -73,4 +113,5 @@ fn clean_up_classic_mode(mut commands: Commands, classic_mode_data: Res<ClassicM
         .despawn_recursive();
 
     commands.remove_resource::<MapGrid>();
+    commands.remove_resource::<GridOccupancy>();
 }
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    components::creature::Creature,
+    resources::dungeon::{grid_pos::GridPos, pathfinding::estimate},
+};
+
+/// The length of one side of a square, in feet.
+pub const FEET_PER_SQUARE: usize = 5;
+
+/// The distance in feet between the squares centered on `from` and `to`, counting every second
+/// diagonal as 10 feet like movement does.
+pub fn distance(from: Vec2, to: Vec2) -> usize {
+    estimate(GridPos::from_world(from), GridPos::from_world(to), false) as usize
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+/// The shape of an area of effect, e.g. of a spell or of channel energy.
+/// - `Burst`: Every square within `radius` feet of the center, counting every second diagonal
+///   as 10 feet like movement does.
+pub enum Area {
+    Burst { radius: usize },
+}
+
+impl Area {
+    /// Whether the square centered on `point` is inside the area centered on `center`.
+    pub fn contains(self, center: Vec2, point: Vec2) -> bool {
+        match self {
+            Area::Burst { radius } => distance(center, point) <= radius,
+        }
+    }
+
+    /// The creatures standing inside the area centered on `center`.
+    pub fn creatures(
+        self,
+        center: Vec2,
+        creature_query: &Query<(Entity, &Transform), With<Creature>>,
+    ) -> Vec<Entity> {
+        creature_query
+            .iter()
+            .filter(|(_, transform)| self.contains(center, transform.translation.truncate()))
+            .map(|(entity, _)| entity)
+            .collect()
+    }
+}
@@ -4,11 +4,55 @@ use crate::plugins::combat::AttackData;
 
 use super::armor_class_modifier::{ACModEvent, ACModList};
 
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+/// The armor class an attack is made against.
+/// - `Normal`: All AC bonuses apply.
+/// - `Touch`: Armor, Shield, and Natural Armor bonuses do not apply, e.g. touch spells.
+/// - `FlatFooted`: Dexterity and Dodge bonuses do not apply, e.g. surprised targets.
+/// - `FlatFootedTouch`: Both of the above.
+pub enum TargetAC {
+    #[default]
+    Normal,
+    Touch,
+    FlatFooted,
+    FlatFootedTouch,
+}
+
+impl TargetAC {
+    /// Returns the flat-footed version of this target AC.
+    pub fn flat_footed(self) -> Self {
+        match self {
+            TargetAC::Normal | TargetAC::FlatFooted => TargetAC::FlatFooted,
+            TargetAC::Touch | TargetAC::FlatFootedTouch => TargetAC::FlatFootedTouch,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Event, Deref)]
 pub struct ACBonusSumEvent {
     pub attack_data: AttackData,
     #[deref]
     pub total_ac_bonus: isize,
+    pub touch_ac_bonus: isize,
+    pub flat_footed_ac_bonus: isize,
+    pub flat_footed_touch_ac_bonus: isize,
+}
+
+impl ACBonusSumEvent {
+    /// The AC bonus which applies to the type of AC the attack is made against.
+    pub fn bonus_vs(&self, target_ac: TargetAC) -> isize {
+        match target_ac {
+            TargetAC::Normal => self.total_ac_bonus,
+            TargetAC::Touch => self.touch_ac_bonus,
+            TargetAC::FlatFooted => self.flat_footed_ac_bonus,
+            TargetAC::FlatFootedTouch => self.flat_footed_touch_ac_bonus,
+        }
+    }
+
+    /// The AC of the defender against this attack, including the base of 10.
+    pub fn target_total(&self) -> isize {
+        10 + self.bonus_vs(self.attack_data.target_ac)
+    }
 }
 
 /// Collects the various AC modifiers from the systems which manage those modifiers and send out
@@ -27,7 +71,21 @@ pub fn sum_armor_class_modifiers(
         let sum_event = ACBonusSumEvent {
             attack_data,
             total_ac_bonus: ac_mod_list.sum_all(),
+            touch_ac_bonus: ac_mod_list.sum_touch(),
+            flat_footed_ac_bonus: ac_mod_list.sum_flat_footed(),
+            flat_footed_touch_ac_bonus: ac_mod_list.sum_flat_footed_touch(),
         };
+        if debug {
+            println!(
+                "{:>6}|{:>28}| normal: {}, touch: {}, flat-footed: {}, flat-footed touch: {}",
+                "",
+                "",
+                sum_event.total_ac_bonus,
+                sum_event.touch_ac_bonus,
+                sum_event.flat_footed_ac_bonus,
+                sum_event.flat_footed_touch_ac_bonus
+            );
+        }
 
         ac_mod_finished.send(sum_event);
     }
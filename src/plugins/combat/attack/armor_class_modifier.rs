@@ -1,11 +1,26 @@
 use bevy::prelude::*;
 
 use crate::{
-    components::attributes::{Attribute, Dexterity},
-    plugins::combat::{
-        bonus::{BonusSource, BonusType},
-        AttackData, AttackDataEvent,
+    components::{
+        attack_bonus::BaseAttackBonus,
+        attributes::{AbilityAdjustments, Attribute, Dexterity},
+        class_features::{ArmorTraining, RollingDodge},
+        conditions::Prone,
+        creature::Subtypes,
+        feats::combat_feats::Dodge,
+        racial_traits::RacialTraits,
+        size::Size,
     },
+    plugins::{
+        combat::{
+            bonus::{BonusSource, BonusType},
+            rage::{Raging, RAGE_AC_PENALTY},
+            stance::{ActiveStances, Stance},
+            AttackData, AttackDataEvent,
+        },
+        item::equipment::armor::EquippedArmor,
+    },
+    resources::equipment::{armor::Armor, weapon::Weapon},
 };
 
 #[derive(Copy, Clone, Debug)]
@@ -75,25 +90,39 @@ pub fn base(
     }
 }
 
-/// Add the dexterity modifier to armor class, if applicable.
+/// Add the dexterity modifier to armor class, if applicable. The bonus is capped by the lowest
+/// maximum Dexterity bonus of any armor or shield the defender has equipped.
 pub fn add_dexterity(
     mut attack_data_event: EventReader<AttackDataEvent>,
     mut event_writer: EventWriter<ACModEvent>,
-    defender_query: Query<&Dexterity>,
+    defender_query: Query<(
+        &Dexterity,
+        Option<&AbilityAdjustments>,
+        Option<&EquippedArmor>,
+        Option<&ArmorTraining>,
+    )>,
+    armor_query: Query<&Armor>,
 ) {
     let debug = false;
     for attack_data in attack_data_event.into_iter() {
         if debug {
             println!("debug | armor_class_modifier::add_dexterity | start");
         }
-        if let Ok(dexterity) = defender_query.get(attack_data.defender) {
+        if let Ok((dexterity, adjustments, equipped_armor, armor_training)) =
+            defender_query.get(attack_data.defender)
+        {
             let mut armor_class_modifier = ACMod {
                 val: 0,
                 source: BonusSource::Dexterity,
-                bonus_type: BonusType::Untyped,
+                bonus_type: BonusType::Dexterity,
                 attack_data: **attack_data,
             };
-            armor_class_modifier.add_attribute_bonus(*dexterity);
+            armor_class_modifier.add_attribute_bonus(dexterity.adjusted(adjustments));
+            if let Some(max_dex) =
+                equipped_armor.and_then(|equipped| equipped.max_dex(&armor_query, armor_training))
+            {
+                armor_class_modifier.val = armor_class_modifier.val.min(max_dex);
+            }
             if debug {
                 debug_add_dexterity(armor_class_modifier);
             }
@@ -103,6 +132,214 @@ pub fn add_dexterity(
     }
 }
 
+/// Add the Armor and Shield bonuses of the defender's equipped armor and shield.
+pub fn add_armor(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<ACModEvent>,
+    defender_query: Query<&EquippedArmor>,
+    armor_query: Query<&Armor>,
+) {
+    let debug = false;
+    for attack_data in attack_data_event.into_iter() {
+        if debug {
+            println!("debug | armor_class_modifier::add_armor | start");
+        }
+        if let Ok(equipped_armor) = defender_query.get(attack_data.defender) {
+            for armor in equipped_armor
+                .iter()
+                .filter_map(|entity| armor_query.get(entity).ok())
+            {
+                let armor_class_modifier = armor.to_ac_mod(**attack_data);
+                if debug {
+                    println!(
+                        "{:>6}|{:>28}| {:?} bonus added: {}",
+                        "", "", armor.armor_name, armor_class_modifier.val
+                    );
+                }
+                event_writer.send(armor_class_modifier.into());
+            }
+        }
+    }
+}
+
+/// Add the -4 AC penalty for a `Prone` defender.
+// TODO: Prone creatures instead get a +4 bonus to AC against ranged attacks, once those exist.
+pub fn add_prone(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<ACModEvent>,
+    defender_query: Query<(), With<Prone>>,
+) {
+    for attack_data in attack_data_event.into_iter() {
+        if defender_query.contains(attack_data.defender) {
+            let armor_class_modifier = ACMod {
+                val: -4,
+                source: BonusSource::Prone,
+                bonus_type: BonusType::Untyped,
+                attack_data: **attack_data,
+            };
+            event_writer.send(armor_class_modifier.into());
+        }
+    }
+}
+
+/// Add the +1 dodge bonus of the `Dodge` feat. Like other dodge bonuses, this is lost when the
+/// defender is flat-footed.
+pub fn add_dodge(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<ACModEvent>,
+    defender_query: Query<(), With<Dodge>>,
+) {
+    for attack_data in attack_data_event.into_iter() {
+        if defender_query.contains(attack_data.defender) {
+            let armor_class_modifier = ACMod {
+                val: 1,
+                source: BonusSource::Dodge,
+                bonus_type: BonusType::Dodge,
+                attack_data: **attack_data,
+            };
+            event_writer.send(armor_class_modifier.into());
+        }
+    }
+}
+
+/// Add the dodge bonus from the defender's Combat Expertise stance, +1 for every -1 it takes on
+/// attack rolls.
+pub fn add_combat_expertise(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<ACModEvent>,
+    defender_query: Query<(&ActiveStances, Option<&BaseAttackBonus>)>,
+) {
+    for attack_data in attack_data_event.into_iter() {
+        if let Ok((stances, bab)) = defender_query.get(attack_data.defender) {
+            if stances.is_active(Stance::CombatExpertise) {
+                let armor_class_modifier = ACMod {
+                    val: Stance::steps(bab),
+                    source: BonusSource::CombatExpertise,
+                    bonus_type: BonusType::Dodge,
+                    attack_data: **attack_data,
+                };
+                event_writer.send(armor_class_modifier.into());
+            }
+        }
+    }
+}
+
+/// Add the -2 AC penalty of a raging defender.
+pub fn add_rage(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<ACModEvent>,
+    defender_query: Query<(), With<Raging>>,
+) {
+    for attack_data in attack_data_event.into_iter() {
+        if defender_query.contains(attack_data.defender) {
+            let armor_class_modifier = ACMod {
+                val: RAGE_AC_PENALTY,
+                source: BonusSource::Rage,
+                bonus_type: BonusType::Untyped,
+                attack_data: **attack_data,
+            };
+            event_writer.send(armor_class_modifier.into());
+        }
+    }
+}
+
+/// Add the dodge bonus of the Rolling Dodge rage power against ranged attacks, while the defender
+/// is raging.
+pub fn add_rolling_dodge(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<ACModEvent>,
+    defender_query: Query<&RollingDodge, With<Raging>>,
+    weapon_query: Query<&Weapon>,
+) {
+    for attack_data in attack_data_event.into_iter() {
+        let (Ok(rolling_dodge), Ok(weapon)) = (
+            defender_query.get(attack_data.defender),
+            weapon_query.get(attack_data.weapon_slot.entity),
+        ) else {
+            continue;
+        };
+        if !weapon.is_melee() {
+            let armor_class_modifier = ACMod {
+                val: **rolling_dodge,
+                source: BonusSource::RollingDodge,
+                bonus_type: BonusType::Dodge,
+                attack_data: **attack_data,
+            };
+            event_writer.send(armor_class_modifier.into());
+        }
+    }
+}
+
+/// Add the dodge bonus of a dwarf's Defensive Training against giants.
+pub fn add_defensive_training(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<ACModEvent>,
+    defender_query: Query<&RacialTraits>,
+    attacker_query: Query<&Subtypes>,
+) {
+    for attack_data in attack_data_event.into_iter() {
+        let (Ok(racial_traits), Ok(subtypes)) = (
+            defender_query.get(attack_data.defender),
+            attacker_query.get(attack_data.attacker),
+        ) else {
+            continue;
+        };
+        let bonus = racial_traits.ac_bonus_vs(subtypes);
+        if bonus != 0 {
+            let armor_class_modifier = ACMod {
+                val: bonus,
+                source: BonusSource::DefensiveTraining,
+                bonus_type: BonusType::Dodge,
+                attack_data: **attack_data,
+            };
+            event_writer.send(armor_class_modifier.into());
+        }
+    }
+}
+
+/// Add the defender's size modifier, e.g. +1 for Small creatures and -1 for Large creatures. The
+/// size modifier applies to touch and flat-footed AC as well.
+pub fn add_size(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<ACModEvent>,
+    defender_query: Query<&Size>,
+) {
+    for attack_data in attack_data_event.into_iter() {
+        let Ok(size) = defender_query.get(attack_data.defender) else {
+            continue;
+        };
+        if size.size_modifier() != 0 {
+            let armor_class_modifier = ACMod {
+                val: size.size_modifier(),
+                source: BonusSource::Size,
+                bonus_type: BonusType::Size,
+                attack_data: **attack_data,
+            };
+            event_writer.send(armor_class_modifier.into());
+        }
+    }
+}
+
+/// Add the AC bonus from the defender's cover against the attacker, +2 for partial cover and +4
+/// for cover. Cover applies to touch and flat-footed AC as well.
+pub fn add_cover(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<ACModEvent>,
+) {
+    for attack_data in attack_data_event.into_iter() {
+        let bonus = attack_data.cover.ac_bonus();
+        if bonus != 0 {
+            let armor_class_modifier = ACMod {
+                val: bonus,
+                source: BonusSource::Cover,
+                bonus_type: BonusType::Untyped,
+                attack_data: **attack_data,
+            };
+            event_writer.send(armor_class_modifier.into());
+        }
+    }
+}
+
 fn debug_add_dexterity(armor_class_modifier: ACMod) {
     println!(
         "{:>6}|{:>28}| dexterity bonus added: {}",
@@ -154,6 +391,37 @@ impl ACModList {
         self.sum_stackable() + self.sum_non_stackable()
     }
 
+    /// Sum up the modifiers which apply to touch AC, leaving out Armor, Shield, and Natural Armor
+    /// bonuses.
+    pub fn sum_touch(&self) -> isize {
+        self.iter()
+            .filter(|ac_mod| !BonusType::not_touch().contains(&ac_mod.bonus_type))
+            .copied()
+            .collect::<ACModList>()
+            .sum_all()
+    }
+
+    /// Sum up the modifiers which apply to flat-footed AC, leaving out Dexterity and Dodge
+    /// bonuses. Penalties of those types still apply.
+    pub fn sum_flat_footed(&self) -> isize {
+        self.iter()
+            .filter(|ac_mod| {
+                !(BonusType::not_flat_footed().contains(&ac_mod.bonus_type) && ac_mod.val > 0)
+            })
+            .copied()
+            .collect::<ACModList>()
+            .sum_all()
+    }
+
+    /// Sum up the modifiers which apply to flat-footed touch AC.
+    pub fn sum_flat_footed_touch(&self) -> isize {
+        self.iter()
+            .filter(|ac_mod| !BonusType::not_touch().contains(&ac_mod.bonus_type))
+            .copied()
+            .collect::<ACModList>()
+            .sum_flat_footed()
+    }
+
     /// The `verified_data` method goes through the list of ACMods and compares the attack_data of
     /// each to ensure they are all from the same attack.
     pub fn verified_data(&self) -> Result<AttackData, &'static str> {
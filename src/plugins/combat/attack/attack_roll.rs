@@ -2,14 +2,18 @@ use bevy::prelude::*;
 
 use crate::plugins::combat::{
     attack::attack_roll_modifier::{AttackModEvent, AttackModList},
+    bonus::{BonusSource, BonusType},
     AttackData,
 };
 
-#[derive(Copy, Clone, Event, Deref)]
+#[derive(Clone, Event, Deref)]
+/// The total attack bonus, along with the `breakdown` of each of the modifiers which went into
+/// it, used to show the attack breakdown.
 pub struct AttackBonusSumEvent {
     pub attack_data: AttackData,
     #[deref]
     pub total_attack_bonus: isize,
+    pub breakdown: Vec<(BonusSource, BonusType, isize)>,
 }
 
 /// `sum_attack_modifier` adds together all of the modifiers in the `attack_modifier` mod. It
@@ -30,6 +34,10 @@ pub fn sum_attack_modifier(
         let sum_event = AttackBonusSumEvent {
             attack_data,
             total_attack_bonus: atk_mod_list.sum_all(),
+            breakdown: atk_mod_list
+                .iter()
+                .map(|atk_mod| (atk_mod.source, atk_mod.bonus_type, atk_mod.val))
+                .collect(),
         };
 
         atk_mod_finished.send(sum_event);
@@ -1,12 +1,21 @@
 use bevy::prelude::*;
 
 use crate::components::attack_bonus::BaseAttackBonus;
+use crate::components::class_features::WeaponTraining;
+use crate::components::conditions::{Broken, Grappled, Prone};
+use crate::components::creature::Subtypes;
+use crate::components::proficiencies::{Proficiencies, NONPROFICIENT_WEAPON_PENALTY};
+use crate::components::racial_traits::{RacialTraits, WeaponFamiliarity};
+use crate::components::size::Size;
 use crate::plugins::combat::bonus::BonusSource;
+use crate::plugins::combat::stance::{ActiveStances, Stance};
 use crate::plugins::combat::{AttackData, AttackDataEvent};
+use crate::plugins::item::equipment::armor::EquippedArmor;
+use crate::resources::equipment::armor::Armor;
 use crate::resources::equipment::weapon::Weapon;
 use crate::{
     components::{
-        attributes::{Attribute, Strength},
+        attributes::{AbilityAdjustments, Attribute, Strength},
         feats::combat_feats::WeaponFocus,
     },
     plugins::{combat::bonus::BonusType, player::control::ActionPriority},
@@ -98,21 +107,21 @@ pub fn add_strength(
     mut attack_data_event: EventReader<AttackDataEvent>,
     // mut attack_bonus_event: EventReader<AttackBonusEvent>,
     mut event_writer: EventWriter<AttackModEvent>,
-    query_attacker: Query<&Strength, With<ActionPriority>>,
+    query_attacker: Query<(&Strength, Option<&AbilityAdjustments>), With<ActionPriority>>,
 ) {
     let debug = false;
     for attack_data in attack_data_event.iter() {
         if debug {
             println!("debug | attack_modifier::add_strength | start");
         }
-        if let Ok(strength) = query_attacker.get_single() {
+        if let Ok((strength, adjustments)) = query_attacker.get_single() {
             let mut attack_modifier = AttackMod {
                 val: 0,
                 source: BonusSource::Strength,
                 bonus_type: BonusType::Untyped,
                 attack_data: **attack_data,
             };
-            attack_modifier.add_attribute_bonus(*strength);
+            attack_modifier.add_attribute_bonus(strength.adjusted(adjustments));
             if debug {
                 debug_add_strength(attack_modifier);
             }
@@ -162,6 +171,301 @@ fn debug_add_weapon_focus(attack_modifier: AttackMod) {
     );
 }
 
+/// Adds the Fighter's Weapon Training bonus for the weapon's best trained group.
+pub fn add_weapon_training(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<AttackModEvent>,
+    query_attacker: Query<&WeaponTraining>,
+    query_weapon: Query<&Weapon>,
+) {
+    let debug = false;
+    for attack_data in attack_data_event.iter() {
+        let (Ok(training), Ok(weapon)) = (
+            query_attacker.get(attack_data.attacker),
+            query_weapon.get(attack_data.weapon_slot.entity),
+        ) else {
+            continue;
+        };
+        if let Some(bonus) = training.bonus(weapon) {
+            let attack_modifier = AttackMod {
+                val: bonus,
+                source: BonusSource::WeaponTraining,
+                bonus_type: BonusType::Untyped,
+                attack_data: **attack_data,
+            };
+            if debug {
+                println!(
+                    "debug | attack_roll_modifier::add_weapon_training | bonus: {}",
+                    bonus
+                );
+            }
+            event_writer.send(attack_modifier.into());
+        }
+    }
+}
+
+/// Adds the +2 Circumstance bonus for flanking the defender. Whether the attacker is flanking is
+/// decided by `flanking::is_flanking` when the `AttackData` is built.
+pub fn add_flanking(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<AttackModEvent>,
+) {
+    let debug = false;
+    for attack_data in attack_data_event.iter().filter(|data| data.flanking) {
+        let attack_modifier = AttackMod {
+            val: 2,
+            source: BonusSource::Flanking,
+            bonus_type: BonusType::Circumstance,
+            attack_data: **attack_data,
+        };
+        if debug {
+            println!(
+                "debug | attack_modifier::add_flanking | flanking bonus added: {}",
+                attack_modifier.val
+            );
+        }
+        event_writer.send(attack_modifier.into());
+    }
+}
+
+/// Adds the attack roll penalties from conditions on the attacker, e.g. `Prone` or `Grappled`.
+pub fn add_conditions(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<AttackModEvent>,
+    query_attacker: Query<(Option<&Prone>, Option<&Grappled>)>,
+) {
+    let debug = false;
+    for attack_data in attack_data_event.iter() {
+        if let Ok((prone, grappled)) = query_attacker.get(attack_data.attacker) {
+            if prone.is_some() {
+                event_writer.send(
+                    AttackMod {
+                        val: -4,
+                        source: BonusSource::Prone,
+                        bonus_type: BonusType::Untyped,
+                        attack_data: **attack_data,
+                    }
+                    .into(),
+                );
+            }
+            if grappled.is_some() {
+                event_writer.send(
+                    AttackMod {
+                        val: -2,
+                        source: BonusSource::Grappled,
+                        bonus_type: BonusType::Untyped,
+                        attack_data: **attack_data,
+                    }
+                    .into(),
+                );
+            }
+            if debug {
+                println!(
+                    "debug | attack_modifier::add_conditions | prone: {}, grappled: {}",
+                    prone.is_some(),
+                    grappled.is_some()
+                );
+            }
+        }
+    }
+}
+
+/// Adds the -2 attack roll penalty for attacking with a `Broken` weapon.
+pub fn add_broken_weapon(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<AttackModEvent>,
+    query_weapon: Query<(), (With<Weapon>, With<Broken>)>,
+) {
+    for attack_data in attack_data_event.iter() {
+        if query_weapon.contains(attack_data.weapon_slot.entity) {
+            event_writer.send(
+                AttackMod {
+                    val: -2,
+                    source: BonusSource::Broken,
+                    bonus_type: BonusType::Untyped,
+                    attack_data: **attack_data,
+                }
+                .into(),
+            );
+        }
+    }
+}
+
+/// Adds the -4 attack roll penalty for dealing nonlethal damage with a weapon which normally deals
+/// lethal damage.
+pub fn add_nonlethal(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<AttackModEvent>,
+    query_weapon: Query<&Weapon>,
+) {
+    for attack_data in attack_data_event.iter().filter(|data| data.nonlethal) {
+        if query_weapon
+            .get(attack_data.weapon_slot.entity)
+            .is_ok_and(|weapon| !weapon.is_nonlethal())
+        {
+            event_writer.send(
+                AttackMod {
+                    val: -4,
+                    source: BonusSource::Nonlethal,
+                    bonus_type: BonusType::Untyped,
+                    attack_data: **attack_data,
+                }
+                .into(),
+            );
+        }
+    }
+}
+
+/// Adds the racial bonus of a dwarf's Hatred against orcs and goblinoids.
+pub fn add_hatred(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<AttackModEvent>,
+    query_attacker: Query<&RacialTraits>,
+    query_defender: Query<&Subtypes>,
+) {
+    for attack_data in attack_data_event.iter() {
+        let (Ok(racial_traits), Ok(subtypes)) = (
+            query_attacker.get(attack_data.attacker),
+            query_defender.get(attack_data.defender),
+        ) else {
+            continue;
+        };
+        let bonus = racial_traits.attack_bonus_vs(subtypes);
+        if bonus != 0 {
+            event_writer.send(
+                AttackMod {
+                    val: bonus,
+                    source: BonusSource::Hatred,
+                    bonus_type: BonusType::Racial,
+                    attack_data: **attack_data,
+                }
+                .into(),
+            );
+        }
+    }
+}
+
+/// Adds the attacker's size modifier, e.g. +1 for Small creatures and -1 for Large creatures.
+pub fn add_size(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<AttackModEvent>,
+    query_attacker: Query<&Size>,
+) {
+    for attack_data in attack_data_event.iter() {
+        let Ok(size) = query_attacker.get(attack_data.attacker) else {
+            continue;
+        };
+        if size.size_modifier() != 0 {
+            event_writer.send(
+                AttackMod {
+                    val: size.size_modifier(),
+                    source: BonusSource::Size,
+                    bonus_type: BonusType::Size,
+                    attack_data: **attack_data,
+                }
+                .into(),
+            );
+        }
+    }
+}
+
+/// Adds the -4 penalty for attacking with a weapon the attacker is not proficient with, and the
+/// armor check penalty of any armor or shield the attacker is not proficient with.
+pub fn add_nonproficiency(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<AttackModEvent>,
+    query_attacker: Query<(
+        &Proficiencies,
+        Option<&WeaponFamiliarity>,
+        Option<&EquippedArmor>,
+    )>,
+    query_weapon: Query<&Weapon>,
+    query_armor: Query<&Armor>,
+) {
+    let debug = false;
+    for attack_data in attack_data_event.iter() {
+        let Ok((proficiencies, familiarity, equipped_armor)) =
+            query_attacker.get(attack_data.attacker)
+        else {
+            continue;
+        };
+        if let Ok(weapon) = query_weapon.get(attack_data.weapon_slot.entity) {
+            if !proficiencies.weapon(weapon, familiarity) {
+                event_writer.send(
+                    AttackMod {
+                        val: NONPROFICIENT_WEAPON_PENALTY,
+                        source: BonusSource::Nonproficient,
+                        bonus_type: BonusType::Untyped,
+                        attack_data: **attack_data,
+                    }
+                    .into(),
+                );
+            }
+        }
+        let armor_penalty: isize = equipped_armor
+            .iter()
+            .flat_map(|equipped| equipped.iter())
+            .filter_map(|entity| query_armor.get(entity).ok())
+            .filter(|armor| !proficiencies.armor(armor))
+            .map(|armor| armor.armor_check_penalty())
+            .sum();
+        if armor_penalty != 0 {
+            event_writer.send(
+                AttackMod {
+                    val: armor_penalty,
+                    source: BonusSource::NonproficientArmor,
+                    bonus_type: BonusType::Untyped,
+                    attack_data: **attack_data,
+                }
+                .into(),
+            );
+        }
+        if debug {
+            println!(
+                "debug | attack_roll_modifier::add_nonproficiency | armor penalty: {}",
+                armor_penalty
+            );
+        }
+    }
+}
+
+/// Adds the attack roll penalty of each stance the attacker is using, e.g. Power Attack. Stances
+/// only apply to melee attacks.
+pub fn add_stances(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut event_writer: EventWriter<AttackModEvent>,
+    query_attacker: Query<(&ActiveStances, Option<&BaseAttackBonus>)>,
+    query_weapon: Query<&Weapon>,
+) {
+    let debug = false;
+    for attack_data in attack_data_event.iter() {
+        let (Ok((stances, bab)), Ok(weapon)) = (
+            query_attacker.get(attack_data.attacker),
+            query_weapon.get(attack_data.weapon_slot.entity),
+        ) else {
+            continue;
+        };
+        if !weapon.is_melee() {
+            continue;
+        }
+        for stance in stances.iter() {
+            let attack_modifier = AttackMod {
+                val: -Stance::steps(bab),
+                source: stance.bonus_source(),
+                bonus_type: BonusType::Untyped,
+                attack_data: **attack_data,
+            };
+            if debug {
+                println!(
+                    "debug | attack_modifier::add_stances | {:?} penalty added: {}",
+                    stance, attack_modifier.val
+                );
+            }
+            event_writer.send(attack_modifier.into());
+        }
+    }
+}
+
 #[derive(Debug, Deref)]
 pub struct AttackModList(Vec<AttackMod>);
 
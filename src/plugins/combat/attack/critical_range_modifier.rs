@@ -1,8 +1,11 @@
 use bevy::prelude::*;
 
 use crate::{
-    components::feats::combat_feats::ImprovedCritical, plugins::player::control::ActionPriority,
-    resources::equipment::weapon::Weapon,
+    components::feats::combat_feats::ImprovedCritical,
+    plugins::player::control::ActionPriority,
+    resources::equipment::{
+        weapon::Weapon, weapon_property::WeaponProperties, WeaponPropertyLibrary,
+    },
 };
 
 use crate::plugins::combat::{AttackData, AttackDataEvent};
@@ -29,6 +32,7 @@ impl CritRangeMod {
 #[derive(Copy, Clone, Debug, Default)]
 pub enum CritThreatBonusSource {
     ImprovedCritical,
+    Keen,
     #[default]
     None, // more here
 }
@@ -208,3 +212,26 @@ pub fn improved_critical(
         }
     }
 }
+
+/// Adds the weapon crit threat range increase for weapon properties like Keen.
+/// This will only run if the weapon entity has `WeaponProperties` with a `DoubleCritRange`
+/// effect. As with `ImprovedCritical`, this does not stack with other range doubling effects.
+pub fn weapon_properties(
+    mut attack_data_event: EventReader<AttackDataEvent>,
+    mut crit_mod_writer: EventWriter<CritRangeModEvent>,
+    weapon_query: Query<(&Weapon, &WeaponProperties)>,
+    property_library: Res<WeaponPropertyLibrary>,
+) {
+    for attack_data in attack_data_event.iter() {
+        if let Ok((weapon, properties)) = weapon_query.get(attack_data.weapon_slot.entity) {
+            for effect in properties
+                .iter()
+                .flat_map(|name| property_library.effects(name))
+            {
+                if let Some(modifier) = effect.to_crit_range_mod(**attack_data, weapon) {
+                    crit_mod_writer.send(CritRangeModEvent(modifier));
+                }
+            }
+        }
+    }
+}
@@ -50,14 +50,39 @@ impl Plugin for AttackPlugin {
         app.add_systems(
             Update,
             (
-                attack_roll_modifier::base_attack_bonus,
-                attack_roll_modifier::add_strength,
-                attack_roll_modifier::add_weapon_focus,
-                critical_range_modifier::base,
-                critical_range_modifier::improved_critical,
-                crit_multiplier_modifier::base,
-                armor_class_modifier::base,
-                armor_class_modifier::add_dexterity,
+                (
+                    attack_roll_modifier::base_attack_bonus,
+                    attack_roll_modifier::add_strength,
+                    attack_roll_modifier::add_weapon_focus,
+                    attack_roll_modifier::add_weapon_training,
+                    attack_roll_modifier::add_conditions,
+                    attack_roll_modifier::add_flanking,
+                    attack_roll_modifier::add_broken_weapon,
+                    attack_roll_modifier::add_nonlethal,
+                    attack_roll_modifier::add_stances,
+                    attack_roll_modifier::add_hatred,
+                    attack_roll_modifier::add_nonproficiency,
+                    attack_roll_modifier::add_size,
+                ),
+                (
+                    critical_range_modifier::base,
+                    critical_range_modifier::improved_critical,
+                    critical_range_modifier::weapon_properties,
+                    crit_multiplier_modifier::base,
+                ),
+                (
+                    armor_class_modifier::base,
+                    armor_class_modifier::add_dexterity,
+                    armor_class_modifier::add_armor,
+                    armor_class_modifier::add_prone,
+                    armor_class_modifier::add_cover,
+                    armor_class_modifier::add_dodge,
+                    armor_class_modifier::add_combat_expertise,
+                    armor_class_modifier::add_rage,
+                    armor_class_modifier::add_rolling_dodge,
+                    armor_class_modifier::add_defensive_training,
+                    armor_class_modifier::add_size,
+                ),
             )
                 .in_set(AttackModifier),
         );
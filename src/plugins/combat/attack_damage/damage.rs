@@ -24,6 +24,12 @@ pub struct AttackDamageSumEvent(AttackDamageSum);
 pub enum DamageBonusSource {
     Strength,
     Weapon,
+    WeaponProperty,
+    Broken,
+    WeaponSpecialization,
+    PowerAttack,
+    WeaponTraining,
+    SneakAttack,
     Base,
 }
 
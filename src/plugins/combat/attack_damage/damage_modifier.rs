@@ -1,17 +1,33 @@
 #![allow(dead_code)]
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
 use crate::{
-    components::attributes::{Attribute, Strength},
+    components::{
+        alignment::Alignment,
+        attack_bonus::BaseAttackBonus,
+        attributes::{AbilityAdjustments, Attribute, Strength},
+        class_features::{SneakAttack, UncannyDodge, WeaponTraining},
+        conditions::{Broken, FlatFooted},
+        feats::combat_feats::WeaponSpecialization,
+    },
     plugins::{
-        combat::{bonus::BonusType, damage::DamageType, AttackData, AttackDataEvent},
+        combat::{
+            bonus::BonusType,
+            damage::DamageType,
+            stance::{ActiveStances, Stance},
+            AttackData, AttackDataEvent,
+        },
         player::equipment::WeaponSlotName,
     },
-    resources::{dice::Dice, equipment::weapon::Weapon},
+    resources::{
+        dice::Dice,
+        equipment::{weapon::Weapon, weapon_property::WeaponProperties, WeaponPropertyLibrary},
+    },
 };
 
-use super::damage::DamageBonusSource;
+use super::{damage::DamageBonusSource, immunity::PrecisionImmunity};
 
 #[derive(Debug, Copy, Clone)]
 pub struct AttackDamageMod {
@@ -32,6 +48,14 @@ pub struct DamageDice {
 }
 
 impl DamageDice {
+    pub fn new(dice: Dice, dice_rolls: usize, bonus_per_roll: isize) -> Self {
+        Self {
+            dice,
+            dice_rolls,
+            bonus_per_roll,
+        }
+    }
+
     pub fn roll(self) -> usize {
         let mut rng = rand::thread_rng();
         match self.dice_rolls.cmp(&1) {
@@ -46,7 +70,7 @@ impl DamageDice {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 /// The way this damage interacts with criticals hits.
 pub enum OnCrit {
     /// The damage is only applied on a critical hit.
@@ -112,10 +136,200 @@ pub fn weapon(
     }
 }
 
+/// Adds the extra damage dice from weapon special abilities, e.g. the 1d6 fire of Flaming or the
+/// extra dice of Flaming Burst on a critical hit. The effects of each property are defined in
+/// `WEAPON_PROPERTY_DATA`.
+pub fn weapon_properties(
+    mut attack_reader: EventReader<AttackDataEvent>,
+    mut damage_mod_writer: EventWriter<AttackDamageModEvent>,
+    weapon_query: Query<(&Weapon, &WeaponProperties)>,
+    defender_query: Query<&Alignment>,
+    property_library: Res<WeaponPropertyLibrary>,
+) {
+    for data in attack_reader.into_iter() {
+        if let Ok((weapon, properties)) = weapon_query.get(data.weapon_slot.entity) {
+            let defender_alignment = defender_query.get(data.defender).ok();
+            for effect in properties
+                .iter()
+                .flat_map(|name| property_library.effects(name))
+            {
+                if let Some(damage_mod) = effect.to_damage_mod(**data, weapon, defender_alignment) {
+                    damage_mod_writer.send(AttackDamageModEvent(damage_mod));
+                }
+            }
+        }
+    }
+}
+
+/// Adds the -2 damage penalty for attacking with a `Broken` weapon.
+pub fn broken_weapon(
+    mut attack_reader: EventReader<AttackDataEvent>,
+    mut damage_mod_writer: EventWriter<AttackDamageModEvent>,
+    weapon_query: Query<(), (With<Weapon>, With<Broken>)>,
+) {
+    for data in attack_reader.into_iter() {
+        if weapon_query.contains(data.weapon_slot.entity) {
+            let damage_mod = AttackDamageMod {
+                damage_type: DamageType::Weapon,
+                val: -2,
+                attack_data: **data,
+                bonus_type: BonusType::Untyped,
+                bonus_source: DamageBonusSource::Broken,
+                on_crit: OnCrit::CanMultiply,
+                damage_dice: None,
+            };
+            damage_mod_writer.send(AttackDamageModEvent(damage_mod));
+        }
+    }
+}
+
+/// Adds the +2 damage of the `WeaponSpecialization` feat, if the attacker has it for the weapon
+/// used in the attack.
+pub fn add_weapon_specialization(
+    mut attack_reader: EventReader<AttackDataEvent>,
+    mut damage_mod_writer: EventWriter<AttackDamageModEvent>,
+    attacker_query: Query<&WeaponSpecialization>,
+    weapon_query: Query<&Weapon>,
+) {
+    for data in attack_reader.into_iter() {
+        let (Ok(specialization), Ok(weapon)) = (
+            attacker_query.get(data.attacker),
+            weapon_query.get(data.weapon_slot.entity),
+        ) else {
+            continue;
+        };
+        if specialization.contains(&weapon.weapon_name) {
+            let damage_mod = AttackDamageMod {
+                damage_type: DamageType::Weapon,
+                val: specialization.bonus(),
+                attack_data: **data,
+                bonus_type: BonusType::Untyped,
+                bonus_source: DamageBonusSource::WeaponSpecialization,
+                on_crit: OnCrit::CanMultiply,
+                damage_dice: None,
+            };
+            damage_mod_writer.send(AttackDamageModEvent(damage_mod));
+        }
+    }
+}
+
+/// Adds the Fighter's Weapon Training bonus for the weapon's best trained group.
+pub fn add_weapon_training(
+    mut attack_reader: EventReader<AttackDataEvent>,
+    mut damage_mod_writer: EventWriter<AttackDamageModEvent>,
+    attacker_query: Query<&WeaponTraining>,
+    weapon_query: Query<&Weapon>,
+) {
+    for data in attack_reader.into_iter() {
+        let (Ok(training), Ok(weapon)) = (
+            attacker_query.get(data.attacker),
+            weapon_query.get(data.weapon_slot.entity),
+        ) else {
+            continue;
+        };
+        if let Some(bonus) = training.bonus(weapon) {
+            let damage_mod = AttackDamageMod {
+                damage_type: DamageType::Weapon,
+                val: bonus,
+                attack_data: **data,
+                bonus_type: BonusType::Untyped,
+                bonus_source: DamageBonusSource::WeaponTraining,
+                on_crit: OnCrit::CanMultiply,
+                damage_dice: None,
+            };
+            damage_mod_writer.send(AttackDamageModEvent(damage_mod));
+        }
+    }
+}
+
+/// Adds the Rogue's sneak attack dice when the defender is flat-footed or flanked. Sneak attack is
+/// precision damage, so it is not multiplied on a critical hit and creatures with
+/// `PrecisionImmunity` do not take it.
+// TODO: Limit ranged sneak attacks to targets within 30ft.
+pub fn add_sneak_attack(
+    mut attack_reader: EventReader<AttackDataEvent>,
+    mut damage_mod_writer: EventWriter<AttackDamageModEvent>,
+    attacker_query: Query<&SneakAttack>,
+    defender_query: Query<(
+        Option<&FlatFooted>,
+        Option<&UncannyDodge>,
+        Option<&PrecisionImmunity>,
+    )>,
+) {
+    let debug = false;
+    for data in attack_reader.into_iter() {
+        let (Ok(sneak_attack), Ok((flat_footed, uncanny_dodge, immune))) = (
+            attacker_query.get(data.attacker),
+            defender_query.get(data.defender),
+        ) else {
+            continue;
+        };
+        // A flat-footed defender with Uncanny Dodge keeps its Dexterity bonus to AC, so it is not
+        // open to sneak attack.
+        let flat_footed = flat_footed.is_some() && uncanny_dodge.is_none();
+        if immune.is_some() || !(flat_footed || data.flanking) || **sneak_attack == 0 {
+            continue;
+        }
+        let damage_mod = AttackDamageMod {
+            damage_type: DamageType::Precision,
+            val: 0,
+            attack_data: **data,
+            bonus_type: BonusType::Untyped,
+            bonus_source: DamageBonusSource::SneakAttack,
+            on_crit: OnCrit::CannotMultiply,
+            damage_dice: Some(DamageDice::new(Dice::D6, **sneak_attack, 0)),
+        };
+        if debug {
+            println!(
+                "debug | damage_modifier::add_sneak_attack | {}d6 against {:?}",
+                **sneak_attack, data.defender
+            );
+        }
+        damage_mod_writer.send(AttackDamageModEvent(damage_mod));
+    }
+}
+
+/// Adds +2 damage for every -1 attack penalty taken with the Power Attack stance. As with
+/// Strength, the bonus is 1.5 times as much for two-handed attacks, and half as much for off-hand
+/// attacks.
+pub fn add_power_attack(
+    mut attack_reader: EventReader<AttackDataEvent>,
+    mut damage_mod_writer: EventWriter<AttackDamageModEvent>,
+    attacker_query: Query<(&ActiveStances, Option<&BaseAttackBonus>)>,
+    weapon_query: Query<&Weapon>,
+) {
+    for data in attack_reader.into_iter() {
+        let (Ok((stances, bab)), Ok(weapon)) = (
+            attacker_query.get(data.attacker),
+            weapon_query.get(data.weapon_slot.entity),
+        ) else {
+            continue;
+        };
+        if !stances.is_active(Stance::PowerAttack) || !weapon.is_melee() {
+            continue;
+        }
+        let bonus = 2 * Stance::steps(bab);
+        let damage_mod = AttackDamageMod {
+            damage_type: DamageType::Weapon,
+            val: match data.weapon_slot.slot {
+                WeaponSlotName::TwoHanded | WeaponSlotName::NaturalOnly => bonus + bonus / 2,
+                WeaponSlotName::OffHand | WeaponSlotName::NaturalSecondary => bonus / 2,
+                _ => bonus,
+            },
+            attack_data: **data,
+            bonus_type: BonusType::Untyped,
+            bonus_source: DamageBonusSource::PowerAttack,
+            on_crit: OnCrit::CanMultiply,
+            damage_dice: None,
+        };
+        damage_mod_writer.send(AttackDamageModEvent(damage_mod));
+    }
+}
+
 pub fn add_strength(
     mut attack_reader: EventReader<AttackDataEvent>,
     mut damage_mod_writer: EventWriter<AttackDamageModEvent>,
-    query_attacker: Query<&Strength>,
+    query_attacker: Query<(&Strength, Option<&AbilityAdjustments>)>,
 ) {
     for attack in attack_reader.into_iter() {
         let mut damage_mod = AttackDamageMod {
@@ -127,7 +341,8 @@ pub fn add_strength(
             on_crit: OnCrit::CanMultiply,
             damage_dice: None,
         };
-        let strength = query_attacker.get(attack.attacker).unwrap();
+        let (strength, adjustments) = query_attacker.get(attack.attacker).unwrap();
+        let strength = strength.adjusted(adjustments);
         damage_mod.val = match attack.weapon_slot.slot {
             WeaponSlotName::TwoHanded | WeaponSlotName::NaturalOnly => {
                 strength.bonus() + strength.bonus() / 2
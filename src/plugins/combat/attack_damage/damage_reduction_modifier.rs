@@ -119,28 +119,32 @@ impl DRModList {
     //     }
     // }
 
+    /// The largest total of DR against the damage type that can be made from DR which stacks,
+    /// e.g. the DR of the barbarian class feature with the DR of the Stalwart feat. DR which does
+    /// not stack with any other DR is only counted on its own.
     pub fn sum_stackable_type(&self, dmg_type: weapon::DamageType) -> Option<Self> {
-        // println!(">>> sum_stackable_types: self is {:?}", self);
-        if self.is_empty() {
-            println!(">>> sum_stackable_types: self is empty");
-            return None;
-        } else if self.len() == 1 {
-            return Some(self.clone());
-        }
-        self.iter()
-            .inspect(|item| println!(">>> debug sum_stackable_type - inspecting item: {:?}", item))
+        let reducing: Vec<&DRMod> = self
+            .iter()
             .filter(|dr_mod| dr_mod.damage_types.does_reduce(dmg_type))
-            .map(|outer| {
-                let stacks_list: DRModList = self
-                    .iter()
-                    .filter(|inner| inner.damage_types.does_reduce(dmg_type))
-                    .filter(|inner| {
-                        inner
-                            .reduction_source
-                            .can_stack_with(outer.reduction_source)
-                    })
-                    .collect();
-                stacks_list
+            .collect();
+        reducing
+            .iter()
+            .enumerate()
+            .map(|(i, outer)| {
+                // Every DR in the list must stack with every other DR already in it.
+                reducing.iter().enumerate().filter(|(j, _)| *j != i).fold(
+                    DRModList(vec![**outer]),
+                    |mut stacks_list, (_, inner)| {
+                        if stacks_list.iter().all(|included| {
+                            inner
+                                .reduction_source
+                                .can_stack_with(included.reduction_source)
+                        }) {
+                            stacks_list.add(**inner);
+                        }
+                        stacks_list
+                    },
+                )
             })
             .max_by(|x, y| x.sum().cmp(&y.sum()))
     }
@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+#[derive(Component, Copy, Clone, Debug)]
+/// Creatures without a vulnerable anatomy, e.g. oozes and elementals, do not take precision damage
+/// such as sneak attack.
+pub struct PrecisionImmunity;
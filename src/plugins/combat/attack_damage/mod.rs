@@ -2,7 +2,10 @@ use bevy::prelude::*;
 
 use self::{
     damage::{sum_damage_mod, AttackDamageSumEvent},
-    damage_modifier::{add_strength, base, weapon, AttackDamageModEvent},
+    damage_modifier::{
+        add_power_attack, add_sneak_attack, add_strength, add_weapon_specialization,
+        add_weapon_training, base, broken_weapon, weapon, weapon_properties, AttackDamageModEvent,
+    },
     damage_reduction::{debug_sum_damage_reduction, sum_damage_reduction, DRTotalEvent},
     damage_reduction_modifier::DRModEvent,
 };
@@ -30,7 +33,21 @@ impl Plugin for AttackDamagePlugin {
             .add_event::<DRModEvent>()
             .add_event::<DRTotalEvent>()
             // Attack Damage related systems
-            .add_systems(Update, (base, add_strength, weapon).in_set(AttackModifier))
+            .add_systems(
+                Update,
+                (
+                    base,
+                    add_strength,
+                    weapon,
+                    weapon_properties,
+                    broken_weapon,
+                    add_weapon_specialization,
+                    add_weapon_training,
+                    add_sneak_attack,
+                    add_power_attack,
+                )
+                    .in_set(AttackModifier),
+            )
             // Damage Reduction related systems
             .add_systems(
                 Update,
@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 
 use crate::{
-    components::attributes::{Attribute, Dexterity},
+    components::attributes::{AbilityAdjustments, Attribute, Dexterity},
     plugins::combat::bonus::BonusType,
 };
 
@@ -61,7 +61,7 @@ pub struct CombatReflexes;
 
 impl CombatReflexes {
     pub fn add_bonus(
-        dex_query: Query<&Dexterity, With<CombatReflexes>>,
+        dex_query: Query<(&Dexterity, Option<&AbilityAdjustments>), With<CombatReflexes>>,
         mut start_event: EventReader<AOORoundStart>,
         mut mod_event: EventWriter<AOORoundModEvent>,
     ) {
@@ -73,7 +73,7 @@ impl CombatReflexes {
                     **entity
                 );
             }
-            if let Ok(dexterity) = dex_query.get(**entity) {
+            if let Ok((dexterity, adjustments)) = dex_query.get(**entity) {
                 if debug {
                     println!(
                         "debug | CombatReflexes::add_bonus | aoos added to entity: {:?}",
@@ -82,7 +82,7 @@ impl CombatReflexes {
                 }
                 let mut aoo_mod =
                     AOORoundMod::new(AOOBonusSource::Dexterity, **entity, BonusType::Dexterity);
-                aoo_mod.add_attribute_bonus(*dexterity);
+                aoo_mod.add_attribute_bonus(dexterity.adjusted(adjustments));
 
                 mod_event.send(aoo_mod.into())
             }
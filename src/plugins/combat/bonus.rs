@@ -7,16 +7,40 @@ pub enum BonusType {
     Dodge,
     Strength,
     Dexterity,
+    Armor,
+    Shield,
+    NaturalArmor,
+    Circumstance,
+    Racial,
     Untyped, // more here
 }
 
 impl BonusType {
-    pub fn stackable() -> [Self; 2] {
-        [Self::Dodge, Self::Untyped]
+    pub fn stackable() -> [Self; 3] {
+        [Self::Dodge, Self::Circumstance, Self::Untyped]
     }
 
-    pub fn non_stackable() -> [Self; 4] {
-        [Self::Morale, Self::Strength, Self::Dexterity, Self::Size]
+    pub fn non_stackable() -> [Self; 8] {
+        [
+            Self::Morale,
+            Self::Racial,
+            Self::Strength,
+            Self::Dexterity,
+            Self::Size,
+            Self::Armor,
+            Self::Shield,
+            Self::NaturalArmor,
+        ]
+    }
+
+    /// Bonus types which do not apply to touch AC.
+    pub fn not_touch() -> [Self; 3] {
+        [Self::Armor, Self::Shield, Self::NaturalArmor]
+    }
+
+    /// Bonus types which do not apply to flat-footed AC. Penalties of these types still apply.
+    pub fn not_flat_footed() -> [Self; 2] {
+        [Self::Dexterity, Self::Dodge]
     }
 }
 
@@ -26,5 +50,24 @@ pub enum BonusSource {
     Strength,
     Dexterity,
     WeaponFocus,
-    BaseAttackBonus, // more here
+    BaseAttackBonus,
+    Armor,
+    Shield,
+    Prone,
+    Grappled,
+    Broken,
+    Flanking,
+    Cover,
+    Nonlethal,
+    Dodge,
+    PowerAttack,
+    CombatExpertise,
+    WeaponTraining,
+    Rage,
+    RollingDodge,
+    Hatred,
+    DefensiveTraining,
+    Nonproficient,
+    NonproficientArmor,
+    Size, // more here
 }
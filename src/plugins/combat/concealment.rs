@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::dice::Dice;
+
+#[derive(Component, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+/// Concealment gives attacks against the creature a chance to miss, rolled after the attack has
+/// hit. Effects such as blur or fog insert this component on the creatures they conceal, and
+/// monsters which are hard to see start with it from their stats.
+pub enum Concealment {
+    /// A 20% miss chance.
+    Partial,
+    /// A 50% miss chance.
+    Total,
+}
+
+impl Concealment {
+    /// The percent chance for an attack which hits to miss instead.
+    pub fn miss_chance(self) -> usize {
+        match self {
+            Concealment::Partial => 20,
+            Concealment::Total => 50,
+        }
+    }
+
+    /// Rolls the miss chance of this concealment.
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R) -> ConcealmentRoll {
+        ConcealmentRoll {
+            concealment: self,
+            roll: Dice::D100.roll_once(rng),
+        }
+    }
+}
+
+#[derive(Resource, Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// The light level of the current room, copied from the room when it is entered. Dim light conceals every creature in the room, and
+/// darkness totally conceals them.
+pub enum Lighting {
+    #[default]
+    Bright,
+    Dim,
+    Darkness,
+}
+
+impl Lighting {
+    pub fn concealment(self) -> Option<Concealment> {
+        match self {
+            Lighting::Bright => None,
+            Lighting::Dim => Some(Concealment::Partial),
+            Lighting::Darkness => Some(Concealment::Total),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The d100 roll made against the miss chance of the defender's concealment.
+pub struct ConcealmentRoll {
+    pub concealment: Concealment,
+    pub roll: usize,
+}
+
+impl ConcealmentRoll {
+    /// The attack misses if the roll is equal to or less than the miss chance.
+    pub fn is_miss(&self) -> bool {
+        self.roll <= self.concealment.miss_chance()
+    }
+}
+
+/// The concealment of the defender, which is the better of any concealment effect on it and the
+/// concealment given by the light level of the room. The light level does not conceal the defender
+/// from an attacker which sees it in the dark, e.g. with darkvision.
+pub fn concealment(
+    defender: Entity,
+    lighting: Lighting,
+    sees_in_dark: bool,
+    concealment_query: &Query<&Concealment>,
+) -> Option<Concealment> {
+    let lighting = if sees_in_dark {
+        None
+    } else {
+        lighting.concealment()
+    };
+    concealment_query.get(defender).ok().copied().max(lighting)
+}
@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+
+use crate::resources::dungeon::{
+    grid_occupancy::{GridOccupancy, Terrain},
+    grid_pos::GridPos,
+};
+
+use super::geometry;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+/// How much cover the defender has against an attack, found with the corner-to-corner rule: the
+/// attacker picks the corner of its square which gives the defender the least cover, and a line is
+/// traced from that corner to each corner of the defender's square. Every line which passes
+/// through a blocking square counts against the attack.
+pub enum Cover {
+    #[default]
+    None,
+    /// One of the lines is blocked, so most of the defender is visible.
+    Partial,
+    /// Two or three of the lines are blocked.
+    Standard,
+    /// All four lines are blocked, and the defender cannot be attacked.
+    Total,
+}
+
+impl Cover {
+    fn from_blocked_lines(blocked: usize) -> Self {
+        match blocked {
+            0 => Cover::None,
+            1 => Cover::Partial,
+            2 | 3 => Cover::Standard,
+            _ => Cover::Total,
+        }
+    }
+
+    /// The bonus to AC granted by this cover.
+    pub fn ac_bonus(self) -> isize {
+        match self {
+            Cover::None | Cover::Total => 0,
+            Cover::Partial => 2,
+            Cover::Standard => 4,
+        }
+    }
+}
+
+/// Finds the cover of a defender at `defender` against an attacker at `attacker`, where
+/// `obstacles` are the centres of the squares which block the line of fire. The squares of the
+/// attacker and defender themselves are ignored.
+pub fn cover_between(attacker: Vec2, defender: Vec2, obstacles: &[Vec2]) -> Cover {
+    let obstacles: Vec<Vec2> = obstacles
+        .iter()
+        .copied()
+        .filter(|square| {
+            square.distance(attacker) > geometry::EPSILON
+                && square.distance(defender) > geometry::EPSILON
+        })
+        .collect();
+
+    let least_blocked = geometry::square_corners(attacker)
+        .into_iter()
+        .map(|attacker_corner| {
+            geometry::square_corners(defender)
+                .into_iter()
+                .filter(|defender_corner| {
+                    obstacles.iter().any(|square| {
+                        geometry::clip_to_square(attacker_corner, *defender_corner, *square)
+                            .is_some()
+                    })
+                })
+                .count()
+        })
+        .min()
+        .unwrap_or_default();
+
+    Cover::from_blocked_lines(least_blocked)
+}
+
+/// Finds the cover the defender has against the attacker from the walls and pillars of the room.
+/// Low walls give cover too, but can be attacked over, so they never give more than standard
+/// cover. Other creatures only provide cover against ranged attacks.
+pub fn cover(attacker: Entity, defender: Entity, ranged: bool, occupancy: &GridOccupancy) -> Cover {
+    let debug = false;
+    // A creature's position is the top-left square of its space.
+    let (Some(attacker_square), Some(defender_square)) = (
+        occupancy.space(attacker).first(),
+        occupancy.space(defender).first(),
+    ) else {
+        return Cover::None;
+    };
+
+    let mut obstacles = occupancy.squares_where(Terrain::blocks_line);
+    let low_walls: Vec<Vec2> = occupancy
+        .squares_where(Terrain::gives_cover)
+        .into_iter()
+        .map(GridPos::to_world)
+        .collect();
+    if ranged {
+        obstacles.extend(occupancy.occupied_squares(&[attacker, defender]));
+    }
+    let obstacles: Vec<Vec2> = obstacles.into_iter().map(GridPos::to_world).collect();
+
+    let attacker_position = attacker_square.to_world();
+    let defender_position = defender_square.to_world();
+    let cover = cover_between(attacker_position, defender_position, &obstacles)
+        .max(cover_between(attacker_position, defender_position, &low_walls).min(Cover::Standard));
+    if debug {
+        println!("debug | cover::cover | defender cover: {:?}", cover);
+    }
+    cover
+}
@@ -1,4 +1,21 @@
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DamageType {
     Weapon,
+    /// Extra damage which relies on hitting a vital spot, e.g. sneak attack. It is never
+    /// multiplied on a critical hit.
+    Precision,
+    Energy(EnergyType),
+    /// Damage from an aligned source, e.g. the Holy weapon property.
+    Aligned,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EnergyType {
+    Fire,
+    Cold,
+    Electricity,
+    Acid,
+    Sonic,
 }
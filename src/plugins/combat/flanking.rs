@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::{creature::Faction, size::Size},
+    plugins::{combat::area::FEET_PER_SQUARE, item::equipment::weapon::EquippedWeapons},
+    resources::{
+        dungeon::{grid_occupancy::GridOccupancy, grid_pos::GridPos},
+        equipment::weapon::Weapon,
+    },
+};
+
+use super::geometry::{self, EPSILON};
+
+/// Whether the attacker threatens the target, that is, whether it holds a melee weapon with enough
+/// reach to attack some square of the target's space from some square of its own, without a wall
+/// in the way.
+pub fn threatens(
+    attacker: Entity,
+    size: Size,
+    target: Entity,
+    equipped_weapons: Option<&EquippedWeapons>,
+    weapon_query: &Query<&Weapon>,
+    occupancy: &GridOccupancy,
+) -> bool {
+    let Some(weapon) = equipped_weapons
+        .and_then(|equipped| equipped.main_hand)
+        .and_then(|entity| weapon_query.get(entity).ok())
+    else {
+        return false;
+    };
+    if !weapon.is_melee() {
+        return false;
+    }
+    let reach = (*weapon.reach().for_size(size) / FEET_PER_SQUARE) as i32;
+    let attacker_space = occupancy.space(attacker);
+    // Most creatures only threaten the squares around them.
+    if reach == 1 {
+        return attacker_space
+            .iter()
+            .any(|square| occupancy.creatures_adjacent_to(*square).contains(&target));
+    }
+    attacker_space.iter().any(|from| {
+        occupancy.space(target).iter().any(|to| {
+            let offset = *to - *from;
+            offset.x.abs().max(offset.y.abs()) <= reach && occupancy.line_clear(*from, *to)
+        })
+    })
+}
+
+/// Whether the line between the centres of the attacker's and ally's spaces passes through
+/// opposite borders, or opposite corners, of the space of a defender at `defender`.
+pub fn is_flanking_line(attacker: Vec2, ally: Vec2, defender: Vec2, defender_size: Size) -> bool {
+    let (lower, upper) = defender_size.space_bounds(defender);
+    let Some((entry, exit)) = geometry::clip_to_rect(attacker, ally, lower, upper) else {
+        return false;
+    };
+    let on_opposite_borders = |axis: usize| {
+        ((entry[axis] - lower[axis]).abs() < EPSILON && (exit[axis] - upper[axis]).abs() < EPSILON)
+            || ((entry[axis] - upper[axis]).abs() < EPSILON
+                && (exit[axis] - lower[axis]).abs() < EPSILON)
+    };
+    on_opposite_borders(0) || on_opposite_borders(1)
+}
+
+/// Whether the attacker is flanking the defender. The attacker must threaten the defender, and
+/// an ally of the attacker who also threatens the defender must be on the other side of the
+/// defender.
+pub fn is_flanking(
+    attacker: Entity,
+    defender: Entity,
+    creature_query: &Query<(Entity, &Faction, Option<&EquippedWeapons>, Option<&Size>)>,
+    weapon_query: &Query<&Weapon>,
+    occupancy: &GridOccupancy,
+) -> bool {
+    let debug = false;
+    let (Ok((_, attacker_faction, attacker_weapons, attacker_size)), Ok((_, _, _, defender_size))) =
+        (creature_query.get(attacker), creature_query.get(defender))
+    else {
+        return false;
+    };
+    // A creature's position is the top-left square of its space.
+    let position = |entity: Entity| {
+        occupancy
+            .space(entity)
+            .first()
+            .copied()
+            .map(GridPos::to_world)
+    };
+    let (Some(attacker_pos), Some(defender_pos)) = (position(attacker), position(defender)) else {
+        return false;
+    };
+    let attacker_size = attacker_size.copied().unwrap_or_default();
+    let defender_size = defender_size.copied().unwrap_or_default();
+    if !threatens(
+        attacker,
+        attacker_size,
+        defender,
+        attacker_weapons,
+        weapon_query,
+        occupancy,
+    ) {
+        return false;
+    }
+
+    let flanking_ally = creature_query
+        .iter()
+        .filter(|(entity, faction, ..)| {
+            *entity != attacker && *entity != defender && attacker_faction.is_ally(**faction)
+        })
+        .filter_map(|(entity, _, weapons, size)| {
+            let size = size.copied().unwrap_or_default();
+            position(entity).map(|ally_pos| (entity, ally_pos, size, weapons))
+        })
+        .find(|(ally, ally_pos, ally_size, weapons)| {
+            threatens(
+                *ally,
+                *ally_size,
+                defender,
+                *weapons,
+                weapon_query,
+                occupancy,
+            ) && is_flanking_line(
+                attacker_size.space_center(attacker_pos),
+                ally_size.space_center(*ally_pos),
+                defender_pos,
+                defender_size,
+            )
+        });
+
+    if debug {
+        println!(
+            "debug | flanking::is_flanking | flanking ally: {:?}",
+            flanking_ally.map(|(entity, ..)| entity)
+        );
+    }
+    flanking_ally.is_some()
+}
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+
+use crate::config::TILE_SIZE;
+
+/// Used to compare positions on the boundary of a square, which may be off by a small amount due
+/// to floating point error.
+pub const EPSILON: f32 = 0.01;
+
+/// The lower and upper corners of the grid square centred on `center`.
+pub fn square_bounds(center: Vec2) -> (Vec2, Vec2) {
+    let half = Vec2::splat(TILE_SIZE / 2.0);
+    (center - half, center + half)
+}
+
+/// The four corners of the grid square centred on `center`.
+pub fn square_corners(center: Vec2) -> [Vec2; 4] {
+    let (lower, upper) = square_bounds(center);
+    [
+        lower,
+        Vec2::new(upper.x, lower.y),
+        upper,
+        Vec2::new(lower.x, upper.y),
+    ]
+}
+
+/// Clips the line from `start` to `end` against the grid square centred on `center`, returning
+/// the points where the line enters and exits the square. Lines which only touch a border or a
+/// corner of the square do not pass through it, and return `None`.
+pub fn clip_to_square(start: Vec2, end: Vec2, center: Vec2) -> Option<(Vec2, Vec2)> {
+    let (lower, upper) = square_bounds(center);
+    clip_to_rect(start, end, lower, upper)
+}
+
+/// Clips the line from `start` to `end` against the rectangle from `lower` to `upper`, e.g. the
+/// space of a creature larger than one square. See `clip_to_square`.
+pub fn clip_to_rect(start: Vec2, end: Vec2, lower: Vec2, upper: Vec2) -> Option<(Vec2, Vec2)> {
+    let delta = end - start;
+
+    let mut t_enter: f32 = 0.0;
+    let mut t_exit: f32 = 1.0;
+    for axis in 0..2 {
+        if delta[axis].abs() < EPSILON {
+            if start[axis] <= lower[axis] + EPSILON || start[axis] >= upper[axis] - EPSILON {
+                return None;
+            }
+        } else {
+            let t_lower = (lower[axis] - start[axis]) / delta[axis];
+            let t_upper = (upper[axis] - start[axis]) / delta[axis];
+            t_enter = t_enter.max(t_lower.min(t_upper));
+            t_exit = t_exit.min(t_lower.max(t_upper));
+        }
+    }
+
+    let entry = start + delta * t_enter;
+    let exit = start + delta * t_exit;
+    if t_enter >= t_exit || entry.distance(exit) < EPSILON {
+        None
+    } else {
+        Some((entry, exit))
+    }
+}
@@ -0,0 +1,199 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::{
+        conditions::{Broken, Grappled, Prone},
+        creature::Creature,
+        size::Size,
+    },
+    config::TILE_SIZE,
+    plugins::{
+        actions::{event::TurnActionEvent, TurnAction, TurnActionStatus},
+        combat_mode::turn::action::CurrentTurn,
+        interact::Interactable,
+        item::equipment::weapon::EquippedWeapons,
+        player::control::ActionPriority,
+    },
+    resources::dungeon::{grid_occupancy::GridOccupancy, grid_pos::GridPos},
+};
+
+use super::{CombatManeuver, ManeuverOutcomeEvent};
+
+/// A successful trip knocks the defender prone. If the check fails by 10 or more, the attacker is
+/// knocked prone instead.
+pub fn trip(mut outcome_reader: EventReader<ManeuverOutcomeEvent>, mut commands: Commands) {
+    for outcome in outcome_reader
+        .iter()
+        .filter(|outcome| outcome.maneuver_data.maneuver == CombatManeuver::Trip)
+    {
+        if outcome.success {
+            commands
+                .entity(outcome.maneuver_data.defender)
+                .insert(Prone);
+        } else if outcome.margin <= -10 {
+            commands
+                .entity(outcome.maneuver_data.attacker)
+                .insert(Prone);
+        }
+    }
+}
+
+/// A successful disarm knocks the defender's main hand weapon out of its `EquippedWeapons`. If the
+/// check fails by 10 or more, the attacker drops its own main hand weapon instead.
+pub fn disarm(
+    mut outcome_reader: EventReader<ManeuverOutcomeEvent>,
+    mut equipped_query: Query<&mut EquippedWeapons>,
+    mut commands: Commands,
+) {
+    for outcome in outcome_reader
+        .iter()
+        .filter(|outcome| outcome.maneuver_data.maneuver == CombatManeuver::Disarm)
+    {
+        let disarmed = if outcome.success {
+            outcome.maneuver_data.defender
+        } else if outcome.margin <= -10 {
+            outcome.maneuver_data.attacker
+        } else {
+            continue;
+        };
+        if let Ok(mut equipped_weapons) = equipped_query.get_mut(disarmed) {
+            if let Some(weapon) = equipped_weapons.main_hand.take() {
+                // TODO: Drop the weapon on the ground so it can be picked up again.
+                commands.entity(disarmed).remove_children(&[weapon]);
+            }
+        }
+    }
+}
+
+/// A successful bull rush pushes the defender 5 feet directly away from the attacker, plus
+/// another 5 feet for every 5 by which the check exceeds the defender's CMD. The defender stops
+/// early if any square of its space would be blocked by a wall or another creature, see
+/// `GridOccupancy::is_passable`.
+pub fn bull_rush(
+    mut outcome_reader: EventReader<ManeuverOutcomeEvent>,
+    mut creature_query: Query<
+        (
+            Entity,
+            &mut Transform,
+            Option<&mut Interactable>,
+            Option<&Size>,
+        ),
+        With<Creature>,
+    >,
+    occupancy: Res<GridOccupancy>,
+) {
+    for outcome in outcome_reader.iter().filter(|outcome| {
+        outcome.maneuver_data.maneuver == CombatManeuver::BullRush && outcome.success
+    }) {
+        let data = outcome.maneuver_data;
+        let (
+            Ok((_, attacker_transform, _, attacker_size)),
+            Ok((_, defender_transform, _, defender_size)),
+        ) = (
+            creature_query.get(data.attacker),
+            creature_query.get(data.defender),
+        )
+        else {
+            continue;
+        };
+        let attacker_size = attacker_size.copied().unwrap_or_default();
+        let defender_size = defender_size.copied().unwrap_or_default();
+        let start = defender_transform.translation;
+        // Push away from the centre of the attacker's space, so larger creatures push straight.
+        let direction = (defender_size.space_center(start.truncate())
+            - attacker_size.space_center(attacker_transform.translation.truncate()))
+        .normalize_or_zero()
+        .round();
+        if direction == Vec2::ZERO {
+            continue;
+        }
+        let step = direction.extend(0.0) * TILE_SIZE;
+
+        let squares = 1 + outcome.margin / 5;
+        let mut destination = start;
+        for _ in 0..squares {
+            let next = destination + step;
+            let blocked = defender_size
+                .squares(next.truncate())
+                .into_iter()
+                .map(GridPos::from_world)
+                .any(|square| !occupancy.is_passable(square, Some(data.defender)));
+            if blocked {
+                break;
+            }
+            destination = next;
+        }
+
+        let offset = (destination - start).truncate();
+        if let Ok((_, mut transform, interactable, _)) = creature_query.get_mut(data.defender) {
+            transform.translation = destination;
+            if let Some(mut interactable) = interactable {
+                *interactable = Interactable::new_from_trans(
+                    interactable.bound_tr.lower + offset,
+                    interactable.bound_tr.upper + offset,
+                    interactable.interacting_type,
+                );
+            }
+        }
+    }
+}
+
+/// A successful grapple leaves both the attacker and the defender grappled with each other.
+pub fn grapple(mut outcome_reader: EventReader<ManeuverOutcomeEvent>, mut commands: Commands) {
+    for outcome in outcome_reader.iter().filter(|outcome| {
+        outcome.maneuver_data.maneuver == CombatManeuver::Grapple && outcome.success
+    }) {
+        let data = outcome.maneuver_data;
+        commands.entity(data.defender).insert(Grappled {
+            with: data.attacker,
+        });
+        commands.entity(data.attacker).insert(Grappled {
+            with: data.defender,
+        });
+    }
+}
+
+/// A successful sunder breaks the defender's main hand weapon. A weapon which is already broken
+/// is destroyed, and removed from the defender's `EquippedWeapons`.
+pub fn sunder(
+    mut outcome_reader: EventReader<ManeuverOutcomeEvent>,
+    mut equipped_query: Query<&mut EquippedWeapons>,
+    broken_query: Query<(), With<Broken>>,
+    mut commands: Commands,
+) {
+    for outcome in outcome_reader.iter().filter(|outcome| {
+        outcome.maneuver_data.maneuver == CombatManeuver::Sunder && outcome.success
+    }) {
+        let defender = outcome.maneuver_data.defender;
+        if let Ok(mut equipped_weapons) = equipped_query.get_mut(defender) {
+            if let Some(weapon) = equipped_weapons.main_hand {
+                if broken_query.contains(weapon) {
+                    equipped_weapons.main_hand = None;
+                    commands.entity(defender).remove_children(&[weapon]);
+                    commands.entity(weapon).despawn_recursive();
+                } else {
+                    commands.entity(weapon).insert(Broken);
+                }
+            }
+        }
+    }
+}
+
+/// A prone creature stands up at the start of its turn, which uses its move action.
+pub fn stand_up(
+    current_turn: Res<CurrentTurn>,
+    prone_query: Query<Option<&ActionPriority>, With<Prone>>,
+    mut turn_action_writer: EventWriter<TurnActionEvent>,
+    mut commands: Commands,
+) {
+    if let Ok(action_priority) = prone_query.get(current_turn.entity) {
+        commands.entity(current_turn.entity).remove::<Prone>();
+        // `ActionStatus` only tracks the actions of the player character for now.
+        if action_priority.is_some() {
+            turn_action_writer.send(TurnActionEvent {
+                turn_action: TurnAction::Move,
+                status: TurnActionStatus::Used,
+            });
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::{
+        attack_bonus::BaseAttackBonus,
+        attributes::{AbilityAdjustments, Dexterity, Strength},
+        combat_maneuver::{CombatManeuverBonus, CombatManeuverDefense},
+        conditions::{Grappled, Prone},
+        racial_traits::RacialTraits,
+        size::Size,
+    },
+    plugins::{
+        actions::{event::TurnActionEvent, ActionStatus, TurnAction, TurnActionStatus},
+        combat_mode::turn::action::CurrentTurn,
+        game_ui::action_bar::{
+            submenu_button::{AttackButton, SelectedSubMenu, SubMenuActions},
+            ActionBarButton, SelectedAction,
+        },
+        interact::{InteractingPos, InteractingType},
+        player::control::ActionPriority,
+    },
+    resources::dice::Dice,
+    scenes::SceneState,
+};
+
+pub mod effects;
+
+pub struct ManeuverPlugin;
+
+#[derive(Clone, Hash, Debug, PartialEq, Eq, SystemSet)]
+pub struct ManeuverResolve;
+
+#[derive(Clone, Hash, Debug, PartialEq, Eq, SystemSet)]
+pub struct ManeuverEffect;
+
+impl Plugin for ManeuverPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ManeuverDataEvent>()
+            .add_event::<ManeuverOutcomeEvent>();
+
+        app.configure_set(Update, ManeuverResolve.after(check_maneuver_conditions));
+        app.configure_set(Update, ManeuverEffect.after(ManeuverResolve));
+
+        app.add_systems(
+            Update,
+            (
+                update_combat_maneuver_stats,
+                check_maneuver_conditions.run_if(resource_exists_and_equals(SelectedAction(
+                    ActionBarButton::Attack,
+                ))),
+            )
+                .run_if(in_state(SceneState::InGameClassicMode)),
+        );
+
+        app.add_systems(Update, resolve_maneuver.in_set(ManeuverResolve));
+
+        app.add_systems(
+            Update,
+            (
+                effects::trip,
+                effects::disarm,
+                effects::bull_rush,
+                effects::grapple,
+                effects::sunder,
+            )
+                .in_set(ManeuverEffect),
+        );
+
+        app.add_systems(
+            Update,
+            effects::stand_up.run_if(resource_exists_and_changed::<CurrentTurn>()),
+        );
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CombatManeuver {
+    Trip,
+    Disarm,
+    BullRush,
+    Grapple,
+    Sunder,
+}
+
+impl TryFrom<AttackButton> for CombatManeuver {
+    type Error = &'static str;
+
+    fn try_from(value: AttackButton) -> Result<Self, Self::Error> {
+        match value {
+            AttackButton::Trip => Ok(CombatManeuver::Trip),
+            AttackButton::Disarm => Ok(CombatManeuver::Disarm),
+            AttackButton::BullRush => Ok(CombatManeuver::BullRush),
+            AttackButton::Grapple => Ok(CombatManeuver::Grapple),
+            AttackButton::Sunder => Ok(CombatManeuver::Sunder),
+            AttackButton::Single | AttackButton::Full => {
+                Err("The selected attack button is not a combat maneuver")
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// `ManeuverData` includes the entities used in a combat maneuver and the maneuver attempted. As
+/// with `AttackData`, the systems which resolve the maneuver can query these entities for any
+/// components they need.
+pub struct ManeuverData {
+    pub maneuver: CombatManeuver,
+    pub attacker: Entity,
+    pub defender: Entity,
+}
+
+#[derive(Copy, Clone, Deref, DerefMut, Event, Debug)]
+/// Sent by `check_maneuver_conditions` once all of the conditions for a combat maneuver have been
+/// met, and listened to by `resolve_maneuver`.
+pub struct ManeuverDataEvent(pub ManeuverData);
+
+#[derive(Copy, Clone, Debug)]
+/// The outcome of a combat maneuver check. `margin` is the amount by which the check beat the
+/// defender's CMD, and is negative when the check fails.
+pub struct ManeuverOutcome {
+    pub maneuver_data: ManeuverData,
+    pub roll_raw: usize,
+    pub roll_total: isize,
+    pub defender_cmd: isize,
+    pub success: bool,
+    pub margin: isize,
+}
+
+#[derive(Copy, Clone, Deref, Event, Debug)]
+/// Sent by `resolve_maneuver` and listened to by the systems in `effects`, which apply the result
+/// of the maneuver.
+pub struct ManeuverOutcomeEvent(ManeuverOutcome);
+
+/// Keeps the combat maneuver bonus and defense of each creature up to date with its base attack
+/// bonus, adjusted Strength and Dexterity, and size.
+pub fn update_combat_maneuver_stats(
+    mut commands: Commands,
+    creature_query: Query<
+        (
+            Entity,
+            Option<&BaseAttackBonus>,
+            &Strength,
+            &Dexterity,
+            Option<&AbilityAdjustments>,
+            Option<&Size>,
+        ),
+        Or<(
+            Changed<BaseAttackBonus>,
+            Changed<Strength>,
+            Changed<Dexterity>,
+            Changed<AbilityAdjustments>,
+            Changed<Size>,
+        )>,
+    >,
+) {
+    for (entity, bab, strength, dexterity, adjustments, size) in creature_query.iter() {
+        let size = size.copied().unwrap_or_default();
+        let strength = strength.adjusted(adjustments);
+        commands.entity(entity).insert((
+            CombatManeuverBonus::new(bab, strength, size),
+            CombatManeuverDefense::new(bab, strength, dexterity.adjusted(adjustments), size),
+        ));
+    }
+}
+
+/// This is where a combat maneuver begins. When a combat maneuver is selected in the attack
+/// submenu and an enemy is clicked, this sends a `ManeuverDataEvent` for `resolve_maneuver`.
+/// In combat, a combat maneuver takes the standard action of the turn, so it can only be made
+/// while the standard action is available.
+pub fn check_maneuver_conditions(
+    interacting_pos: Res<InteractingPos>,
+    button: Res<Input<MouseButton>>,
+    selected_submenu: Res<SelectedSubMenu>,
+    action_status: Option<Res<ActionStatus>>,
+    attacker_query: Query<Entity, With<ActionPriority>>,
+    mut maneuver_writer: EventWriter<ManeuverDataEvent>,
+    mut turn_action_writer: EventWriter<TurnActionEvent>,
+) {
+    let debug = false;
+    let attack_submenu = selected_submenu.attack_submenu;
+    let Ok(maneuver) = CombatManeuver::try_from(attack_submenu) else {
+        return;
+    };
+    let Some(defender) = interacting_pos.entity else {
+        return;
+    };
+    if interacting_pos.interacting_type != InteractingType::Enemy
+        || !button.just_pressed(MouseButton::Left)
+    {
+        return;
+    }
+    if action_status
+        .as_deref()
+        .is_some_and(|action_status| !attack_submenu.is_action_available(action_status))
+    {
+        if debug {
+            println!("debug | check_maneuver_conditions | the standard action has been used");
+        }
+        return;
+    }
+
+    // TODO: Check if target is in reach
+    let attacker = attacker_query.get_single().unwrap();
+    let maneuver_data = ManeuverData {
+        maneuver,
+        attacker,
+        defender,
+    };
+    if debug {
+        println!(
+            "debug | check_maneuver_conditions | sending: {:?}",
+            maneuver_data
+        );
+    }
+    maneuver_writer.send(ManeuverDataEvent(maneuver_data));
+    // `ActionStatus` only exists in combat.
+    if action_status.is_some() {
+        turn_action_writer.send(TurnActionEvent {
+            turn_action: TurnAction::Standard,
+            status: TurnActionStatus::Used,
+        });
+    }
+}
+
+/// Rolls the combat maneuver check against the defender's CMD and sends the outcome.
+pub fn resolve_maneuver(
+    mut maneuver_reader: EventReader<ManeuverDataEvent>,
+    mut outcome_writer: EventWriter<ManeuverOutcomeEvent>,
+    attacker_query: Query<(&CombatManeuverBonus, Option<&Prone>, Option<&Grappled>)>,
+    defender_query: Query<(&CombatManeuverDefense, Option<&RacialTraits>)>,
+) {
+    let debug = false;
+    for maneuver_data in maneuver_reader.iter() {
+        let (Ok((cmb, prone, grappled)), Ok((cmd, racial_traits))) = (
+            attacker_query.get(maneuver_data.attacker),
+            defender_query.get(maneuver_data.defender),
+        ) else {
+            continue;
+        };
+
+        // Prone and grappled creatures take the same penalties to their combat maneuver checks as
+        // to their attack rolls. Grappled creatures do not take the penalty to grapple.
+        let mut condition_penalty = 0;
+        if prone.is_some() {
+            condition_penalty -= 4;
+        }
+        if grappled.is_some() && maneuver_data.maneuver != CombatManeuver::Grapple {
+            condition_penalty -= 2;
+        }
+
+        let defender_cmd = **cmd
+            + racial_traits.map_or(0, |racial_traits| {
+                racial_traits.cmd_bonus(maneuver_data.maneuver)
+            });
+
+        let mut rng = rand::thread_rng();
+        let roll_raw = Dice::D20.roll_once(&mut rng);
+        let roll_total = roll_raw as isize + **cmb + condition_penalty;
+        let margin = roll_total - defender_cmd;
+        let success = roll_raw == 20 || (roll_raw != 1 && margin >= 0);
+
+        let outcome = ManeuverOutcome {
+            maneuver_data: **maneuver_data,
+            roll_raw,
+            roll_total,
+            defender_cmd,
+            success,
+            margin,
+        };
+        if debug {
+            debug_resolve_maneuver(outcome);
+        }
+        outcome_writer.send(ManeuverOutcomeEvent(outcome));
+    }
+}
+
+fn debug_resolve_maneuver(outcome: ManeuverOutcome) {
+    println!("{:-<10}", "start resolve_maneuver");
+    println!(
+        "      |                     | maneuver: {:?}",
+        outcome.maneuver_data.maneuver
+    );
+    println!(
+        "      |                     | D20 roll: {}",
+        outcome.roll_raw
+    );
+    println!(
+        "      |                     | total CMB roll: {}",
+        outcome.roll_total
+    );
+    println!(
+        "      |                     | defender CMD: {}",
+        outcome.defender_cmd
+    );
+    println!(
+        "      |                     | success: {}, margin: {}",
+        outcome.success, outcome.margin
+    );
+}
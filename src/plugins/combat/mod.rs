@@ -3,13 +3,17 @@
 use bevy::prelude::*;
 
 use crate::{
-    resources::{dice::Dice, equipment::weapon::Weapon},
+    components::{
+        class_features::UncannyDodge, conditions::FlatFooted, creature::Faction,
+        racial_traits::Darkvision, size::Size,
+    },
+    resources::{dice::Dice, dungeon::grid_occupancy::GridOccupancy, equipment::weapon::Weapon},
     scenes::SceneState,
 };
 
 use self::{
     attack::{
-        armor_class::ACBonusSumEvent,
+        armor_class::{ACBonusSumEvent, TargetAC},
         attack_roll::AttackBonusSumEvent,
         crit_multiplier::{CritMultiplier, CritMultiplierSumEvent},
         critical_range::CritRangeModSumEvent,
@@ -19,10 +23,18 @@ use self::{
         damage::AttackDamageSumEvent, damage_reduction::DRTotalEvent, AttackDamagePlugin,
     },
     attack_of_opportunity::AOORoundPlugin,
+    concealment::{Concealment, ConcealmentRoll, Lighting},
+    cover::Cover,
+    maneuver::{CombatManeuver, ManeuverPlugin},
 };
 
 use super::{
-    game_ui::action_bar::{ActionBarButton, SelectedAction},
+    combat_mode::{state::CombatMode, turn::action::CurrentTurn},
+    game_ui::{
+        action_bar::{submenu_button::SelectedSubMenu, ActionBarButton, SelectedAction},
+        combat_mode::CombatModeRes,
+    },
+    health::{ApplyHealth, DamageEvent, DamageSource, DealNonlethal},
     interact::{InteractingPos, InteractingType},
     item::equipment::weapon::EquippedWeapons,
     player::{
@@ -32,11 +44,19 @@ use super::{
     },
 };
 
+pub mod area;
 pub mod attack;
 pub mod attack_damage;
 pub mod attack_of_opportunity;
 pub mod bonus;
+pub mod concealment;
+pub mod cover;
 pub mod damage;
+pub mod flanking;
+pub mod geometry;
+pub mod maneuver;
+pub mod rage;
+pub mod stance;
 
 pub struct CombatPlugin;
 
@@ -58,6 +78,13 @@ pub struct AttackDataEvent(pub AttackData);
 ///     primary or secondary natural attack.
 /// - `IterativeAttack`: If the character has more than +5 attack bonus and is using a weapon,
 ///     which of the iterative attack bonuses to apply.
+/// - `TargetAC`: Whether the attack is made against the defender's normal, touch, or flat-footed
+///     armor class.
+/// - `flanking`: Whether the attacker is flanking the defender when the attack is made.
+/// - `Cover`: How much cover the defender has against the attacker.
+/// - `nonlethal`: Whether the attack deals nonlethal damage.
+/// - `Concealment`: The concealment of the defender, if any, which gives the attack a chance to
+///     miss after it hits.
 /// When the attack/crit/damage systems need to know, e.g., what weapon type is used in the attack,
 /// they can query the entity to find the relevent components, if they exist.
 pub struct AttackData {
@@ -65,6 +92,11 @@ pub struct AttackData {
     pub iterative_attack: IterativeAttack,
     pub attacker: Entity,
     pub defender: Entity,
+    pub target_ac: TargetAC,
+    pub flanking: bool,
+    pub cover: Cover,
+    pub concealment: Option<Concealment>,
+    pub nonlethal: bool,
 }
 
 #[derive(Copy, Clone)]
@@ -77,6 +109,21 @@ pub struct CompleteAttack {
     roll_total: isize,
     defender_ac: isize,
     attack_data: AttackData,
+    /// The roll against the defender's concealment, which is only made if the attack hits. A hit
+    /// which fails this roll becomes a `Miss`.
+    concealment_roll: Option<ConcealmentRoll>,
+}
+
+impl CompleteAttack {
+    /// The cover the defender had against the attack.
+    pub fn cover(&self) -> Cover {
+        self.attack_data.cover
+    }
+
+    /// Whether the attack would have hit, but missed due to the defender's concealment.
+    pub fn missed_from_concealment(&self) -> bool {
+        self.concealment_roll.is_some_and(|roll| roll.is_miss())
+    }
 }
 
 #[derive(Clone, Hash, Debug, PartialEq, Eq, SystemSet)]
@@ -93,9 +140,16 @@ impl Plugin for CombatPlugin {
         app
             // Attack outcome and associated data, used by both AttackPlugin and AttackDamagePlugin
             .add_event::<CompleteAttackEvent>()
-            .add_event::<AttackDataEvent>();
+            .add_event::<AttackDataEvent>()
+            .add_event::<rage::ToggleRage>()
+            .init_resource::<Lighting>();
 
-        app.add_plugins((AttackPlugin, AttackDamagePlugin, AOORoundPlugin));
+        app.add_plugins((
+            AttackPlugin,
+            AttackDamagePlugin,
+            AOORoundPlugin,
+            ManeuverPlugin,
+        ));
 
         app.add_systems(
             Update,
@@ -111,6 +165,19 @@ impl Plugin for CombatPlugin {
         app.configure_set(Update, DebugSet.after(SumModifier));
 
         app.add_systems(Update, evaluate_complete_attack.after(SumModifier));
+        app.add_systems(
+            Update,
+            (
+                stance::end_stances.run_if(resource_exists_and_changed::<CurrentTurn>()),
+                rage::tick_rage.run_if(resource_exists_and_changed::<CurrentTurn>()),
+                rage::toggle_rage.run_if(in_state(SceneState::InGameClassicMode)),
+                rage::end_rage_out_of_combat.run_if(
+                    resource_exists_and_changed::<CombatModeRes>()
+                        .and_then(resource_equals(CombatModeRes(CombatMode::OutOfCombat))),
+                ),
+            ),
+        );
+        app.configure_set(Update, ApplyHealth.after(evaluate_complete_attack));
     }
 }
 
@@ -124,10 +191,27 @@ pub fn check_attack_conditions(
     mut attack_event_writer: EventWriter<StartAttack>,
     button: Res<Input<MouseButton>>,
     selected_action: Res<SelectedAction>,
+    selected_submenu: Res<SelectedSubMenu>,
 
     // TODO: Move the below arguments into the system which prompts the attack, once it has been
     // created.
-    attacker_query: Query<(Entity, &EquippedWeapons), With<ActionPriority>>,
+    attacker_query: Query<
+        (
+            Entity,
+            &EquippedWeapons,
+            Option<&DealNonlethal>,
+            Option<&Darkvision>,
+        ),
+        With<ActionPriority>,
+    >,
+    flat_footed_query: Query<(), (With<FlatFooted>, Without<UncannyDodge>)>,
+    creature_query: Query<(Entity, &Faction, Option<&EquippedWeapons>, Option<&Size>)>,
+    weapon_query: Query<&Weapon>,
+    (occupancy, concealment_query, lighting): (
+        Res<GridOccupancy>,
+        Query<&Concealment>,
+        Res<Lighting>,
+    ),
     mut attack_data_writer: EventWriter<AttackDataEvent>,
 ) {
     let debug = false;
@@ -138,28 +222,86 @@ pub fn check_attack_conditions(
             interacting_pos.entity.is_some()
         );
     }
+    // Combat maneuvers are started by `maneuver::check_maneuver_conditions` instead.
+    let is_maneuver = CombatManeuver::try_from(selected_submenu.attack_submenu).is_ok();
     if **selected_action == ActionBarButton::Attack
+        && !is_maneuver
         && interacting_pos.interacting_type == InteractingType::Enemy
         && interacting_pos.entity.is_some()
         && button.just_pressed(MouseButton::Left)
     {
+        // TODO: Only for testing, change values when moving to another system that will prompt an
+        // attack.
+        let (attacker_entity, equipped_weapon_entity, deal_nonlethal, darkvision) =
+            attacker_query.get_single().unwrap();
+        // TODO: Allow unarmed strikes once they are implemented.
+        let Some(main_hand) = equipped_weapon_entity.main_hand else {
+            return;
+        };
+
+        let defender = interacting_pos.entity.unwrap();
+        let weapon = weapon_query.get(main_hand).ok();
+        let ranged = weapon.is_some_and(|weapon| !weapon.is_melee());
+        let nonlethal =
+            deal_nonlethal.is_some() || weapon.is_some_and(|weapon| weapon.is_nonlethal());
+        let cover = cover::cover(attacker_entity, defender, ranged, &occupancy);
+        if cover == Cover::Total {
+            if debug {
+                println!("debug | check_attack_conditions | defender has total cover");
+            }
+            return;
+        }
+
+        // Darkvision only sees through the dark out to its range.
+        let sees_in_dark = darkvision.is_some_and(|darkvision| {
+            match (
+                occupancy.space(attacker_entity).first(),
+                occupancy.space(defender).first(),
+            ) {
+                (Some(from), Some(to)) => {
+                    area::distance(from.to_world(), to.to_world()) <= **darkvision
+                }
+                _ => false,
+            }
+        });
+
         // TODO: Check if target is in range
         attack_event_writer.send(StartAttack);
 
-        // TODO: Only for testing, change values when moving to another system that will prompt an
-        // attack.
-        let (attacker_entity, equipped_weapon_entity) = attacker_query.get_single().unwrap();
+        // Weapon attacks target normal AC, unless the defender is flat-footed and does not have
+        // Uncanny Dodge.
+        let target_ac = if flat_footed_query.contains(defender) {
+            TargetAC::Normal.flat_footed()
+        } else {
+            TargetAC::Normal
+        };
         let start_attack_data = AttackData {
             weapon_slot: WeaponSlot {
                 // The enum in `slot` should be be supplied by the system which prompts the attack.
                 slot: WeaponSlotName::MainHand,
-                entity: equipped_weapon_entity.main_hand,
+                entity: main_hand,
             },
             // The enum in `iterative_attack` should be supplied by the system which prompts the
             // attack.
             iterative_attack: IterativeAttack::First,
             attacker: attacker_entity,
-            defender: interacting_pos.entity.unwrap(),
+            defender,
+            target_ac,
+            flanking: flanking::is_flanking(
+                attacker_entity,
+                defender,
+                &creature_query,
+                &weapon_query,
+                &occupancy,
+            ),
+            cover,
+            concealment: concealment::concealment(
+                defender,
+                *lighting,
+                sees_in_dark,
+                &concealment_query,
+            ),
+            nonlethal,
         };
         attack_data_writer.send(AttackDataEvent(start_attack_data));
     }
@@ -179,6 +321,7 @@ pub fn evaluate_complete_attack(
     mut damage_finished: EventReader<AttackDamageSumEvent>,
     mut dr_total_reader: EventReader<DRTotalEvent>,
     mut complete_attack_writer: EventWriter<CompleteAttackEvent>,
+    mut damage_writer: EventWriter<DamageEvent>,
     weapon_query: Query<&Weapon>,
 ) {
     for (
@@ -205,7 +348,7 @@ pub fn evaluate_complete_attack(
         let mut rng = rand::thread_rng();
         let attack_roll_raw = Dice::D20.roll_once(&mut rng);
         let attack_modifier = atk_mod.total_attack_bonus;
-        let total_defender_ac = 10 + ac_mod.total_ac_bonus;
+        let total_defender_ac = ac_mod.target_total();
         let crit_range_lower = crit_range_mod.lower_crit();
 
         let attack_roll_total: isize = attack_roll_raw as isize + attack_modifier;
@@ -221,6 +364,18 @@ pub fn evaluate_complete_attack(
         } else {
             AttackOutcome::Miss
         };
+        // Concealment is only rolled once the attack is known to hit.
+        let concealment_roll = match outcome {
+            AttackOutcome::Hit | AttackOutcome::CritHit => attack_data
+                .concealment
+                .map(|concealment| concealment.roll(&mut rng)),
+            AttackOutcome::Miss | AttackOutcome::CritMiss => None,
+        };
+        let outcome = if concealment_roll.is_some_and(|roll| roll.is_miss()) {
+            AttackOutcome::Miss
+        } else {
+            outcome
+        };
         let mut total_damage: Option<usize> = None;
         println!("crit_multiplier.size(): {}", crit_multiplier.size());
         println!("damage.weapon_damage: {}", damage.weapon_damage);
@@ -250,6 +405,14 @@ pub fn evaluate_complete_attack(
                 total_damage = Some(total_damage.unwrap().saturating_sub(lowest_dr));
             }
         }
+        if let Some(amount) = total_damage {
+            damage_writer.send(DamageEvent {
+                target: attack_data.defender,
+                amount,
+                nonlethal: attack_data.nonlethal,
+                source: DamageSource::Weapon(attack_data.weapon_slot.entity),
+            });
+        }
 
         let complete_attack = CompleteAttack {
             attack_modifier,
@@ -260,6 +423,7 @@ pub fn evaluate_complete_attack(
             defender_ac: total_defender_ac,
             attack_data: **attack_data,
             crit_multiplier: crit_multiplier.val,
+            concealment_roll,
         };
         complete_attack_writer.send(CompleteAttackEvent(complete_attack));
         debug_complete_attack(
@@ -271,6 +435,7 @@ pub fn evaluate_complete_attack(
             **attack_data,
             crit_multiplier.val,
             total_damage,
+            concealment_roll,
         );
     }
 }
@@ -321,28 +486,48 @@ fn debug_complete_attack(
     attack_data: AttackData,
     crit_multiplier: CritMultiplier,
     total_damage: Option<usize>,
+    concealment_roll: Option<ConcealmentRoll>,
 ) {
     println!("      |                     | D20 roll: {}", attack_roll);
     println!(
         "      |                     | summed attack modifiers: {}",
         atk_event.total_attack_bonus
     );
+    for (source, bonus_type, val) in atk_event.breakdown.iter() {
+        println!(
+            "      |                     |     {:?} ({:?}): {}",
+            source, bonus_type, val
+        );
+    }
     println!(
         "      |                     | attack bonus total: {}",
         atk_event.total_attack_bonus
     );
     println!(
-        "      |                     | defender AC bonus: {}",
-        ac_event.total_ac_bonus
+        "      |                     | defender AC bonus: {} ({:?})",
+        ac_event.bonus_vs(attack_data.target_ac),
+        attack_data.target_ac
     );
     println!(
         "      |                     | defender AC total: {}",
-        10 + ac_event.total_ac_bonus
+        ac_event.target_total()
+    );
+    println!(
+        "      |                     | defender cover: {:?}",
+        attack_data.cover
     );
     println!(
         "      |                     | total attack roll with bonuses: {}",
         attack_roll_total
     );
+    if let Some(roll) = concealment_roll {
+        println!(
+            "      |                     | concealment: {:?}, d100 roll: {} vs {}% miss chance",
+            roll.concealment,
+            roll.roll,
+            roll.concealment.miss_chance()
+        );
+    }
     println!(
         "      |                     | attack outcome: {:?}",
         attack_outcome
@@ -0,0 +1,260 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::{
+        attributes::{ability_bonus, AbilityAdjustments, Attribute, Constitution},
+        conditions::Fatigued,
+        hitpoints::HitPoints,
+        level::CharacterLevel,
+    },
+    plugins::combat_mode::turn::action::CurrentTurn,
+};
+
+/// The morale bonus to Strength while raging.
+pub const RAGE_STRENGTH_BONUS: isize = 4;
+/// The morale bonus to Constitution while raging. The score itself is left alone, instead its
+/// change in bonus is added to Fortitude saves and hit points.
+pub const RAGE_CONSTITUTION_BONUS: usize = 4;
+/// The morale bonus on Will saves while raging.
+pub const RAGE_WILL_BONUS: isize = 2;
+/// The penalty to AC while raging.
+pub const RAGE_AC_PENALTY: isize = -2;
+/// The penalty to Strength and Dexterity while fatigued.
+pub const FATIGUE_PENALTY: isize = 2;
+
+#[derive(Component, Clone, Copy, Debug)]
+/// The Barbarian's rage, which lasts a limited number of rounds per day. Each round spent raging,
+/// including the round it starts, uses one of these rounds.
+pub struct Rage {
+    rounds_per_day: usize,
+    rounds_left: usize,
+    /// The rounds spent in the current rage, which sets how long the barbarian is fatigued once it
+    /// ends.
+    rounds_raged: usize,
+}
+
+impl Rage {
+    pub fn new(level: usize, constitution_bonus: isize) -> Self {
+        let rounds_per_day = Self::rounds_per_day(level, constitution_bonus);
+        Self {
+            rounds_per_day,
+            rounds_left: rounds_per_day,
+            rounds_raged: 0,
+        }
+    }
+
+    /// 4 rounds plus the Constitution bonus, and another 2 for every level after 1st.
+    pub fn rounds_per_day(level: usize, constitution_bonus: isize) -> usize {
+        (4 + constitution_bonus).max(0) as usize + 2 * level.saturating_sub(1)
+    }
+
+    /// Gaining a level adds its rounds to those left today.
+    pub fn set_level(&mut self, level: usize, constitution_bonus: isize) {
+        let rounds_per_day = Self::rounds_per_day(level, constitution_bonus);
+        self.rounds_left += rounds_per_day.saturating_sub(self.rounds_per_day);
+        self.rounds_per_day = rounds_per_day;
+    }
+
+    pub fn rounds_left(&self) -> usize {
+        self.rounds_left
+    }
+
+    /// Restores all rounds of rage, e.g. after resting.
+    pub fn refresh(&mut self) {
+        self.rounds_left = self.rounds_per_day;
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+/// A creature in a rage. While raging it has +4 Strength in its `AbilityAdjustments`, +2 hit
+/// points per level, a +2 morale bonus on Will saves and a -2 penalty to AC.
+pub struct Raging {
+    /// The bonus hit points of the rage, which are lost when it ends.
+    bonus_hit_points: usize,
+}
+
+impl Raging {
+    /// The change in the Constitution bonus from the rage. A creature without a Constitution score
+    /// gains nothing from it.
+    pub fn constitution_bonus(constitution: Option<&Constitution>) -> isize {
+        constitution.map_or(0, |constitution| {
+            ability_bonus(**constitution + RAGE_CONSTITUTION_BONUS) - constitution.bonus()
+        })
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+/// The rounds left of the fatigue after a rage, which lasts twice as long as the rage did.
+pub struct RageFatigue {
+    rounds_left: usize,
+}
+
+#[derive(Event, Clone, Copy, Debug, Deref)]
+/// Starts the rage of a creature, or ends it if it is already raging.
+pub struct ToggleRage(pub Entity);
+
+type RagerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut Rage,
+        Option<&'static Raging>,
+        Option<&'static mut RageFatigue>,
+        &'static mut AbilityAdjustments,
+        Option<&'static mut HitPoints>,
+        Option<&'static CharacterLevel>,
+        Option<&'static Constitution>,
+    ),
+>;
+
+/// Starts or ends a rage. A rage cannot start while the creature is fatigued or out of rounds.
+pub fn toggle_rage(
+    mut commands: Commands,
+    mut toggle_reader: EventReader<ToggleRage>,
+    mut rager_query: RagerQuery,
+) {
+    let debug = false;
+    for toggle in toggle_reader.iter() {
+        let Ok((
+            entity,
+            mut rage,
+            raging,
+            fatigue,
+            mut adjustments,
+            hit_points,
+            level,
+            constitution,
+        )) = rager_query.get_mut(**toggle)
+        else {
+            continue;
+        };
+        if let Some(raging) = raging {
+            end_rage(
+                &mut commands,
+                entity,
+                &mut rage,
+                raging,
+                &mut adjustments,
+                hit_points,
+            );
+        } else if fatigue.is_some() || rage.rounds_left == 0 {
+            if debug {
+                println!(
+                    "debug | rage::toggle_rage | {:?} cannot rage, fatigued: {}, rounds left: {}",
+                    **toggle,
+                    fatigue.is_some(),
+                    rage.rounds_left
+                );
+            }
+        } else {
+            rage.rounds_left -= 1;
+            rage.rounds_raged = 1;
+            adjustments.strength += RAGE_STRENGTH_BONUS;
+            let bonus_hit_points = Raging::constitution_bonus(constitution).max(0) as usize
+                * level.map_or(1, |level| level.level);
+            if let Some(mut hit_points) = hit_points {
+                hit_points.increase_max(bonus_hit_points);
+            }
+            commands
+                .entity(**toggle)
+                .insert(Raging { bonus_hit_points });
+            if debug {
+                println!(
+                    "debug | rage::toggle_rage | {:?} starts raging, rounds left: {}",
+                    **toggle, rage.rounds_left
+                );
+            }
+        }
+    }
+}
+
+/// At the start of a raging creature's turn, spends a round of rage, or ends the rage if there are
+/// none left. At the start of a fatigued creature's turn, counts down its fatigue.
+pub fn tick_rage(
+    mut commands: Commands,
+    current_turn: Res<CurrentTurn>,
+    mut rager_query: RagerQuery,
+) {
+    let entity = current_turn.entity;
+    let Ok((entity, mut rage, raging, fatigue, mut adjustments, hit_points, _, _)) =
+        rager_query.get_mut(entity)
+    else {
+        return;
+    };
+    if let Some(raging) = raging {
+        if rage.rounds_left == 0 {
+            end_rage(
+                &mut commands,
+                entity,
+                &mut rage,
+                raging,
+                &mut adjustments,
+                hit_points,
+            );
+        } else {
+            rage.rounds_left -= 1;
+            rage.rounds_raged += 1;
+        }
+    } else if let Some(mut fatigue) = fatigue {
+        fatigue.rounds_left = fatigue.rounds_left.saturating_sub(1);
+        if fatigue.rounds_left == 0 {
+            end_fatigue(&mut commands, entity, &mut adjustments);
+        }
+    }
+}
+
+/// When combat ends, a raging creature stops raging without becoming fatigued, and the fatigue of
+/// an earlier rage wears off.
+pub fn end_rage_out_of_combat(mut commands: Commands, mut rager_query: RagerQuery) {
+    for (entity, mut rage, raging, fatigue, mut adjustments, hit_points, _, _) in
+        rager_query.iter_mut()
+    {
+        if let Some(raging) = raging {
+            remove_rage_bonuses(&mut adjustments, raging, hit_points);
+            commands.entity(entity).remove::<Raging>();
+            rage.rounds_raged = 0;
+        } else if fatigue.is_some() {
+            end_fatigue(&mut commands, entity, &mut adjustments);
+        }
+    }
+}
+
+/// Removes the bonuses of the rage, then leaves the creature fatigued for twice as many rounds as
+/// it raged.
+fn end_rage(
+    commands: &mut Commands,
+    entity: Entity,
+    rage: &mut Rage,
+    raging: &Raging,
+    adjustments: &mut AbilityAdjustments,
+    hit_points: Option<Mut<HitPoints>>,
+) {
+    remove_rage_bonuses(adjustments, raging, hit_points);
+    adjustments.strength -= FATIGUE_PENALTY;
+    adjustments.dexterity -= FATIGUE_PENALTY;
+    commands.entity(entity).remove::<Raging>().insert((
+        Fatigued,
+        RageFatigue {
+            rounds_left: 2 * rage.rounds_raged,
+        },
+    ));
+    rage.rounds_raged = 0;
+}
+
+fn remove_rage_bonuses(
+    adjustments: &mut AbilityAdjustments,
+    raging: &Raging,
+    hit_points: Option<Mut<HitPoints>>,
+) {
+    adjustments.strength -= RAGE_STRENGTH_BONUS;
+    if let Some(mut hit_points) = hit_points {
+        hit_points.decrease_max(raging.bonus_hit_points);
+    }
+}
+
+fn end_fatigue(commands: &mut Commands, entity: Entity, adjustments: &mut AbilityAdjustments) {
+    adjustments.strength += FATIGUE_PENALTY;
+    adjustments.dexterity += FATIGUE_PENALTY;
+    commands.entity(entity).remove::<(Fatigued, RageFatigue)>();
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use std::slice::Iter;
+
+use crate::{
+    components::attack_bonus::BaseAttackBonus, plugins::combat_mode::turn::action::CurrentTurn,
+};
+
+use super::bonus::BonusSource;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Combat stances are feats the creature chooses to use each turn, rather than passive bonuses.
+/// Each trades a penalty on melee attack rolls for a bonus elsewhere:
+/// - `PowerAttack`: +2 damage for every -1 on attack rolls.
+/// - `CombatExpertise`: +1 dodge bonus to AC for every -1 on attack rolls.
+pub enum Stance {
+    PowerAttack,
+    CombatExpertise,
+}
+
+impl Stance {
+    pub fn iterator() -> Iter<'static, Self> {
+        [Stance::PowerAttack, Stance::CombatExpertise].iter()
+    }
+
+    /// The size of the trade: 1, plus 1 for every 4 points of base attack bonus.
+    pub fn steps(bab: Option<&BaseAttackBonus>) -> isize {
+        1 + bab.map_or(0, |bab| **bab).max(0) / 4
+    }
+
+    pub fn bonus_source(self) -> BonusSource {
+        match self {
+            Stance::PowerAttack => BonusSource::PowerAttack,
+            Stance::CombatExpertise => BonusSource::CombatExpertise,
+        }
+    }
+}
+
+#[derive(Component, Clone, Debug, Default, Deref)]
+/// The stances a creature has chosen. Stances last until the start of the creature's next turn.
+pub struct ActiveStances(Vec<Stance>);
+
+impl ActiveStances {
+    pub fn is_active(&self, stance: Stance) -> bool {
+        self.contains(&stance)
+    }
+
+    pub fn toggle(&mut self, stance: Stance) {
+        if self.is_active(stance) {
+            self.0.retain(|active| *active != stance);
+        } else {
+            self.0.push(stance);
+        }
+    }
+}
+
+/// Ends the stances of a creature at the start of its turn, so they must be chosen again.
+pub fn end_stances(current_turn: Res<CurrentTurn>, mut stance_query: Query<&mut ActiveStances>) {
+    if let Ok(mut stances) = stance_query.get_mut(current_turn.entity) {
+        if !stances.is_empty() {
+            stances.0.clear();
+        }
+    }
+}
@@ -2,7 +2,11 @@ use bevy::prelude::*;
 
 use crate::components::attributes::Attribute;
 use crate::{
-    components::{attributes::Dexterity, creature::Creature},
+    components::{
+        attributes::{AbilityAdjustments, Dexterity},
+        creature::Creature,
+        feats::combat_feats::ImprovedInitiative,
+    },
     plugins::combat::bonus::BonusType,
 };
 
@@ -11,6 +15,7 @@ use super::StartInitiative;
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum InitiativeBonusSource {
     Dexterity,
+    ImprovedInitiative,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -57,12 +62,12 @@ impl From<InitiativeModEvent> for InitiativeMod {
 pub fn base_initiative(
     mut event_reader: EventReader<StartInitiative>,
     mut event_writer: EventWriter<InitiativeModEvent>,
-    query_dexterity: Query<&Dexterity, With<Creature>>,
+    query_dexterity: Query<(&Dexterity, Option<&AbilityAdjustments>), With<Creature>>,
 ) {
     for creature in event_reader.into_iter() {
-        if let Ok(dexterity) = query_dexterity.get(**creature) {
+        if let Ok((dexterity, adjustments)) = query_dexterity.get(**creature) {
             let initiative_event = InitiativeModEvent::from(InitiativeMod {
-                bonus: dexterity.bonus(),
+                bonus: dexterity.adjusted(adjustments).bonus(),
                 source: InitiativeBonusSource::Dexterity,
                 bonus_type: BonusType::Untyped,
                 entity: **creature,
@@ -80,3 +85,21 @@ pub fn base_initiative(
         }
     }
 }
+
+/// Adds the +4 bonus of the `ImprovedInitiative` feat.
+pub fn improved_initiative(
+    mut event_reader: EventReader<StartInitiative>,
+    mut event_writer: EventWriter<InitiativeModEvent>,
+    query_feat: Query<(), (With<Creature>, With<ImprovedInitiative>)>,
+) {
+    for creature in event_reader.into_iter() {
+        if query_feat.contains(**creature) {
+            event_writer.send(InitiativeModEvent::from(InitiativeMod {
+                bonus: 4,
+                source: InitiativeBonusSource::ImprovedInitiative,
+                bonus_type: BonusType::Untyped,
+                entity: **creature,
+            }));
+        }
+    }
+}
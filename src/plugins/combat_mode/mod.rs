@@ -59,7 +59,11 @@ impl Plugin for CombatModePlugin {
             )
             .add_systems(
                 Update,
-                (initiative_modifier::base_initiative).in_set(ModSet),
+                (
+                    initiative_modifier::base_initiative,
+                    initiative_modifier::improved_initiative,
+                )
+                    .in_set(ModSet),
             )
             .add_systems(Update, sum_initiative_modifiers.in_set(SumSet));
 
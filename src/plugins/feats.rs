@@ -0,0 +1,208 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::{
+        attack_bonus::BaseAttackBonus,
+        attributes::{Dexterity, Intelligence, Strength},
+        class_features::BonusFeats,
+        feats::{
+            combat_feats::{
+                CombatExpertise, Dodge, ImprovedCritical, ImprovedInitiative, PowerAttack,
+                Toughness, WeaponFocus, WeaponSpecialization,
+            },
+            Feats,
+        },
+        hitpoints::HitPoints,
+        level::CharacterLevel,
+        racial_traits::RacialTraits,
+    },
+    plugins::{
+        classes::ClassProgression,
+        combat::attack_of_opportunity::aoo_round_modifier::CombatReflexes,
+    },
+    resources::{
+        equipment::weapon::WeaponName,
+        feat::{FeatCandidate, FeatLibrary, FeatName, FeatSelection},
+    },
+    scenes::SceneState,
+};
+
+pub struct FeatPlugin;
+
+impl Plugin for FeatPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            apply_feats
+                .after(ClassProgression)
+                .run_if(in_state(SceneState::InGameClassicMode)),
+        );
+    }
+}
+
+/// Checks each feat in a creature's `Feats` against its prerequisites in the `FeatLibrary`, then
+/// inserts the components which enable the modifier systems of the feats it qualifies for:
+/// - Weapon Focus: `attack_roll_modifier::add_weapon_focus`
+/// - Weapon Specialization: `damage_modifier::add_weapon_specialization`
+/// - Improved Critical: `critical_range_modifier::improved_critical`
+/// - Dodge: `armor_class_modifier::add_dodge`
+/// - Improved Initiative: `initiative_modifier::improved_initiative`
+/// - Combat Reflexes: `aoo_round_modifier::CombatReflexes::add_bonus`
+/// - Toughness: raises the creature's maximum `HitPoints` when taken, and again at each level
+///   past 3rd.
+/// - Power Attack and Combat Expertise: allow the creature to use these combat stances.
+///
+/// Feats are checked in order, so a feat can be a prerequisite of any feat after it in the list.
+/// Feats are checked again when the creature's level changes, since its base attack bonus and
+/// number of feat slots may have changed with it.
+pub fn apply_feats(
+    mut commands: Commands,
+    mut creature_query: Query<
+        (
+            Entity,
+            &Feats,
+            Option<&BaseAttackBonus>,
+            (Option<&Strength>, Option<&Dexterity>, Option<&Intelligence>),
+            (
+                Option<&CharacterLevel>,
+                Option<&BonusFeats>,
+                Option<&RacialTraits>,
+            ),
+            Option<&mut HitPoints>,
+            Option<&Toughness>,
+        ),
+        Or<(Changed<Feats>, Changed<CharacterLevel>)>,
+    >,
+    feat_library: Res<FeatLibrary>,
+) {
+    let debug = false;
+    for (
+        entity,
+        feats,
+        bab,
+        (strength, dexterity, intelligence),
+        (level, bonus_feats, racial_traits),
+        hit_points,
+        toughness,
+    ) in creature_query.iter_mut()
+    {
+        if let Some(level) = level {
+            let slots = feat_slots(level.level)
+                + bonus_feats.map_or(0, |bonus| **bonus)
+                + racial_traits.map_or(0, |racial_traits| racial_traits.bonus_feats());
+            if debug && feats.len() > slots {
+                println!(
+                    "debug | feats::apply_feats | {:?} has {} feats but only {} feat slots at \
+                    level {}",
+                    entity,
+                    feats.len(),
+                    slots,
+                    level.level
+                );
+            }
+        }
+
+        let mut taken: Vec<FeatName> = Vec::new();
+        let mut accepted: Vec<FeatSelection> = Vec::new();
+        for selection in feats.iter() {
+            let candidate = FeatCandidate {
+                base_attack_bonus: bab.map_or(0, |bab| **bab),
+                strength: strength.map(|strength| **strength),
+                dexterity: dexterity.map(|dexterity| **dexterity),
+                intelligence: intelligence.map(|intelligence| **intelligence),
+                level,
+                feats: &taken,
+            };
+            match feat_library.unmet_prerequisites(selection.name, &candidate) {
+                Ok(unmet) if unmet.is_empty() => {
+                    if !taken.contains(&selection.name) {
+                        taken.push(selection.name);
+                    }
+                    accepted.push(*selection);
+                }
+                Ok(unmet) => {
+                    if debug {
+                        println!(
+                            "debug | feats::apply_feats | {:?} does not meet the prerequisites \
+                            of {:?}: {:?}",
+                            entity, selection.name, unmet
+                        );
+                    }
+                }
+                Err(err) => {
+                    if debug {
+                        println!("debug | feats::apply_feats | {:?}: {}", selection, err);
+                    }
+                }
+            }
+        }
+
+        let weapons_for = |name: FeatName| -> Vec<WeaponName> {
+            accepted
+                .iter()
+                .filter(|selection| selection.name == name)
+                .filter_map(|selection| selection.weapon)
+                .collect()
+        };
+
+        let mut entity_commands = commands.entity(entity);
+        for feat in taken.iter() {
+            match feat {
+                FeatName::WeaponFocus => {
+                    entity_commands.insert(WeaponFocus::new(1, weapons_for(*feat)));
+                }
+                FeatName::WeaponSpecialization => {
+                    entity_commands.insert(WeaponSpecialization::new(weapons_for(*feat)));
+                }
+                FeatName::ImprovedCritical => {
+                    entity_commands.insert(ImprovedCritical::new(weapons_for(*feat)));
+                }
+                FeatName::Dodge => {
+                    entity_commands.insert(Dodge);
+                }
+                FeatName::ImprovedInitiative => {
+                    entity_commands.insert(ImprovedInitiative);
+                }
+                FeatName::CombatReflexes => {
+                    entity_commands.insert(CombatReflexes);
+                }
+                FeatName::PowerAttack => {
+                    entity_commands.insert(PowerAttack);
+                }
+                FeatName::CombatExpertise => {
+                    entity_commands.insert(CombatExpertise);
+                }
+                // Added below, as its hit points depend on the character level.
+                FeatName::Toughness => {}
+            }
+        }
+
+        if taken.contains(&FeatName::Toughness) {
+            let character_level = level.map_or(1, |level| level.level);
+            let bonus_hit_points = Toughness::bonus_hit_points(character_level);
+            let granted_hit_points = toughness.map_or(0, |toughness| toughness.granted_hit_points);
+            if bonus_hit_points > granted_hit_points {
+                if let Some(mut hit_points) = hit_points {
+                    hit_points.increase_max(bonus_hit_points - granted_hit_points);
+                }
+                entity_commands.insert(Toughness {
+                    granted_hit_points: bonus_hit_points,
+                });
+            }
+        }
+
+        if debug {
+            println!(
+                "debug | feats::apply_feats | {:?} has feats: {:?}",
+                entity, accepted
+            );
+        }
+    }
+}
+
+/// Every character gains a feat at 1st level and every odd level after it. Class bonus feats,
+/// e.g. the Fighter's, are counted separately by `BonusFeats`, and racial bonus feats by
+/// `RacialTraits`.
+pub fn feat_slots(level: usize) -> usize {
+    level.div_ceil(2)
+}
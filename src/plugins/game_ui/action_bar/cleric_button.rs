@@ -0,0 +1,174 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::{class_features::SpontaneousCasting, creature::Creature, spell_slots::SpellSlots},
+    plugins::{
+        combat::area,
+        interact::{InteractingPos, InteractionActive},
+        player::control::ActionPriority,
+        spells::{CastSpell, ChannelEnergy, ChannelEnergyEvent, ChannelMode},
+    },
+    resources::{dictionary::Dictionary, spell::SpellName},
+};
+
+#[derive(Component, Copy, Clone, Debug, Eq, PartialEq)]
+/// The buttons in the action bar for the Cleric's channel energy and spontaneous cure spells.
+pub enum ClericButton {
+    Channel(ChannelMode),
+    /// Spontaneously casts Cure Light Wounds on a creature the player touches, which is picked by
+    /// clicking its square.
+    CureLightWounds,
+}
+
+#[derive(Resource, Clone, Copy, Debug, Deref)]
+/// A touch spell the player has chosen to cast, waiting for its target to be clicked.
+pub struct PendingTouchSpell(pub SpellName);
+
+impl ClericButton {
+    pub fn iterator() -> impl Iterator<Item = Self> {
+        [
+            Self::Channel(ChannelMode::HealLiving),
+            Self::Channel(ChannelMode::HarmUndead),
+            Self::CureLightWounds,
+        ]
+        .into_iter()
+    }
+}
+
+pub fn setup_cleric_buttons(
+    action_bar: &mut ChildBuilder,
+    dictionary: &Dictionary,
+    text_style: &TextStyle,
+) {
+    let glossary = dictionary.get_glossary().cleric_actions;
+
+    for button in ClericButton::iterator() {
+        let text = match button {
+            ClericButton::Channel(ChannelMode::HealLiving) => glossary.channel_heal.clone(),
+            ClericButton::Channel(ChannelMode::HarmUndead) => glossary.channel_harm.clone(),
+            ClericButton::CureLightWounds => glossary.cure_light_wounds.clone(),
+        };
+        action_bar
+            .spawn(ButtonBundle {
+                style: Style {
+                    align_self: AlignSelf::Center,
+                    border: UiRect::all(Val::Px(3.0)),
+                    padding: UiRect::all(Val::Px(5.0)),
+                    margin: UiRect::all(Val::Px(5.0)),
+                    ..default()
+                },
+                background_color: Color::DARK_GRAY.into(),
+                border_color: Color::BLACK.into(),
+                ..default()
+            })
+            .insert(button)
+            .insert(Name::from(format!("{:?} Button", button)))
+            .with_children(|builder| {
+                builder.spawn(TextBundle {
+                    text: Text::from_section(text, text_style.clone())
+                        .with_alignment(TextAlignment::Center)
+                        .with_no_wrap(),
+                    ..default()
+                });
+            });
+    }
+}
+
+/// Channels energy when a `ClericButton` is pressed, or readies a cure spell to be cast on the next
+/// creature clicked.
+pub fn handle_cleric_buttons(
+    mut commands: Commands,
+    button_query: Query<(&Interaction, &ClericButton), Changed<Interaction>>,
+    player_query: Query<Entity, With<ActionPriority>>,
+    mut channel_writer: EventWriter<ChannelEnergyEvent>,
+) {
+    let Ok(player) = player_query.get_single() else {
+        return;
+    };
+    for (_, button) in button_query
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+    {
+        match button {
+            ClericButton::Channel(mode) => channel_writer.send(ChannelEnergyEvent {
+                caster: player,
+                mode: *mode,
+            }),
+            ClericButton::CureLightWounds => {
+                commands.insert_resource(PendingTouchSpell(SpellName::CureLightWounds))
+            }
+        }
+    }
+}
+
+/// Casts the `PendingTouchSpell` on the creature standing in the clicked square, which may be the
+/// player. Clicking a square without a creature cancels the spell. Whether the target is in reach
+/// is checked when the spell is cast.
+pub fn target_touch_spell(
+    mut commands: Commands,
+    pending: Res<PendingTouchSpell>,
+    button: Res<Input<MouseButton>>,
+    interaction_active: Res<InteractionActive>,
+    interacting_pos: Res<InteractingPos>,
+    player_query: Query<Entity, With<ActionPriority>>,
+    creature_query: Query<(Entity, &Transform), With<Creature>>,
+    mut cast_writer: EventWriter<CastSpell>,
+) {
+    let debug = false;
+    if **interaction_active || !button.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let Ok(player) = player_query.get_single() else {
+        return;
+    };
+    let square = interacting_pos.pos;
+    let target = creature_query
+        .iter()
+        .find(|(_, transform)| area::distance(transform.translation.truncate(), square) == 0)
+        .map(|(entity, _)| entity);
+    if let Some(target) = target {
+        cast_writer.send(CastSpell {
+            caster: player,
+            spell: **pending,
+            target,
+        });
+    } else if debug {
+        println!(
+            "debug | cleric_button::target_touch_spell | no creature at {:?}, {:?} cancelled",
+            square, **pending
+        );
+    }
+    commands.remove_resource::<PendingTouchSpell>();
+}
+
+/// Greys out the `ClericButton`s the player has no uses left for.
+pub fn handle_cleric_button_colors(
+    mut button_query: Query<(&Interaction, &ClericButton, &mut BackgroundColor)>,
+    player_query: Query<
+        (
+            Option<&ChannelEnergy>,
+            Option<&SpellSlots>,
+            Option<&SpontaneousCasting>,
+        ),
+        With<ActionPriority>,
+    >,
+) {
+    let Ok((channel_energy, spell_slots, spontaneous)) = player_query.get_single() else {
+        return;
+    };
+    for (interaction, button, mut bg_color) in button_query.iter_mut() {
+        let usable = match button {
+            ClericButton::Channel(_) => {
+                channel_energy.is_some_and(|channel| channel.uses_left() > 0)
+            }
+            ClericButton::CureLightWounds => {
+                spontaneous.is_some() && spell_slots.is_some_and(|slots| slots.remaining(1) > 0)
+            }
+        };
+        *bg_color = match (usable, interaction) {
+            (false, _) => Color::DARK_GRAY.into(),
+            (true, Interaction::Hovered) => Color::GOLD.into(),
+            (true, _) => Color::OLIVE.into(),
+        };
+    }
+}
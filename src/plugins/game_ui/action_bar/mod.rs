@@ -6,10 +6,18 @@ use crate::{
     resources::{dictionary::Dictionary, glossary::ActionBar},
 };
 
-use self::submenu_button::{setup_attack_buttons, setup_move_buttons, SubMenu};
+use self::{
+    cleric_button::setup_cleric_buttons,
+    rage_button::setup_rage_button,
+    stance_button::setup_stance_buttons,
+    submenu_button::{setup_attack_buttons, setup_move_buttons, SubMenu},
+};
 
 use super::ui_root::UserInterfaceRoot;
 
+pub mod cleric_button;
+pub mod rage_button;
+pub mod stance_button;
 pub mod submenu_button;
 
 #[derive(Resource, Debug, Clone, Copy)]
@@ -22,6 +30,7 @@ pub fn cleanup(mut commands: Commands, action_bar_root: Res<ActionBarData>) {
         .entity(action_bar_root.action_bar_root)
         .despawn_recursive();
     commands.remove_resource::<ActionBarData>();
+    commands.remove_resource::<cleric_button::PendingTouchSpell>();
 }
 
 pub fn setup(
@@ -191,6 +200,9 @@ pub fn action_bar(
                     };
                 });
         }
+        setup_stance_buttons(parent, dictionary, &text_style);
+        setup_rage_button(parent, dictionary, &text_style);
+        setup_cleric_buttons(parent, dictionary, &text_style);
     })
     .insert(Name::new("Action Button Bar"));
 }
@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::conditions::Fatigued,
+    plugins::{
+        combat::rage::{Rage, Raging, ToggleRage},
+        player::control::ActionPriority,
+    },
+    resources::dictionary::Dictionary,
+};
+
+#[derive(Component, Copy, Clone, Debug, Eq, PartialEq)]
+/// A toggle in the action bar which starts or ends the player's rage.
+pub struct RageButton;
+
+pub fn setup_rage_button(
+    action_bar: &mut ChildBuilder,
+    dictionary: &Dictionary,
+    text_style: &TextStyle,
+) {
+    let glossary = dictionary.get_glossary();
+
+    action_bar
+        .spawn(ButtonBundle {
+            style: Style {
+                align_self: AlignSelf::Center,
+                border: UiRect::all(Val::Px(3.0)),
+                padding: UiRect::all(Val::Px(5.0)),
+                margin: UiRect::all(Val::Px(5.0)),
+                ..default()
+            },
+            background_color: Color::DARK_GRAY.into(),
+            border_color: Color::BLACK.into(),
+            ..default()
+        })
+        .insert(RageButton)
+        .insert(Name::from("Rage Button"))
+        .with_children(|builder| {
+            builder.spawn(TextBundle {
+                text: Text::from_section(glossary.stances.rage, text_style.clone())
+                    .with_alignment(TextAlignment::Center)
+                    .with_no_wrap(),
+                ..default()
+            });
+        });
+}
+
+/// Starts or ends the player's rage when the `RageButton` is pressed.
+pub fn handle_rage_button(
+    button_query: Query<&Interaction, (Changed<Interaction>, With<RageButton>)>,
+    player_query: Query<Entity, (With<ActionPriority>, With<Rage>)>,
+    mut toggle_writer: EventWriter<ToggleRage>,
+) {
+    let Ok(player) = player_query.get_single() else {
+        return;
+    };
+    for _ in button_query
+        .iter()
+        .filter(|interaction| **interaction == Interaction::Pressed)
+    {
+        toggle_writer.send(ToggleRage(player));
+    }
+}
+
+/// Greys out the `RageButton` when the player cannot start a rage, and outlines it while the
+/// player is raging.
+pub fn handle_rage_button_colors(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        With<RageButton>,
+    >,
+    player_query: Query<(Option<&Rage>, Option<&Raging>, Option<&Fatigued>), With<ActionPriority>>,
+) {
+    let Ok((rage, raging, fatigued)) = player_query.get_single() else {
+        return;
+    };
+    let (raging, fatigued) = (raging.is_some(), fatigued.is_some());
+    let can_toggle = match rage {
+        Some(rage) => raging || (!fatigued && rage.rounds_left() > 0),
+        None => false,
+    };
+    for (interaction, mut bg_color, mut border_color) in button_query.iter_mut() {
+        *bg_color = match (can_toggle, interaction) {
+            (false, _) => Color::DARK_GRAY.into(),
+            (true, Interaction::Hovered) => Color::RED.into(),
+            (true, _) => Color::MAROON.into(),
+        };
+        *border_color = if raging {
+            Color::WHITE.into()
+        } else {
+            Color::BLACK.into()
+        };
+    }
+}
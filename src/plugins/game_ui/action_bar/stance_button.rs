@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::feats::combat_feats::{CombatExpertise, PowerAttack},
+    plugins::{
+        combat::stance::{ActiveStances, Stance},
+        player::control::ActionPriority,
+    },
+    resources::{
+        dictionary::Dictionary,
+        glossary::{Glossary, Translation},
+    },
+};
+
+#[derive(Component, Copy, Clone, Debug, Deref, Eq, PartialEq)]
+/// A toggle in the action bar which turns a combat stance on or off for the player.
+pub struct StanceButton(Stance);
+
+impl Translation for Stance {
+    fn to_string_glossary(self, glossary: &Glossary) -> String {
+        match self {
+            Stance::PowerAttack => glossary.stances.power_attack.clone(),
+            Stance::CombatExpertise => glossary.stances.combat_expertise.clone(),
+        }
+    }
+}
+
+pub fn setup_stance_buttons(
+    action_bar: &mut ChildBuilder,
+    dictionary: &Dictionary,
+    text_style: &TextStyle,
+) {
+    let glossary = &dictionary.get_glossary();
+
+    action_bar
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                margin: UiRect::left(Val::Auto),
+                ..default()
+            },
+            ..default()
+        })
+        .insert(Name::from("Stance Buttons"))
+        .with_children(|builder| {
+            for stance in Stance::iterator() {
+                builder
+                    .spawn(ButtonBundle {
+                        style: Style {
+                            border: UiRect::all(Val::Px(3.0)),
+                            padding: UiRect::all(Val::Px(5.0)),
+                            margin: UiRect::all(Val::Px(5.0)),
+                            ..default()
+                        },
+                        background_color: Color::DARK_GRAY.into(),
+                        border_color: Color::BLACK.into(),
+                        ..default()
+                    })
+                    .insert(StanceButton(*stance))
+                    .insert(Name::from(format!("Stance Button {:?}", stance)))
+                    .with_children(|builder| {
+                        builder.spawn(TextBundle {
+                            text: Text::from_section(
+                                stance.to_string_glossary(glossary),
+                                text_style.clone(),
+                            )
+                            .with_alignment(TextAlignment::Center)
+                            .with_no_wrap(),
+                            ..default()
+                        });
+                    });
+            }
+        });
+}
+
+/// Toggles the stance of a pressed `StanceButton`, if the player has the feat for it.
+pub fn handle_stance_buttons(
+    button_query: Query<(&Interaction, &StanceButton), Changed<Interaction>>,
+    mut player_query: Query<
+        (
+            &mut ActiveStances,
+            Option<&PowerAttack>,
+            Option<&CombatExpertise>,
+        ),
+        With<ActionPriority>,
+    >,
+) {
+    let Ok((mut stances, power_attack, combat_expertise)) = player_query.get_single_mut() else {
+        return;
+    };
+    for (_, button) in button_query
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+    {
+        let has_feat = match **button {
+            Stance::PowerAttack => power_attack.is_some(),
+            Stance::CombatExpertise => combat_expertise.is_some(),
+        };
+        if has_feat {
+            stances.toggle(**button);
+        }
+    }
+}
+
+/// Greys out the stances the player does not have the feat for, and outlines active stances.
+pub fn handle_stance_button_colors(
+    mut button_query: Query<(
+        &Interaction,
+        &StanceButton,
+        &mut BackgroundColor,
+        &mut BorderColor,
+    )>,
+    player_query: Query<
+        (
+            &ActiveStances,
+            Option<&PowerAttack>,
+            Option<&CombatExpertise>,
+        ),
+        With<ActionPriority>,
+    >,
+) {
+    let Ok((stances, power_attack, combat_expertise)) = player_query.get_single() else {
+        return;
+    };
+    for (interaction, button, mut bg_color, mut border_color) in button_query.iter_mut() {
+        let has_feat = match **button {
+            Stance::PowerAttack => power_attack.is_some(),
+            Stance::CombatExpertise => combat_expertise.is_some(),
+        };
+        *bg_color = match (has_feat, interaction) {
+            (false, _) => Color::DARK_GRAY.into(),
+            (true, Interaction::Hovered) => Color::GREEN.into(),
+            (true, _) => Color::DARK_GREEN.into(),
+        };
+        *border_color = if stances.is_active(**button) {
+            Color::WHITE.into()
+        } else {
+            Color::BLACK.into()
+        };
+    }
+}
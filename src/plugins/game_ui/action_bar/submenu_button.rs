@@ -1,8 +1,9 @@
 #![allow(dead_code)]
 
 use crate::{
+    components::creature::LandSpeed,
     plugins::{actions::ActionStatus, game_ui::action_bar::ActionBarButton},
-    resources::{dictionary::Dictionary, glossary::Translation},
+    resources::{dictionary::Dictionary, dungeon::pathfinding::MoveBudget, glossary::Translation},
 };
 use bevy::prelude::*;
 use std::{fmt::Display, slice::Iter};
@@ -28,6 +29,21 @@ impl MoveButton {
     }
 }
 
+impl MoveButton {
+    /// How far the player can move with this option: their speed for a move action, twice their
+    /// speed for a full move, or a single square which is not difficult terrain for a five-foot
+    /// step.
+    pub fn move_budget(self, land_speed: LandSpeed) -> MoveBudget {
+        match self {
+            MoveButton::MoveAction | MoveButton::StandardAction => {
+                MoveBudget::feet(*land_speed as u32)
+            }
+            MoveButton::FullMove => MoveBudget::feet(2 * *land_speed as u32),
+            MoveButton::FiveFootStep => MoveBudget::five_foot_step(),
+        }
+    }
+}
+
 impl SubMenuActions for MoveButton {
     fn is_action_available(self, action_status: &ActionStatus) -> bool {
         match self {
@@ -66,13 +82,23 @@ pub enum AttackButton {
     #[default]
     Single,
     Full,
+    Trip,
+    Disarm,
+    BullRush,
+    Grapple,
+    Sunder,
 }
 
 impl SubMenuActions for AttackButton {
     fn is_action_available(self, action_status: &ActionStatus) -> bool {
         match self {
-            Self::Single => action_status.standard.is_available(),
             Self::Full => action_status.full_round.is_available(),
+            Self::Single
+            | Self::Trip
+            | Self::Disarm
+            | Self::BullRush
+            | Self::Grapple
+            | Self::Sunder => action_status.standard.is_available(),
         }
     }
 }
@@ -86,7 +112,16 @@ where
 
 impl AttackButton {
     fn iterator() -> Iter<'static, Self> {
-        [AttackButton::Single, AttackButton::Full].iter()
+        [
+            AttackButton::Single,
+            AttackButton::Full,
+            AttackButton::Trip,
+            AttackButton::Disarm,
+            AttackButton::BullRush,
+            AttackButton::Grapple,
+            AttackButton::Sunder,
+        ]
+        .iter()
     }
 }
 
@@ -95,6 +130,11 @@ impl Translation for AttackButton {
         match self {
             AttackButton::Single => glossary.attack_submenu.single_attack.clone(),
             AttackButton::Full => glossary.attack_submenu.full_attack.clone(),
+            AttackButton::Trip => glossary.attack_submenu.trip.clone(),
+            AttackButton::Disarm => glossary.attack_submenu.disarm.clone(),
+            AttackButton::BullRush => glossary.attack_submenu.bull_rush.clone(),
+            AttackButton::Grapple => glossary.attack_submenu.grapple.clone(),
+            AttackButton::Sunder => glossary.attack_submenu.sunder.clone(),
         }
     }
 }
@@ -104,6 +144,11 @@ impl Display for AttackButton {
         match self {
             Self::Single => write!(f, "Single Attack"),
             Self::Full => write!(f, "Full Attack"),
+            Self::Trip => write!(f, "Trip"),
+            Self::Disarm => write!(f, "Disarm"),
+            Self::BullRush => write!(f, "Bull Rush"),
+            Self::Grapple => write!(f, "Grapple"),
+            Self::Sunder => write!(f, "Sunder"),
         }
     }
 }
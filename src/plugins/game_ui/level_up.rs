@@ -0,0 +1,293 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::attributes::Ability,
+    materials::font::FontMaterials,
+    plugins::{
+        leveling::{HitPointChoice, LevelUpEvent, PendingLevelUp},
+        player::control::ActionPriority,
+    },
+    resources::{dictionary::Dictionary, feat::FeatSelection, glossary::Translation},
+};
+
+use super::ui_root::UserInterfaceRoot;
+
+#[derive(Resource, Clone, Debug)]
+/// The open level-up screen, with the choices made on it so far.
+pub struct LevelUpScreen {
+    root: Entity,
+    character: Entity,
+    feat_picks: usize,
+    ability_increase: bool,
+    hit_points: Option<HitPointChoice>,
+    feats: Vec<FeatSelection>,
+    ability: Option<Ability>,
+}
+
+impl LevelUpScreen {
+    fn is_complete(&self) -> bool {
+        self.hit_points.is_some()
+            && self.feats.len() == self.feat_picks
+            && (!self.ability_increase || self.ability.is_some())
+    }
+
+    fn is_selected(&self, button: &LevelUpButton) -> bool {
+        match button {
+            LevelUpButton::HitPoints(choice) => self.hit_points == Some(*choice),
+            LevelUpButton::Feat(feat) => self.feats.contains(feat),
+            LevelUpButton::Ability(ability) => self.ability == Some(*ability),
+            LevelUpButton::Confirm => false,
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LevelUpButton {
+    HitPoints(HitPointChoice),
+    Feat(FeatSelection),
+    Ability(Ability),
+    Confirm,
+}
+
+/// Opens the level-up screen when the player has enough experience for their next level.
+pub fn setup(
+    mut commands: Commands,
+    player_query: Query<(Entity, &PendingLevelUp), (With<ActionPriority>, Added<PendingLevelUp>)>,
+    ui_root: Res<UserInterfaceRoot>,
+    font_materials: Res<FontMaterials>,
+    dictionary: Res<Dictionary>,
+) {
+    let Ok((player, pending)) = player_query.get_single() else {
+        return;
+    };
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let full_glossary = dictionary.get_glossary();
+    let glossary = full_glossary.level_up.clone();
+    let text_style = TextStyle {
+        font,
+        font_size: 22.0,
+        color: Color::WHITE,
+    };
+
+    let mut root: Option<Entity> = None;
+    commands
+        .get_entity(ui_root.entity)
+        .unwrap()
+        .with_children(|builder| {
+            root = Some(
+                builder
+                    .spawn(NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            top: Val::Percent(15.0),
+                            left: Val::Percent(25.0),
+                            width: Val::Percent(50.0),
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(Val::Px(15.0)),
+                            row_gap: Val::Px(10.0),
+                            ..default()
+                        },
+                        background_color: Color::rgba(0.1, 0.1, 0.1, 0.9).into(),
+                        ..default()
+                    })
+                    .insert(Name::from("Level Up Screen"))
+                    .with_children(|builder| {
+                        builder.spawn(TextBundle::from_section(
+                            format!("{} {}", glossary.level_up, pending.level),
+                            text_style.clone(),
+                        ));
+
+                        let hit_points = [
+                            (
+                                LevelUpButton::HitPoints(HitPointChoice::Roll),
+                                format!("{} ({:?})", glossary.roll, pending.hit_die),
+                            ),
+                            (
+                                LevelUpButton::HitPoints(HitPointChoice::Average),
+                                format!(
+                                    "{} ({})",
+                                    glossary.average,
+                                    HitPointChoice::average(pending.hit_die)
+                                ),
+                            ),
+                        ];
+                        choice_row(builder, &glossary.hit_points, &hit_points, &text_style);
+
+                        if pending.feat_picks > 0 {
+                            let feats: Vec<_> = pending
+                                .feats
+                                .iter()
+                                .map(|feat| {
+                                    let text = match feat.weapon {
+                                        Some(weapon) => format!("{:?} ({:?})", feat.name, weapon),
+                                        None => format!("{:?}", feat.name),
+                                    };
+                                    (LevelUpButton::Feat(*feat), text)
+                                })
+                                .collect();
+                            let label = format!("{} ({})", glossary.feats, pending.feat_picks);
+                            choice_row(builder, &label, &feats, &text_style);
+                        }
+
+                        if pending.ability_increase {
+                            let abilities: Vec<_> = Ability::iterator()
+                                .map(|ability| {
+                                    (
+                                        LevelUpButton::Ability(*ability),
+                                        ability.to_string_glossary(&full_glossary),
+                                    )
+                                })
+                                .collect();
+                            choice_row(builder, &glossary.ability_score, &abilities, &text_style);
+                        }
+
+                        level_up_button(
+                            builder,
+                            LevelUpButton::Confirm,
+                            glossary.confirm.clone(),
+                            &text_style,
+                        );
+                    })
+                    .id(),
+            );
+        });
+
+    commands.insert_resource(LevelUpScreen {
+        root: root.unwrap(),
+        character: player,
+        // There may be fewer eligible feats than feat slots.
+        feat_picks: pending.feat_picks.min(pending.feats.len()),
+        ability_increase: pending.ability_increase,
+        hit_points: None,
+        feats: Vec::new(),
+        ability: None,
+    });
+}
+
+fn choice_row(
+    builder: &mut ChildBuilder,
+    label: &str,
+    choices: &[(LevelUpButton, String)],
+    text_style: &TextStyle,
+) {
+    builder
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        })
+        .insert(Name::from(format!("Level Up Row: {}", label)))
+        .with_children(|builder| {
+            builder.spawn(TextBundle::from_section(
+                format!("{}:", label),
+                text_style.clone(),
+            ));
+            for (button, text) in choices {
+                level_up_button(builder, *button, text.clone(), text_style);
+            }
+        });
+}
+
+fn level_up_button(
+    builder: &mut ChildBuilder,
+    button: LevelUpButton,
+    text: String,
+    text_style: &TextStyle,
+) {
+    builder
+        .spawn(ButtonBundle {
+            style: Style {
+                border: UiRect::all(Val::Px(3.0)),
+                padding: UiRect::all(Val::Px(5.0)),
+                margin: UiRect::all(Val::Px(5.0)),
+                ..default()
+            },
+            background_color: Color::DARK_GRAY.into(),
+            border_color: Color::BLACK.into(),
+            ..default()
+        })
+        .insert(button)
+        .insert(Name::from(format!("{:?} Button", button)))
+        .with_children(|builder| {
+            builder.spawn(TextBundle {
+                text: Text::from_section(text, text_style.clone())
+                    .with_alignment(TextAlignment::Center)
+                    .with_no_wrap(),
+                ..default()
+            });
+        });
+}
+
+/// Records the choices made on the level-up screen, and raises the player's level once every
+/// choice has been made and the level up is confirmed.
+pub fn handle_level_up_buttons(
+    mut commands: Commands,
+    button_query: Query<(&Interaction, &LevelUpButton), Changed<Interaction>>,
+    mut screen: ResMut<LevelUpScreen>,
+    mut level_up_writer: EventWriter<LevelUpEvent>,
+) {
+    for (_, button) in button_query
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+    {
+        match button {
+            LevelUpButton::HitPoints(choice) => screen.hit_points = Some(*choice),
+            LevelUpButton::Feat(feat) => {
+                if let Some(index) = screen.feats.iter().position(|taken| taken == feat) {
+                    screen.feats.remove(index);
+                } else if screen.feats.len() < screen.feat_picks {
+                    screen.feats.push(*feat);
+                }
+            }
+            LevelUpButton::Ability(ability) => screen.ability = Some(*ability),
+            LevelUpButton::Confirm => {
+                let Some(hit_points) = screen.hit_points.filter(|_| screen.is_complete()) else {
+                    continue;
+                };
+                level_up_writer.send(LevelUpEvent {
+                    character: screen.character,
+                    hit_points,
+                    feats: screen.feats.clone(),
+                    ability: screen.ability,
+                });
+                commands.entity(screen.root).despawn_recursive();
+                commands.remove_resource::<LevelUpScreen>();
+                return;
+            }
+        }
+    }
+}
+
+/// Highlights the choices made on the level-up screen, and greys out the confirm button until
+/// every choice has been made.
+pub fn handle_level_up_button_colors(
+    mut button_query: Query<(&Interaction, &LevelUpButton, &mut BackgroundColor)>,
+    screen: Res<LevelUpScreen>,
+) {
+    for (interaction, button, mut bg_color) in button_query.iter_mut() {
+        let available = match button {
+            LevelUpButton::Confirm => screen.is_complete(),
+            _ => true,
+        };
+        *bg_color = match (available, screen.is_selected(button), interaction) {
+            (false, _, _) => Color::DARK_GRAY.into(),
+            (true, true, _) => Color::DARK_GREEN.into(),
+            (true, false, Interaction::Hovered) => Color::GRAY.into(),
+            (true, false, _) if *button == LevelUpButton::Confirm => Color::DARK_GREEN.into(),
+            (true, false, _) => Color::rgb(0.25, 0.25, 0.25).into(),
+        };
+    }
+}
+
+pub fn cleanup(mut commands: Commands, screen: Option<Res<LevelUpScreen>>) {
+    if let Some(screen) = screen {
+        commands.entity(screen.root).despawn_recursive();
+        commands.remove_resource::<LevelUpScreen>();
+    }
+}
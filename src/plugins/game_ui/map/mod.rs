@@ -4,6 +4,7 @@ use crate::materials::ingame::InGameMaterials;
 
 pub mod focus_box;
 pub mod pathing;
+pub mod reachable;
 
 #[derive(Resource)]
 pub struct MapUiData {
@@ -40,6 +41,7 @@ pub fn setup(mut commands: Commands, ingame_materials: Res<InGameMaterials>) {
         user_interface_root,
         map_ui_sprites_root,
     });
+    commands.init_resource::<reachable::ReachableSquares>();
 }
 
 /// Cleans up both the Node frame (w/ children) for the map sprite as well
@@ -55,4 +57,5 @@ pub fn cleanup(mut commands: Commands, map_ui_data: Res<MapUiData>) {
 
     commands.remove_resource::<MapUiData>();
     commands.remove_resource::<pathing::MovePathFrameData>();
+    commands.remove_resource::<reachable::ReachableSquares>();
 }
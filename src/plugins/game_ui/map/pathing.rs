@@ -7,6 +7,7 @@ use bevy::prelude::*;
 use crate::components::player::PlayerComponent;
 use crate::plugins::game_ui::map::MapUiData;
 use crate::plugins::input::movement::Movement;
+use crate::resources::dungeon::grid_pos::GridPos;
 use crate::{config::TILE_SIZE, plugins::input::movement::path_move::MovementPath};
 
 #[derive(Resource, Clone, Copy)]
@@ -49,73 +50,52 @@ pub enum SpriteAction {
 
 #[derive(Clone, Copy, Component, Debug)]
 pub struct PathSprite {
-    pub step: (Vec3, Vec3),
+    pub step: (GridPos, GridPos),
 }
 
 impl PathSprite {
-    fn contains(self, other: Vec2) -> bool {
-        let x_min = self.step.0.x.min(self.step.1.x);
-        let x_max = self.step.0.x.max(self.step.1.x);
-        let y_min = self.step.0.y.min(self.step.1.y);
-        let y_max = self.step.0.y.max(self.step.1.y);
-        if self.is_vertical() {
-            other
-                .y
-                .clamp(y_min + TILE_SIZE / 4.0, y_max - TILE_SIZE / 4.0)
-                == other.y
-                && other.x == self.step.0.x
-        } else if self.is_horizontal() {
-            other
-                .x
-                .clamp(x_min + TILE_SIZE / 4.0, x_max - TILE_SIZE / 4.0)
-                == other.x
-                && other.y == self.step.0.y
-        } else {
-            other
-                .y
-                .clamp(y_min + TILE_SIZE / 4.0, y_max - TILE_SIZE / 4.0)
-                == other.y
-                && other
-                    .x
-                    .clamp(x_min + TILE_SIZE / 4.0, x_max - TILE_SIZE / 4.0)
-                    == other.x
-        }
+    /// Whether the step ends on the square, i.e. the player has reached the step
+    /// once they stand on `other`.
+    fn contains(self, other: GridPos) -> bool {
+        self.step.1 == other
     }
 
     pub fn is_vertical(self) -> bool {
-        self.step.0.x == self.step.1.x
+        is_vertical(&self.step)
     }
 
     pub fn is_horizontal(self) -> bool {
-        self.step.0.y == self.step.1.y
+        is_horizontal(&self.step)
     }
 }
-pub fn is_vertical(pos: &(Vec3, Vec3)) -> bool {
+pub fn is_vertical(pos: &(GridPos, GridPos)) -> bool {
     pos.0.x == pos.1.x
 }
 
-pub fn is_horizontal(pos: &(Vec3, Vec3)) -> bool {
+pub fn is_horizontal(pos: &(GridPos, GridPos)) -> bool {
     pos.0.y == pos.1.y
 }
 
-pub fn is_rising_diagonal(pos: &(Vec3, Vec3)) -> bool {
-    pos.0.y < pos.1.y && pos.0.x < pos.1.x || pos.0.y > pos.1.y && pos.0.x > pos.1.x
+/// Rising on the screen, so the row decreases as the column increases.
+pub fn is_rising_diagonal(pos: &(GridPos, GridPos)) -> bool {
+    pos.0.y > pos.1.y && pos.0.x < pos.1.x || pos.0.y < pos.1.y && pos.0.x > pos.1.x
 }
 
-pub fn is_falling_diagonal(pos: &(Vec3, Vec3)) -> bool {
-    pos.0.y < pos.1.y && pos.0.x > pos.1.x || pos.0.y > pos.1.y && pos.0.x < pos.1.x
+pub fn is_falling_diagonal(pos: &(GridPos, GridPos)) -> bool {
+    pos.0.y > pos.1.y && pos.0.x > pos.1.x || pos.0.y < pos.1.y && pos.0.x < pos.1.x
 }
 
-pub fn calc_signed_offset_x(step: &(Vec3, Vec3), offset: Vec3) -> f32 {
-    if (step.1.x - step.0.x).is_sign_positive() {
+pub fn calc_signed_offset_x(step: &(GridPos, GridPos), offset: Vec3) -> f32 {
+    if step.1.x >= step.0.x {
         offset.x
     } else {
         -1.0 * offset.x
     }
 }
 
-pub fn calc_signed_offset_y(step: &(Vec3, Vec3), offset: Vec3) -> f32 {
-    if (step.1.y - step.0.y).is_sign_positive() {
+/// Rows count down the screen, so moving to a lower row is a positive offset.
+pub fn calc_signed_offset_y(step: &(GridPos, GridPos), offset: Vec3) -> f32 {
+    if step.1.y <= step.0.y {
         offset.y
     } else {
         -1.0 * offset.y
@@ -172,9 +152,10 @@ pub fn spawn_move_path(
             .any(|(_, old_sprite)| (**new_step == old_sprite.step))
             || old_path_sprites.is_empty()
     }) {
+        let start = step.0.to_world();
         if is_horizontal(step) {
             let signed_offset_x = calc_signed_offset_x(step, offset);
-            let transform_x = step.0.x + signed_offset_x;
+            let transform_x = start.x + signed_offset_x;
             commands
                 .spawn(SpriteBundle {
                     sprite: Sprite {
@@ -185,7 +166,7 @@ pub fn spawn_move_path(
                     },
                     transform: Transform::from_translation(Vec3::new(
                         transform_x,
-                        step.0.y,
+                        start.y,
                         offset.z,
                     )),
                     ..default()
@@ -197,7 +178,7 @@ pub fn spawn_move_path(
             }
         } else if is_vertical(step) {
             let signed_offset_y = calc_signed_offset_y(step, offset);
-            let transform_y = step.0.y + signed_offset_y;
+            let transform_y = start.y + signed_offset_y;
             commands
                 .spawn(SpriteBundle {
                     sprite: Sprite {
@@ -207,7 +188,7 @@ pub fn spawn_move_path(
                         ..default()
                     },
                     transform: Transform::from_translation(Vec3::new(
-                        step.0.x,
+                        start.x,
                         transform_y,
                         offset.z,
                     )),
@@ -220,9 +201,9 @@ pub fn spawn_move_path(
             }
         } else {
             let signed_offset_x = calc_signed_offset_x(step, offset);
-            let transform_x = step.0.x + signed_offset_x;
+            let transform_x = start.x + signed_offset_x;
             let signed_offset_y = calc_signed_offset_y(step, offset);
-            let transform_y = step.0.y + signed_offset_y;
+            let transform_y = start.y + signed_offset_y;
 
             let rotation_radians = if is_rising_diagonal(step) {
                 -1.0 * PI / 4.0
@@ -256,15 +237,16 @@ pub fn despawn_on_move(
 ) {
     let debug = false;
     if movement.moving {
-        let player_pos = player_pos.get_single().unwrap().translation;
+        let player_pos =
+            GridPos::from_world(player_pos.get_single().unwrap().translation.truncate());
         for (entity, step) in path_sprites
             .iter()
-            .filter(|(_, step)| step.contains(player_pos.truncate()))
+            .filter(|(_, step)| step.contains(player_pos))
             .inspect(|(_, step)| {
                 if debug {
                     println!(
                         "spawn_on_move | step.contains: {}",
-                        step.contains(player_pos.truncate())
+                        step.contains(player_pos)
                     )
                 }
             })
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::components::creature::{Faction, LandSpeed};
+use crate::components::player::PlayerComponent;
+use crate::config::TILE_SIZE;
+use crate::plugins::actions::ActionStatus;
+use crate::plugins::combat_mode::state::CombatMode;
+use crate::plugins::game_ui::action_bar::submenu_button::SelectedSubMenu;
+use crate::plugins::game_ui::action_bar::{ActionBarButton, SelectedAction};
+use crate::plugins::game_ui::combat_mode::CombatModeRes;
+use crate::plugins::input::movement::click_move::move_budget;
+use crate::plugins::input::movement::path_move::MovementPath;
+use crate::plugins::input::movement::Movement;
+use crate::resources::dungeon::grid_occupancy::GridOccupancy;
+use crate::resources::dungeon::grid_pos::GridPos;
+use crate::resources::dungeon::pathfinding;
+
+use super::MapUiData;
+
+// TODO: Make consts for the z_layers and keep them in an organized list
+// somewhere.
+/// Between the map tiles and the focus box.
+const REACHABLE_Z: f32 = 0.1;
+
+#[derive(Resource, Clone, Debug, Default, PartialEq, Deref)]
+/// The squares the player can move to with the move selected in the action bar, along with the
+/// cost in feet of moving there. Empty whenever the player is not planning a move in combat.
+pub struct ReachableSquares(HashMap<GridPos, u32>);
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ReachableSprite;
+
+/// Flood-fills the squares the player can reach with the selected move: their speed for a move
+/// action, twice their speed for a full move, or a single square for a five-foot step.
+pub fn update_reachable_squares(
+    mut reachable: ResMut<ReachableSquares>,
+    player_query: Query<(Entity, &Transform, &Faction, Option<&LandSpeed>), With<PlayerComponent>>,
+    faction_query: Query<&Faction>,
+    occupancy: Res<GridOccupancy>,
+    combat_mode: Res<CombatModeRes>,
+    selected_action: Res<SelectedAction>,
+    selected_submenu: Res<SelectedSubMenu>,
+    action_status: Option<Res<ActionStatus>>,
+    movement: Res<Movement>,
+    movement_path: Option<Res<MovementPath>>,
+) {
+    let planning = **combat_mode == CombatMode::InCombat
+        && **selected_action == ActionBarButton::Move
+        && !movement.moving
+        && !movement_path.is_some_and(|move_path| move_path.is_traversing());
+    let Ok((player, transform, faction, land_speed)) = player_query.get_single() else {
+        return;
+    };
+    if !planning {
+        reachable.set_if_neq(ReachableSquares::default());
+        return;
+    }
+
+    let budget = move_budget(
+        &combat_mode,
+        &selected_submenu,
+        action_status.as_deref(),
+        land_speed,
+    );
+    let is_enemy = |entity: Entity| {
+        !faction_query
+            .get(entity)
+            .is_ok_and(|other| other.is_ally(*faction))
+    };
+    let squares = pathfinding::reachable(
+        &occupancy,
+        player,
+        is_enemy,
+        GridPos::from_world(transform.translation.truncate()),
+        budget,
+    );
+    reachable.set_if_neq(ReachableSquares(squares));
+}
+
+/// Tints the reachable squares on the map whenever they change.
+pub fn spawn_reachable_overlay(
+    mut commands: Commands,
+    reachable: Res<ReachableSquares>,
+    sprite_query: Query<Entity, With<ReachableSprite>>,
+    map_ui_data: Res<MapUiData>,
+) {
+    for entity in sprite_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands
+        .entity(map_ui_data.map_ui_sprites_root)
+        .with_children(|builder| {
+            for square in reachable.keys() {
+                builder.spawn((
+                    SpriteBundle {
+                        sprite: Sprite {
+                            // TODO: Put this color in a const somewhere
+                            color: Color::rgba(0.25, 0.5, 1.0, 0.25),
+                            custom_size: Some(Vec2::new(TILE_SIZE, TILE_SIZE)),
+                            ..default()
+                        },
+                        transform: Transform::from_translation(
+                            square.to_world().extend(REACHABLE_Z),
+                        ),
+                        ..default()
+                    },
+                    ReachableSprite,
+                    Name::new("Reachable Square"),
+                ));
+            }
+        });
+}
@@ -5,7 +5,9 @@ use crate::scenes::SceneState;
 use self::{
     action_bar::{submenu_button::SelectedSubMenu, SelectedAction},
     combat_mode::CombatModeRes,
+    level_up::LevelUpScreen,
     map::pathing::PathSpriteEvent,
+    ui_root::UserInterfaceRoot,
 };
 
 use super::{
@@ -15,7 +17,9 @@ use super::{
 
 pub mod action_bar;
 pub mod combat_mode;
+pub mod level_up;
 pub mod map;
+pub mod proficiency_warning;
 pub mod translate;
 pub mod turn_actions;
 pub mod turn_mode;
@@ -28,6 +32,7 @@ impl Plugin for IngameUiPlugin {
         app
             // TODO: Move TurnActionEvent into the action plugin
             .add_event::<TurnActionEvent>()
+            .add_event::<proficiency_warning::EquipRequest>()
             .init_resource::<SelectedSubMenu>()
             .add_systems(
                 OnEnter(SceneState::InGameClassicMode),
@@ -39,6 +44,7 @@ impl Plugin for IngameUiPlugin {
                     turn_mode::setup,
                     turn_actions::setup,
                     action_bar::setup,
+                    proficiency_warning::setup,
                     apply_deferred,
                     map::pathing::setup,
                 )
@@ -67,6 +73,45 @@ impl Plugin for IngameUiPlugin {
                     .run_if(resource_exists::<ActionStatus>()),
                 action_bar::submenu_button::handle_submenu_border
                     .run_if(resource_exists_and_changed::<SelectedSubMenu>()),
+                action_bar::stance_button::handle_stance_buttons,
+                action_bar::stance_button::handle_stance_button_colors,
+                action_bar::rage_button::handle_rage_button,
+                action_bar::rage_button::handle_rage_button_colors,
+                action_bar::cleric_button::handle_cleric_buttons,
+                action_bar::cleric_button::handle_cleric_button_colors,
+            )
+                .run_if(in_state(SceneState::InGameClassicMode)),
+        );
+
+        app.add_systems(
+            Update,
+            (
+                level_up::setup.run_if(resource_exists::<UserInterfaceRoot>()),
+                proficiency_warning::warn_before_equip,
+                proficiency_warning::update_proficiency_warning,
+                action_bar::cleric_button::target_touch_spell.run_if(resource_exists::<
+                    action_bar::cleric_button::PendingTouchSpell,
+                >()),
+                (
+                    level_up::handle_level_up_buttons,
+                    level_up::handle_level_up_button_colors,
+                )
+                    .run_if(resource_exists::<LevelUpScreen>()),
+            )
+                .run_if(in_state(SceneState::InGameClassicMode)),
+        );
+
+        app.add_systems(
+            Update,
+            (
+                map::reachable::update_reachable_squares
+                    .run_if(resource_exists::<map::reachable::ReachableSquares>())
+                    .run_if(resource_exists::<SelectedAction>()),
+                map::reachable::spawn_reachable_overlay
+                    .run_if(resource_exists_and_changed::<
+                        map::reachable::ReachableSquares,
+                    >())
+                    .after(map::reachable::update_reachable_squares),
             )
                 .run_if(in_state(SceneState::InGameClassicMode)),
         );
@@ -81,6 +126,8 @@ impl Plugin for IngameUiPlugin {
             (
                 map::cleanup,
                 action_bar::cleanup,
+                level_up::cleanup,
+                proficiency_warning::cleanup,
                 combat_mode::cleanup,
                 turn_mode::cleanup,
             ),
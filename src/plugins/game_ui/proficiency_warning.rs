@@ -0,0 +1,198 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::{proficiencies::Proficiencies, racial_traits::WeaponFamiliarity},
+    materials::font::FontMaterials,
+    plugins::{
+        item::equipment::{armor::EquippedArmor, weapon::EquippedWeapons, EquipItem},
+        player::control::ActionPriority,
+    },
+    resources::{
+        dictionary::Dictionary,
+        equipment::{armor::Armor, weapon::Weapon},
+        glossary::ProficiencyText,
+    },
+};
+
+use super::ui_root::UserInterfaceRoot;
+
+#[derive(Component, Copy, Clone, Debug)]
+/// Lists the player's equipped gear which they are not proficient with, along with the penalty
+/// for using it.
+pub struct ProficiencyWarning;
+
+#[derive(Event, Clone, Copy, Debug)]
+/// Asks to equip a weapon or a piece of armor. If the creature is not proficient with it, the
+/// player is warned instead, and the item is only equipped if it is asked for again.
+pub struct EquipRequest {
+    pub creature: Entity,
+    pub item: Entity,
+}
+
+#[derive(Resource, Clone, Copy, Debug, Deref)]
+/// The item the player was last warned about, which is equipped if it is requested again.
+pub struct PendingEquip(Entity);
+
+pub fn setup(
+    mut commands: Commands,
+    ui_root: Res<UserInterfaceRoot>,
+    font_materials: Res<FontMaterials>,
+    dictionary: Res<Dictionary>,
+) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    commands
+        .get_entity(ui_root.entity)
+        .unwrap()
+        .with_children(|builder| {
+            builder
+                .spawn(TextBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(90.0),
+                        left: Val::Px(15.0),
+                        ..default()
+                    },
+                    text: Text::from_section(
+                        "",
+                        TextStyle {
+                            font,
+                            font_size: 20.0,
+                            color: Color::ORANGE_RED,
+                        },
+                    ),
+                    ..default()
+                })
+                .insert(ProficiencyWarning)
+                .insert(Name::new("Proficiency Warning"));
+        });
+}
+
+/// The warnings for each piece of gear in the lists which the creature is not proficient with.
+/// This can be used to warn the player before gear is equipped, as well as for gear already
+/// equipped.
+pub fn proficiency_warnings<'a>(
+    proficiencies: &Proficiencies,
+    familiarity: Option<&WeaponFamiliarity>,
+    weapons: impl Iterator<Item = &'a Weapon>,
+    armor: impl Iterator<Item = &'a Armor>,
+    glossary: &ProficiencyText,
+) -> Vec<String> {
+    let weapon_warnings = weapons
+        .filter(|weapon| !proficiencies.weapon(weapon, familiarity))
+        .map(|weapon| {
+            format!(
+                "{} {:?}: {}",
+                glossary.not_proficient, weapon.weapon_name, glossary.weapon_penalty
+            )
+        });
+    let armor_warnings = armor
+        .filter(|armor| !proficiencies.armor(armor))
+        .map(|armor| {
+            format!(
+                "{} {:?}: {} {}",
+                glossary.not_proficient,
+                armor.armor_name,
+                armor.armor_check_penalty(),
+                glossary.armor_penalty
+            )
+        });
+    weapon_warnings.chain(armor_warnings).collect()
+}
+
+/// Equips the requested item, unless the creature is not proficient with it and the player has not
+/// yet been warned about it, in which case the warning is shown and the item waits in
+/// `PendingEquip`.
+pub fn warn_before_equip(
+    mut commands: Commands,
+    mut request_reader: EventReader<EquipRequest>,
+    pending: Option<Res<PendingEquip>>,
+    creature_query: Query<(&Proficiencies, Option<&WeaponFamiliarity>)>,
+    weapon_query: Query<&Weapon>,
+    armor_query: Query<&Armor>,
+    mut warning_query: Query<&mut Text, With<ProficiencyWarning>>,
+    mut equip_writer: EventWriter<EquipItem>,
+    dictionary: Res<Dictionary>,
+) {
+    let glossary = dictionary.get_glossary().proficiency;
+    for request in request_reader.iter() {
+        let warnings =
+            creature_query
+                .get(request.creature)
+                .map_or(vec![], |(proficiencies, familiarity)| {
+                    proficiency_warnings(
+                        proficiencies,
+                        familiarity,
+                        weapon_query.get(request.item).into_iter(),
+                        armor_query.get(request.item).into_iter(),
+                        &glossary,
+                    )
+                });
+        let confirmed = pending
+            .as_deref()
+            .is_some_and(|pending| **pending == request.item);
+        if warnings.is_empty() || confirmed {
+            equip_writer.send(EquipItem {
+                creature: request.creature,
+                item: request.item,
+            });
+            commands.remove_resource::<PendingEquip>();
+        } else {
+            commands.insert_resource(PendingEquip(request.item));
+            for mut text in warning_query.iter_mut() {
+                text.sections[0].value =
+                    format!("{}\n{}", warnings.join("\n"), glossary.confirm_equip);
+            }
+        }
+    }
+}
+
+/// Updates the warning whenever the player's gear or proficiencies change.
+pub fn update_proficiency_warning(
+    player_query: Query<
+        (
+            &Proficiencies,
+            Option<&WeaponFamiliarity>,
+            Option<&EquippedWeapons>,
+            Option<&EquippedArmor>,
+        ),
+        (
+            With<ActionPriority>,
+            Or<(
+                Changed<Proficiencies>,
+                Changed<EquippedWeapons>,
+                Changed<EquippedArmor>,
+            )>,
+        ),
+    >,
+    weapon_query: Query<&Weapon>,
+    armor_query: Query<&Armor>,
+    mut warning_query: Query<&mut Text, With<ProficiencyWarning>>,
+    dictionary: Res<Dictionary>,
+) {
+    let Ok((proficiencies, familiarity, equipped_weapons, equipped_armor)) =
+        player_query.get_single()
+    else {
+        return;
+    };
+    let glossary = dictionary.get_glossary().proficiency;
+    let weapons = equipped_weapons
+        .iter()
+        .flat_map(|equipped| equipped.main_hand.iter().chain(equipped.off_hand.iter()))
+        .filter_map(|entity| weapon_query.get(*entity).ok());
+    let armor = equipped_armor
+        .iter()
+        .flat_map(|equipped| equipped.iter())
+        .filter_map(|entity| armor_query.get(entity).ok());
+    let warnings = proficiency_warnings(proficiencies, familiarity, weapons, armor, &glossary);
+
+    for mut text in warning_query.iter_mut() {
+        text.sections[0].value = warnings.join("\n");
+    }
+}
+
+pub fn cleanup(mut commands: Commands, warning_query: Query<Entity, With<ProficiencyWarning>>) {
+    for entity in warning_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<PendingEquip>();
+}
@@ -2,6 +2,10 @@
 
 use crate::config::*;
 
+/// The translation of the center of the top left square of the room.
+pub const START_X: f32 = 0.0 - WINDOW_HEIGHT * RESOLUTION / 2.0 + TILE_SIZE / 2.0;
+pub const START_Y: f32 = 0.0 + WINDOW_HEIGHT / 2.0 - TILE_SIZE / 2.0;
+
 /// Change co-ordinate from transform to UI.
 /// Alter the co-ordinates of an (x, y) point from a traditional cartesian
 /// graph with an origin in the center of the main window, which is used by
@@ -19,13 +23,34 @@ pub fn trans_to_window(x_tr: f32, y_tr: f32) -> (f32, f32) {
     (x_ui, y_ui)
 }
 
+/// Change co-ordinate from UI to transform, the inverse of `trans_to_window`.
 pub fn window_to_trans(x_ui: f32, y_ui: f32) -> (f32, f32) {
     let x_offset = WINDOW_HEIGHT * RESOLUTION / 2.0;
     let y_offset = WINDOW_HEIGHT / 2.0;
     // let tile_offset = TILE_SIZE / 2.0;
 
     let x_tr = x_ui - x_offset;
-    let y_tr = y_ui - y_offset;
+    let y_tr = y_offset - y_ui;
+
+    (x_tr, y_tr)
+}
+
+/// Change co-ordinate from grid to transform.
+/// The grid counts squares from the top left square of the room, with +x
+/// moving right one column and +y moving down one row. The translation is
+/// that of the center of the square.
+pub fn grid_to_trans(x_grid: i32, y_grid: i32) -> (f32, f32) {
+    let x_tr = START_X + x_grid as f32 * TILE_SIZE;
+    let y_tr = START_Y - y_grid as f32 * TILE_SIZE;
 
     (x_tr, y_tr)
 }
+
+/// Change co-ordinate from transform to grid, giving the square containing
+/// the point.
+pub fn trans_to_grid(x_tr: f32, y_tr: f32) -> (i32, i32) {
+    let x_grid = ((x_tr - START_X) / TILE_SIZE).round() as i32;
+    let y_grid = ((START_Y - y_tr) / TILE_SIZE).round() as i32;
+
+    (x_grid, y_grid)
+}
@@ -0,0 +1,179 @@
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+
+use crate::{
+    components::{
+        attributes::Constitution,
+        conditions::{Dead, Staggered, Unconscious},
+        hitpoints::{HealthState, HitPoints},
+    },
+    plugins::combat_mode::turn::action::CurrentTurn,
+};
+
+pub struct HealthPlugin;
+
+#[derive(Clone, Hash, Debug, PartialEq, Eq, SystemSet)]
+/// Systems which change hit points through `DamageEvent` and `HealEvent`. Anything which deals
+/// damage or heals should send its events before this set.
+pub struct ApplyHealth;
+
+impl Plugin for HealthPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DamageEvent>().add_event::<HealEvent>();
+
+        app.add_systems(
+            Update,
+            (apply_damage, apply_healing, update_health_conditions)
+                .chain()
+                .in_set(ApplyHealth),
+        );
+
+        app.add_systems(
+            Update,
+            regeneration
+                .before(ApplyHealth)
+                .run_if(resource_exists_and_changed::<CurrentTurn>()),
+        );
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Where damage came from, so the combat log can explain it.
+pub enum DamageSource {
+    /// The entity of the weapon used in the attack.
+    Weapon(Entity),
+    Spell,
+    ChannelEnergy,
+    Environment,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Where healing came from, so the combat log can explain it.
+pub enum HealSource {
+    Spell,
+    ChannelEnergy,
+    Potion,
+    Regeneration,
+    Rest,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HealType {
+    /// Heals lethal damage and an equal amount of nonlethal damage.
+    Normal,
+    /// Heals only nonlethal damage.
+    NonlethalOnly,
+    /// Grants temporary hit points, which do not stack.
+    Temporary,
+}
+
+#[derive(Copy, Clone, Debug, Event)]
+/// Every source of damage sends a `DamageEvent`, which is applied to the target's `HitPoints` by
+/// `apply_damage`.
+pub struct DamageEvent {
+    pub target: Entity,
+    pub amount: usize,
+    pub nonlethal: bool,
+    pub source: DamageSource,
+}
+
+#[derive(Copy, Clone, Debug, Event)]
+/// Every source of healing sends a `HealEvent`, which is applied to the target's `HitPoints` by
+/// `apply_healing`.
+pub struct HealEvent {
+    pub target: Entity,
+    pub amount: usize,
+    pub heal_type: HealType,
+    pub source: HealSource,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+/// The creature chooses to deal nonlethal damage with weapons which normally deal lethal damage,
+/// taking a -4 penalty on its attack rolls.
+pub struct DealNonlethal;
+
+#[derive(Component, Clone, Copy, Debug, Deref)]
+/// The creature heals this many hit points at the start of each of its turns.
+pub struct Regeneration(pub usize);
+
+pub fn apply_damage(
+    mut damage_reader: EventReader<DamageEvent>,
+    mut hit_points_query: Query<&mut HitPoints>,
+) {
+    let debug = false;
+    for damage in damage_reader.iter() {
+        if let Ok(mut hit_points) = hit_points_query.get_mut(damage.target) {
+            hit_points.take_damage(damage.amount, damage.nonlethal);
+            if debug {
+                println!(
+                    "debug | health::apply_damage | {:?} after damage: {:?}",
+                    damage, *hit_points
+                );
+            }
+        }
+    }
+}
+
+pub fn apply_healing(
+    mut heal_reader: EventReader<HealEvent>,
+    mut hit_points_query: Query<&mut HitPoints>,
+) {
+    let debug = false;
+    for heal in heal_reader.iter() {
+        if let Ok(mut hit_points) = hit_points_query.get_mut(heal.target) {
+            match heal.heal_type {
+                HealType::Normal => hit_points.heal(heal.amount),
+                HealType::NonlethalOnly => hit_points.heal_nonlethal(heal.amount),
+                HealType::Temporary => hit_points.add_temporary(heal.amount),
+            }
+            if debug {
+                println!(
+                    "debug | health::apply_healing | {:?} after healing: {:?}",
+                    heal, *hit_points
+                );
+            }
+        }
+    }
+}
+
+/// Keeps the `Staggered`, `Unconscious` and `Dead` conditions in line with the hit points of each
+/// creature.
+pub fn update_health_conditions(
+    mut commands: Commands,
+    hit_points_query: Query<(Entity, &HitPoints, Option<&Constitution>), Changed<HitPoints>>,
+) {
+    for (entity, hit_points, constitution) in hit_points_query.iter() {
+        let state = hit_points.state(constitution);
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.remove::<(Staggered, Unconscious, Dead)>();
+        match state {
+            HealthState::Healthy => {}
+            HealthState::Staggered | HealthState::Disabled => {
+                entity_commands.insert(Staggered);
+            }
+            HealthState::Unconscious | HealthState::Dying => {
+                entity_commands.insert(Unconscious);
+            }
+            HealthState::Dead => {
+                entity_commands.insert(Dead);
+            }
+        }
+    }
+}
+
+/// Heals creatures with `Regeneration` at the start of their turn.
+pub fn regeneration(
+    current_turn: Res<CurrentTurn>,
+    regeneration_query: Query<&Regeneration>,
+    mut heal_writer: EventWriter<HealEvent>,
+) {
+    if let Ok(regeneration) = regeneration_query.get(current_turn.entity) {
+        heal_writer.send(HealEvent {
+            target: current_turn.entity,
+            amount: **regeneration,
+            heal_type: HealType::Normal,
+            source: HealSource::Regeneration,
+        });
+    }
+}
@@ -21,7 +21,6 @@ pub struct InputHandlePlugin;
 impl Plugin for InputHandlePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<movement::Movement>();
-        app.init_resource::<movement::click_move::PathNodes>();
         app.init_resource::<movement::click_move::PathConditions>();
 
         app.add_event::<MovementPathEvent>();
@@ -1,15 +1,16 @@
 #![allow(dead_code)]
 
-use crate::plugins::actions::event::MoveActionEvent;
+use crate::components::creature::{Faction, LandSpeed};
+use crate::plugins::actions::ActionStatus;
 use crate::plugins::actions::TurnActionStatus;
+use crate::plugins::combat_mode::state::CombatMode;
+use crate::plugins::game_ui::action_bar::submenu_button::{SelectedSubMenu, SubMenuActions};
 use crate::plugins::game_ui::action_bar::ActionBarButton;
 use crate::plugins::game_ui::action_bar::SelectedAction;
+use crate::plugins::game_ui::combat_mode::CombatModeRes;
 use crate::plugins::interact::InteractingPosEvent;
 use crate::plugins::interact::InteractingType;
 use crate::plugins::interact::InteractionActive;
-use crate::plugins::monster::collisions::monster_collision_check;
-use std::ops::Deref;
-use std::ops::DerefMut;
 
 use bevy::prelude::*;
 
@@ -24,9 +25,9 @@ use crate::plugins::input::movement::MovementMode;
 use crate::plugins::input::movement::MovementModeRes;
 use crate::plugins::input::movement::PlayerComponent;
 use crate::plugins::interact::InteractingPos;
-use crate::plugins::player::collisions::wall_collision_check;
-use crate::resources::dungeon::block_type::BlockType;
-use crate::resources::monster::Monster;
+use crate::resources::dungeon::grid_occupancy::GridOccupancy;
+use crate::resources::dungeon::grid_pos::GridPos;
+use crate::resources::dungeon::pathfinding::{self, MoveBudget};
 
 use super::map::MapGrid;
 
@@ -34,8 +35,8 @@ use super::map::MapGrid;
 pub struct MovementPathList {
     pub list: Vec<MovementPath>,
     pub focused: MovementPath,
-    pub start: Vec3,
-    pub end: Vec3,
+    pub start: GridPos,
+    pub end: GridPos,
     pub displayed: bool,
     pub active: bool,
     pub move_ready: bool,
@@ -73,12 +74,19 @@ impl MovementPathList {
         }
     }
 
+    /// The budget left after moving along every path in the list.
+    pub fn remaining(&self, budget: MoveBudget) -> MoveBudget {
+        self.list.iter().fold(budget, |budget, path| {
+            budget.after(path.cost, path.odd_diagonal)
+        })
+    }
+
     pub fn to_event(&self, action: Option<PathListAction>) -> PathListEvent {
         PathListEvent::new(Some(self.list.clone()), action)
     }
 
     pub fn list_to_path(&self) -> MovementPath {
-        let mut new_list: Vec<(Vec3, Vec3)> = self
+        let mut new_list: Vec<(GridPos, GridPos)> = self
             .list
             .clone()
             .into_iter()
@@ -87,31 +95,13 @@ impl MovementPathList {
             .collect();
         new_list.dedup();
 
-        MovementPath::new_inactive(new_list)
-    }
-}
-
-#[derive(Clone, Debug)]
-pub struct MoveNode {
-    pub pos: Vec3,
-    pub target_dist: f32,
-    pub travel_dist: f32,
-    pub open: bool,
-    pub path: Vec<Vec3>,
-}
-
-impl MoveNode {
-    pub fn to_new_pos(&self, step: Vec3, dest: Vec3) -> MoveNode {
-        let mut path: Vec<Vec3> = self.path.clone();
-        path.push(self.pos + step);
-
-        MoveNode {
-            pos: self.pos + step,
-            target_dist: (self.pos + step).distance(dest),
-            travel_dist: self.travel_dist + step.length(),
-            open: true,
-            path,
-        }
+        let mut path = MovementPath::new_inactive(new_list);
+        path.cost = self.list.iter().map(|move_path| move_path.cost).sum();
+        path.odd_diagonal = self
+            .list
+            .iter()
+            .fold(false, |odd, move_path| odd != move_path.odd_diagonal);
+        path
     }
 }
 
@@ -129,15 +119,16 @@ pub fn check_path_conditions(
     interaction_active: Res<InteractionActive>,
     mut path_ready: ResMut<PathConditions>,
 ) {
-    let player_pos = player_query.get_single().unwrap().1.translation.truncate();
-    let focus_pos = interacting_pos.pos;
+    let player_pos =
+        GridPos::from_world(player_query.get_single().unwrap().1.translation.truncate());
+    let focus_pos = interacting_pos.grid_pos();
     **path_ready = !**interaction_active
         && **selected_action == ActionBarButton::Move
         && interacting_pos.interacting_type == InteractingType::MapGrid
         && **movement_mode == MovementMode::TurnBasedMovement
         && !movement.moving
         && player_pos != focus_pos
-        && map_grid.positions.as_slice().contains(&player_pos)
+        && map_grid.positions.contains(&player_pos)
         && (if let Some(move_path) = movement_path {
             !move_path.is_traversing()
         } else {
@@ -152,17 +143,28 @@ pub fn handle_path(
     mut list_event_writer: EventWriter<PathListEvent>,
     mut move_event_writer: EventWriter<MovementPathEvent>,
     interacting_pos_reader: EventReader<InteractingPosEvent>,
-    mut move_action_writer: EventWriter<MoveActionEvent>,
 
     interacting_pos: Res<InteractingPos>,
     path_ready: Res<PathConditions>,
 ) {
-    let focus_pos = interacting_pos.pos;
+    let debug = false;
+    let focus_pos = interacting_pos.grid_pos();
     if **path_ready {
         if let Some(mut path_list) = path_list {
-            if button.just_pressed(MouseButton::Left) {
+            // Paths are only planned to squares within the budget of the selected move, so a
+            // square which is not at the end of a planned path is out of reach.
+            let planned = focus_pos == path_list.end
+                || (!path_list.focused.path.is_empty() && focus_pos == path_list.focused.end());
+            if button.just_pressed(MouseButton::Left) && !planned {
+                if debug {
+                    println!(
+                        "debug | click_move::handle_path | {:?} is out of reach",
+                        focus_pos
+                    );
+                }
+            } else if button.just_pressed(MouseButton::Left) {
                 if !path_list.focused.path.is_empty()
-                    && focus_pos == path_list.focused.end().truncate()
+                    && focus_pos == path_list.focused.end()
                     && !(path_list.end == path_list.focused.end())
                     || !path_list.active
                 {
@@ -173,7 +175,6 @@ pub fn handle_path(
                     let move_path = path_list.list_to_path();
                     move_event.set_move_path(move_path);
                     move_event_writer.send(move_event);
-                    move_action_writer.send(MoveActionEvent);
                 }
             } else if button.just_pressed(MouseButton::Right) {
                 list_event_writer.send(PathListAction::Remove.into());
@@ -193,71 +194,64 @@ pub fn handle_path(
     }
 }
 
-#[derive(Resource, Clone, Default)]
-pub struct PathNodes {
-    pub paths: Vec<MoveNode>,
-    pub ignore: Vec<MoveNode>,
-}
-
-impl PathNodes {
-    pub fn ignore_node(&mut self, node: MoveNode) {
-        self.ignore.push(node);
-    }
-}
-
-impl Deref for PathNodes {
-    type Target = Vec<MoveNode>;
-    fn deref(&self) -> &Self::Target {
-        &self.paths
-    }
-}
-
-impl DerefMut for PathNodes {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.paths
-    }
-}
-
-impl PathNodes {
-    pub fn reset(&mut self) {
-        self.paths = vec![];
+/// How far the player may move with the move selected in the action bar. Nothing can be planned
+/// once the selected move has been used this turn, while out of combat movement is not limited.
+pub fn move_budget(
+    combat_mode: &CombatModeRes,
+    selected_submenu: &SelectedSubMenu,
+    action_status: Option<&ActionStatus>,
+    land_speed: Option<&LandSpeed>,
+) -> MoveBudget {
+    match **combat_mode {
+        CombatMode::InCombat => {
+            let move_button = selected_submenu.move_submenu;
+            if action_status.is_some_and(|status| !move_button.is_action_available(status)) {
+                MoveBudget::feet(0)
+            } else {
+                move_button.move_budget(land_speed.copied().unwrap_or_default())
+            }
+        }
+        CombatMode::OutOfCombat => MoveBudget::unlimited(),
     }
 }
 
 pub fn start_path_list(
     mut commands: Commands,
-    player_query: Query<(&PlayerComponent, &Transform)>,
-    block_type_query: Query<(&BlockType, &Transform), Without<PlayerComponent>>,
-    monster_query: Query<(&Monster, &Transform), Without<PlayerComponent>>,
+    player_query: Query<(Entity, &Transform, &Faction, Option<&LandSpeed>), With<PlayerComponent>>,
+    faction_query: Query<&Faction>,
+    occupancy: Res<GridOccupancy>,
+    combat_mode: Res<CombatModeRes>,
+    selected_submenu: Res<SelectedSubMenu>,
+    action_status: Option<Res<ActionStatus>>,
     interacting_pos: Res<InteractingPos>,
-    mut open_paths: ResMut<PathNodes>,
     mut sprite_writer: EventWriter<PathSpriteEvent>,
     mut event_reader: EventReader<PathListEvent>,
 ) {
-    open_paths.reset();
     if event_reader
         .into_iter()
         .filter_map(|event| event.action)
         .any(|action| action == PathListAction::StartPath)
     {
-        let (_player, transform) = player_query.single();
-
-        let start = transform.translation;
-        let end = Vec3::new(interacting_pos.pos.x, interacting_pos.pos.y, start.z);
-
-        let start_node = MoveNode {
-            pos: start,
-            target_dist: start.distance(end),
-            travel_dist: 0.0,
-            open: true,
-            path: vec![start],
-        };
-
-        if open_paths.paths.is_empty() {
-            open_paths.paths = vec![start_node];
-        }
-
-        if let Some(finished_path) = find_path(open_paths, block_type_query, monster_query, end) {
+        let (player, transform, faction, land_speed) = player_query.single();
+
+        let start = GridPos::from_world(transform.translation.truncate());
+        let end = interacting_pos.grid_pos();
+        let budget = move_budget(
+            &combat_mode,
+            &selected_submenu,
+            action_status.as_deref(),
+            land_speed,
+        );
+
+        if let Some(finished_path) = find_path(
+            &occupancy,
+            player,
+            *faction,
+            &faction_query,
+            start,
+            end,
+            budget,
+        ) {
             sprite_writer.send(PathSpriteEvent::spawn_move_path(finished_path.clone()));
             if !finished_path.path.is_empty() {
                 commands.insert_resource::<MovementPathList>(MovementPathList::new_from_path(
@@ -269,9 +263,12 @@ pub fn start_path_list(
 }
 
 pub fn add_path(
-    block_type_query: Query<(&BlockType, &Transform), Without<PlayerComponent>>,
-    monster_query: Query<(&Monster, &Transform), Without<PlayerComponent>>,
-    mut open_paths: ResMut<PathNodes>,
+    player_query: Query<(Entity, &Faction, Option<&LandSpeed>), With<PlayerComponent>>,
+    faction_query: Query<&Faction>,
+    occupancy: Res<GridOccupancy>,
+    combat_mode: Res<CombatModeRes>,
+    selected_submenu: Res<SelectedSubMenu>,
+    action_status: Option<Res<ActionStatus>>,
     path_list: Option<ResMut<MovementPathList>>,
     mut event_reader: EventReader<PathListEvent>,
     mut sprite_writer: EventWriter<PathSpriteEvent>,
@@ -283,7 +280,6 @@ pub fn add_path(
     // may have some unintended consequences.
     // Also see if there is a way to get rid of the clone
 
-    open_paths.reset();
     let debug = false;
     // if debug {
     //     println!("debug | add_path start");
@@ -292,88 +288,59 @@ pub fn add_path(
         if action == PathListAction::AddPath {
             if let Some(mut path_list) = path_list {
                 let start = path_list.end;
-                let end = Vec3::new(interacting_pos.pos.x, interacting_pos.pos.y, start.z);
+                let end = interacting_pos.grid_pos();
                 if debug {
-                    println!("debug | add_path | start: {}, end: {}", start, end);
-                }
-                let start_node = MoveNode {
-                    pos: start,
-                    target_dist: start.distance(end),
-                    travel_dist: 0.0,
-                    open: true,
-                    path: vec![start],
-                };
-                open_paths.paths = vec![start_node];
-                if let Some(new_path) = find_path(open_paths, block_type_query, monster_query, end)
-                {
-                    path_list.set_focused(new_path.clone());
-                    let mut total_path = path_list.clone().list_to_path();
-                    total_path.join(path_list.focused.clone());
-                    sprite_writer.send(PathSpriteEvent::spawn_move_path(total_path.clone()));
+                    println!("debug | add_path | start: {:?}, end: {:?}", start, end);
                 }
+                let (player, faction, land_speed) = player_query.single();
+                let budget = path_list.remaining(move_budget(
+                    &combat_mode,
+                    &selected_submenu,
+                    action_status.as_deref(),
+                    land_speed,
+                ));
+                // If the square is out of reach, only the paths already in the list are shown.
+                let new_path = find_path(
+                    &occupancy,
+                    player,
+                    *faction,
+                    &faction_query,
+                    start,
+                    end,
+                    budget,
+                )
+                .unwrap_or_else(|| MovementPath::new_inactive(vec![]));
+                path_list.set_focused(new_path);
+                let mut total_path = path_list.clone().list_to_path();
+                total_path.join(path_list.focused.clone());
+                sprite_writer.send(PathSpriteEvent::spawn_move_path(total_path.clone()));
             }
         }
     }
 }
 
+/// Finds the cheapest path for the player to the end square. Creatures of other factions block
+/// the path, while allies can be moved through.
 fn find_path(
-    mut open_paths: ResMut<'_, PathNodes>,
-    // mut event_writer: EventWriter<'_, MovementPathEvent>,
-    block_type_query: Query<'_, '_, (&BlockType, &Transform), Without<PlayerComponent>>,
-    monster_query: Query<'_, '_, (&Monster, &Transform), Without<PlayerComponent>>,
-    end: Vec3,
+    occupancy: &GridOccupancy,
+    player: Entity,
+    faction: Faction,
+    faction_query: &Query<&Faction>,
+    start: GridPos,
+    end: GridPos,
+    budget: MoveBudget,
 ) -> Option<MovementPath> {
     let debug = false;
+    let is_enemy = |entity: Entity| {
+        !faction_query
+            .get(entity)
+            .is_ok_and(|other| other.is_ally(faction))
+    };
+    let path = pathfinding::find_path(occupancy, player, is_enemy, start, end, budget);
     if debug {
-        println!("debug | find_path | start find_path");
-    }
-
-    let mut max_loops = 1000;
-    while open_paths.paths.iter().any(|node| node.open) {
-        max_loops -= 1;
-        let closest = open_paths
-            .paths
-            .iter_mut()
-            .filter(|node| node.open)
-            .min_by(|x, y| {
-                (x.target_dist + x.travel_dist).total_cmp(&(y.target_dist + y.travel_dist))
-            })
-            .unwrap();
-        if closest.target_dist == 0.0 {
-            let queue: Vec<(Vec3, Vec3)> = closest
-                .path
-                .clone()
-                .into_iter()
-                .zip(closest.path.clone().into_iter().skip(1))
-                .rev()
-                .collect();
-            return Some(MovementPath::new_inactive(queue));
-        } else if max_loops == 0 {
-            println!("max_loops reached");
-            break;
-        }
-        let mut wall_check = wall_collision_check(closest.pos, &block_type_query);
-        let monster_check = monster_collision_check(closest.pos, &monster_query);
-        if debug {
-            println!(
-                "debug | find_path | wall_check: \n\t{:?}",
-                wall_check.clone()
-            );
-            println!(
-                "debug | find_path | monster_check: \n\t{:?}",
-                monster_check.clone()
-            );
-            println!(
-                "debug | find_path | merged wall_check and monster_check: \n\t{:?}",
-                wall_check.merge(monster_check)
-            );
-        }
-        let possible_paths = wall_check.merge(monster_check).open_nodes(closest, end);
-        closest.open = false;
-
-        open_paths.paths.extend_from_slice(&possible_paths);
+        println!("debug | find_path | path: {:?}", path);
     }
-    None
+    path.map(MovementPath::from)
 }
 
 pub fn path_list_cleanup(
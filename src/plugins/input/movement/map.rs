@@ -1,6 +1,9 @@
 use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+use crate::resources::dungeon::grid_pos::GridPos;
 
 #[derive(Resource, Clone)]
 pub struct MapGrid {
-    pub positions: Vec<Vec2>,
+    pub positions: HashSet<GridPos>,
 }
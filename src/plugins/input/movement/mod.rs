@@ -7,9 +7,7 @@ use crate::plugins::game_ui::turn_mode::{MovementMode, MovementModeRes};
 use crate::plugins::input::movement::turn_based::to_nearest_square;
 use crate::plugins::input::movement::turn_based::turn_based_movement;
 use crate::plugins::input::movement::wander::wander_movement_system;
-use crate::plugins::interact::Interactable;
-use crate::resources::dungeon::block_type::BlockType;
-use crate::resources::dungeon::grid_square::GridSquare;
+use crate::resources::dungeon::grid_occupancy::GridOccupancy;
 
 pub mod click_move;
 pub mod map;
@@ -24,27 +22,20 @@ pub mod wander;
 pub fn player_movement_system(
     movement_mode: Res<MovementModeRes>,
     player_query: Query<(&PlayerComponent, &mut PlayerAnimation, &mut Transform)>,
-    block_type_query: Query<(&BlockType, &Transform), Without<PlayerComponent>>,
-    ground_query: Query<(&Transform, &Interactable), (Without<PlayerComponent>, With<GridSquare>)>,
+    occupancy: Res<GridOccupancy>,
     keyboard_input: Res<Input<KeyCode>>,
     time: Res<Time>,
     movement: ResMut<Movement>,
 ) {
     if movement_mode.is_changed() && **movement_mode == MovementMode::TurnBasedMovement {
-        to_nearest_square(player_query, ground_query, movement);
+        to_nearest_square(player_query, movement);
     } else {
         match **movement_mode {
             MovementMode::WanderMovement => {
-                wander_movement_system(player_query, block_type_query, keyboard_input, time)
+                wander_movement_system(player_query, occupancy, keyboard_input, time)
             }
             MovementMode::TurnBasedMovement => {
-                turn_based_movement(
-                    player_query,
-                    block_type_query,
-                    keyboard_input,
-                    time,
-                    movement,
-                );
+                turn_based_movement(player_query, occupancy, keyboard_input, time, movement);
             }
         };
     }
@@ -2,15 +2,28 @@
 
 use bevy::prelude::*;
 
+use crate::plugins::actions::event::MoveActionEvent;
+use crate::plugins::combat_mode::state::CombatMode;
+use crate::plugins::game_ui::action_bar::submenu_button::{MoveButton, SelectedSubMenu};
+use crate::plugins::game_ui::combat_mode::CombatModeRes;
 use crate::plugins::input::movement::move_event::MovePathAction;
 use crate::plugins::input::movement::move_event::MovementPathEvent;
 use crate::plugins::input::movement::Movement;
 use crate::plugins::input::movement::PlayerAnimation;
 use crate::plugins::input::movement::PlayerComponent;
+use crate::resources::dungeon::grid_pos::GridPos;
+use crate::resources::dungeon::pathfinding::GridPath;
 
 #[derive(Resource, Clone, Debug, PartialEq)]
 pub struct MovementPath {
-    pub path: Vec<(Vec3, Vec3)>,
+    pub path: Vec<(GridPos, GridPos)>,
+    /// The cost of the path in feet.
+    pub cost: u32,
+    /// Whether the path moves an odd number of diagonals, for the 5-10-5 rule.
+    pub odd_diagonal: bool,
+    /// The move submenu selected when the player started moving along the path, which is the
+    /// turn action the move uses.
+    move_button: Option<MoveButton>,
     active: bool,
     traversing: bool,
 }
@@ -28,17 +41,23 @@ impl MovementPath {
         self.active = false;
     }
 
-    pub fn new_inactive(path: Vec<(Vec3, Vec3)>) -> Self {
+    pub fn new_inactive(path: Vec<(GridPos, GridPos)>) -> Self {
         Self {
             path,
+            cost: 0,
+            odd_diagonal: false,
+            move_button: None,
             active: false,
             traversing: false,
         }
     }
 
-    pub fn new_active(path: Vec<(Vec3, Vec3)>) -> Self {
+    pub fn new_active(path: Vec<(GridPos, GridPos)>) -> Self {
         Self {
             path,
+            cost: 0,
+            odd_diagonal: false,
+            move_button: None,
             active: true,
             traversing: false,
         }
@@ -58,16 +77,27 @@ impl MovementPath {
         event
     }
 
-    pub fn end(&self) -> Vec3 {
+    pub fn end(&self) -> GridPos {
         self.path.first().unwrap().1
     }
 
-    pub fn start(&self) -> Vec3 {
+    pub fn start(&self) -> GridPos {
         self.path.last().unwrap().0
     }
 
     pub fn join(&mut self, other: Self) {
-        self.path.extend_from_slice(other.path.as_slice())
+        self.path.extend_from_slice(other.path.as_slice());
+        self.cost += other.cost;
+        self.odd_diagonal = self.odd_diagonal != other.odd_diagonal;
+    }
+}
+
+impl From<GridPath> for MovementPath {
+    fn from(value: GridPath) -> Self {
+        let mut move_path = MovementPath::new_inactive(value.steps());
+        move_path.cost = value.cost;
+        move_path.odd_diagonal = value.odd_diagonal;
+        move_path
     }
 }
 
@@ -76,7 +106,10 @@ pub fn path_move_system(
     move_path: Option<ResMut<MovementPath>>,
     mut movement: ResMut<Movement>,
     time: Res<Time>,
+    selected_submenu: Res<SelectedSubMenu>,
+    combat_mode: Option<Res<CombatModeRes>>,
     mut event_writer: EventWriter<MovementPathEvent>,
+    mut move_action_writer: EventWriter<MoveActionEvent>,
 ) {
     let debug = false;
     if debug {
@@ -92,16 +125,26 @@ pub fn path_move_system(
             );
         }
         if move_path.is_active() {
+            // The submenu may change while moving, so the move action is the one selected when
+            // the move started.
+            let move_button = *move_path
+                .move_button
+                .get_or_insert(selected_submenu.move_submenu);
             if !movement.moving {
                 if let Some((start, end)) = move_path.path.pop() {
-                    let delta = end - start;
+                    let delta = end.to_world() - start.to_world();
                     movement.set_target(
                         transform.translation.truncate(),
-                        delta.truncate(),
+                        delta,
                         player_stats.speed,
                     );
                 } else {
+                    // The move action is only used up once the whole path has been moved, and
+                    // only in combat.
                     event_writer.send(MovePathAction::Remove.into());
+                    if combat_mode.is_some_and(|mode| **mode == CombatMode::InCombat) {
+                        move_action_writer.send(MoveActionEvent(move_button));
+                    }
                 }
             } else if !movement.is_finished() {
                 let time_delta = time.delta();
@@ -3,17 +3,16 @@ use bevy::prelude::*;
 use crate::components::player::PlayerComponent;
 use crate::components::player_animation::PlayerAnimation;
 use crate::config::*;
-use crate::plugins::interact::Interactable;
 use crate::plugins::player::collisions::wall_collision_check;
 use crate::resources::animation_state::AnimationState;
-use crate::resources::dungeon::block_type::BlockType;
-use crate::resources::dungeon::grid_square::GridSquare;
+use crate::resources::dungeon::grid_occupancy::GridOccupancy;
+use crate::resources::dungeon::grid_pos::GridPos;
 
 use super::Movement;
 
 pub fn turn_based_movement(
     mut player_query: Query<(&PlayerComponent, &mut PlayerAnimation, &mut Transform)>,
-    block_type_query: Query<(&BlockType, &Transform), Without<PlayerComponent>>,
+    occupancy: Res<GridOccupancy>,
     keyboard_input: Res<Input<KeyCode>>,
     time: Res<Time>,
     mut movement: ResMut<Movement>,
@@ -26,7 +25,7 @@ pub fn turn_based_movement(
     let player_position = transform.translation;
     player_animation.animation_state = AnimationState::Idle;
 
-    let player_available_movement = wall_collision_check(player_position, &block_type_query);
+    let player_available_movement = wall_collision_check(player_position, &occupancy);
 
     if !movement.moving && movement.target.is_none() {
         if keyboard_input.pressed(KeyCode::W) && player_available_movement.can_move_up() {
@@ -81,7 +80,6 @@ pub fn turn_based_movement(
 
 pub fn to_nearest_square(
     mut player_query: Query<(&PlayerComponent, &mut PlayerAnimation, &mut Transform)>,
-    ground_query: Query<(&Transform, &Interactable), (Without<PlayerComponent>, With<GridSquare>)>,
     mut movement: ResMut<Movement>,
 ) {
     let debug = false;
@@ -90,12 +88,9 @@ pub fn to_nearest_square(
     let player_position = transform.translation;
     player_animation.animation_state = AnimationState::Moving;
 
-    let nearest_square_center = ground_query
-        .iter()
-        .filter(|(_, interactable)| interactable.bound_tr.contains(player_position.truncate()))
-        .map(|(transform, _)| transform.translation)
-        .next()
-        .unwrap();
+    let nearest_square_center = GridPos::from_world(player_position.truncate())
+        .to_world()
+        .extend(player_position.z);
 
     if debug {
         println!("debug | nearest_square_center: {:?}", nearest_square_center);
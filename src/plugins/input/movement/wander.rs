@@ -6,11 +6,11 @@ use crate::config::*;
 use crate::plugins::game_ui::translate::trans_to_window;
 use crate::plugins::player::collisions::wall_collision_check;
 use crate::resources::animation_state::AnimationState;
-use crate::resources::dungeon::block_type::BlockType;
+use crate::resources::dungeon::grid_occupancy::GridOccupancy;
 
 pub fn wander_movement_system(
     mut player_query: Query<(&PlayerComponent, &mut PlayerAnimation, &mut Transform)>,
-    block_type_query: Query<(&BlockType, &Transform), Without<PlayerComponent>>,
+    occupancy: Res<GridOccupancy>,
     keyboard_input: Res<Input<KeyCode>>,
     time: Res<Time>,
 ) {
@@ -22,7 +22,7 @@ pub fn wander_movement_system(
     let player_position = transform.translation;
     player_animation.animation_state = AnimationState::Idle;
 
-    let player_available_movement = wall_collision_check(player_position, &block_type_query);
+    let player_available_movement = wall_collision_check(player_position, &occupancy);
 
     if keyboard_input.pressed(KeyCode::W) && player_available_movement.can_move_up() {
         delta.y += player_stats.speed * TILE_SIZE * time.delta_seconds();
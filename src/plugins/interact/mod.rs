@@ -8,6 +8,7 @@ use std::ops::Deref;
 use std::ops::DerefMut;
 use std::slice::Iter;
 
+use crate::resources::dungeon::grid_pos::GridPos;
 use crate::scenes::SceneState;
 
 use crate::plugins::game_ui::translate::trans_to_window;
@@ -52,12 +53,14 @@ pub enum InteractingType {
     MapGrid,
     Enemy,
     PlayerCharacter,
+    /// The ladder or treasure at the end of a floor.
+    EndPoint,
 }
 
 impl InteractingType {
     pub fn iterator() -> Iter<'static, Self> {
         use InteractingType::*;
-        [Enemy, MapGrid, PlayerCharacter].iter()
+        [Enemy, MapGrid, PlayerCharacter, EndPoint].iter()
     }
 }
 
@@ -77,6 +80,13 @@ impl InteractingType {
 //     }
 // }
 
+impl InteractingPos {
+    /// The square containing the center of the focused entity.
+    pub fn grid_pos(&self) -> GridPos {
+        GridPos::from_world(self.pos)
+    }
+}
+
 impl Deref for InteractingPos {
     type Target = Vec2;
     fn deref(&self) -> &Self::Target {
@@ -198,6 +208,18 @@ impl BoundingBox {
         self.lower.min(cursor_pos) == self.lower && self.upper.max(cursor_pos) == self.upper
     }
 
+    /// Whether the square is one of the squares covered by a `translation` frame box. The
+    /// corners of the box are moved half a square inwards first, so that squares which only
+    /// touch the border of the box are not counted.
+    pub fn contains_square(&self, square: GridPos) -> bool {
+        let half = Vec2::splat(TILE_SIZE / 2.0);
+        // Rows count down the screen, so the lower left corner is in the bottom row.
+        let bottom_left = GridPos::from_world(self.lower + half);
+        let top_right = GridPos::from_world(self.upper - half);
+        (bottom_left.x..=top_right.x).contains(&square.x)
+            && (top_right.y..=bottom_left.y).contains(&square.y)
+    }
+
     /// Creates a new BoundingBox with `translation` reference frame instead of
     /// a `window frame` reference frame. This requires changing the points from
     /// the upper left and lower right to the lower left and upper right.
@@ -272,9 +294,11 @@ pub fn interact_system(
     let debug = false;
     for window in window_query.iter() {
         if let Some(cursor_pos) = window.cursor_position() {
+            let cursor_square = GridPos::from_window(cursor_pos);
             let mut highest_priority_event: Option<InteractingPosEvent> = None;
             for (entity, mut interactable) in interactable_query.iter_mut() {
-                let is_interacting = interactable.bound_wf.contains(cursor_pos);
+                let is_interacting =
+                    interactable.active && interactable.bound_tr.contains_square(cursor_square);
                 interactable.focused = is_interacting;
 
                 if is_interacting
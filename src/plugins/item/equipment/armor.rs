@@ -0,0 +1,142 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    components::{
+        class_features::{ArmorTraining, FastMovement},
+        creature::{BaseSpeed, LandSpeed},
+        player::PlayerComponent,
+        racial_traits::{RacialTrait, RacialTraits},
+    },
+    resources::equipment::armor::{armored_speed, Armor, ArmorCategory},
+    resources::game_data::GameData,
+};
+
+/// The armor and shield slots of a creature. Each slot holds the entity of the equipped item,
+/// which has an `Armor` component.
+#[derive(Component, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct EquippedArmor {
+    pub armor: Option<Entity>,
+    pub shield: Option<Entity>,
+}
+
+impl EquippedArmor {
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.armor.iter().chain(self.shield.iter()).copied()
+    }
+
+    /// The lowest maximum Dexterity bonus of the equipped armor and shield, if any, after the
+    /// wearer's Armor Training.
+    pub fn max_dex(
+        &self,
+        armor_query: &Query<&Armor>,
+        armor_training: Option<&ArmorTraining>,
+    ) -> Option<isize> {
+        self.iter()
+            .filter_map(|entity| armor_query.get(entity).ok())
+            .filter_map(|armor| match armor_training {
+                Some(training) => training.max_dex(armor),
+                None => armor.max_dex(),
+            })
+            .min()
+    }
+}
+
+/// The combined armor check penalty of the equipped armor and shield.
+#[derive(Component, Debug, Clone, Copy, Default, Deref, DerefMut)]
+pub struct ArmorCheckPenalty(isize);
+
+/// The combined arcane spell failure chance, in percent, of the equipped armor and shield.
+#[derive(Component, Debug, Clone, Copy, Default, Deref, DerefMut)]
+pub struct ArcaneSpellFailure(usize);
+
+/// Updates the armor check penalty, arcane spell failure and speed of a creature whenever the
+/// equipped armor, or the creature's Armor Training, Fast Movement or base speed, changes.
+pub fn update_armor_penalties(
+    mut commands: Commands,
+    mut wearer_query: Query<
+        (
+            Entity,
+            &EquippedArmor,
+            (Option<&ArmorTraining>, Option<&FastMovement>),
+            (Option<&BaseSpeed>, Option<&RacialTraits>),
+            Option<&mut PlayerComponent>,
+        ),
+        Or<(
+            Changed<EquippedArmor>,
+            Changed<ArmorTraining>,
+            Changed<FastMovement>,
+            Changed<BaseSpeed>,
+        )>,
+    >,
+    armor_query: Query<&Armor>,
+    game_data: Res<GameData>,
+) {
+    let debug = false;
+    for (
+        entity,
+        equipped_armor,
+        (armor_training, fast_movement),
+        (base_speed, racial_traits),
+        player,
+    ) in wearer_query.iter_mut()
+    {
+        let worn: Vec<&Armor> = equipped_armor
+            .iter()
+            .filter_map(|entity| armor_query.get(entity).ok())
+            .collect();
+
+        let armor_check_penalty = worn
+            .iter()
+            .map(|armor| match armor_training {
+                Some(training) => training.armor_check_penalty(armor),
+                None => armor.armor_check_penalty(),
+            })
+            .sum();
+        let arcane_spell_failure = worn
+            .iter()
+            .map(|armor| armor.arcane_spell_failure())
+            .sum::<usize>()
+            .min(100);
+
+        commands.entity(entity).insert((
+            ArmorCheckPenalty(armor_check_penalty),
+            ArcaneSpellFailure(arcane_spell_failure),
+        ));
+
+        let wearing_heavy_armor = worn
+            .iter()
+            .any(|armor| armor.category == ArmorCategory::Heavy);
+        let fast_movement_bonus =
+            fast_movement.map_or(0, |_| FastMovement::speed_bonus(wearing_heavy_armor));
+        let base_feet = base_speed.map_or(LandSpeed::default().0, |base_speed| **base_speed)
+            + fast_movement_bonus;
+        let slow_and_steady = racial_traits
+            .is_some_and(|racial_traits| racial_traits.has(RacialTrait::SlowAndSteady));
+        let slowed = !slow_and_steady
+            && worn.iter().any(|armor| {
+                armor.category.slows_wearer()
+                    && !armor_training
+                        .is_some_and(|training| training.ignores_speed_penalty(armor.category))
+            });
+        let land_speed = LandSpeed(if slowed {
+            armored_speed(base_feet)
+        } else {
+            base_feet
+        });
+        commands.entity(entity).insert(land_speed);
+
+        if let Some(mut player) = player {
+            player.speed =
+                game_data.get_hero(player.class.clone()).stats.speed * land_speed.multiplier();
+        }
+
+        if debug {
+            println!(
+                "debug | armor::update_armor_penalties | armor check penalty: {}, \
+                arcane spell failure: {}%",
+                armor_check_penalty, arcane_spell_failure
+            );
+        }
+    }
+}
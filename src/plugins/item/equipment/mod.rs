@@ -1 +1,52 @@
+use bevy::prelude::*;
+
+use crate::resources::equipment::{
+    armor::{Armor, ArmorCategory},
+    weapon::Weapon,
+};
+
+use self::{armor::EquippedArmor, weapon::EquippedWeapons};
+
+pub mod armor;
 pub mod weapon;
+
+#[derive(Event, Clone, Copy, Debug)]
+/// Equips a weapon or a piece of armor carried by the creature, in its main hand or in its armor
+/// or shield slot. Gear the player is not proficient with should be requested with an
+/// `EquipRequest` instead, which warns them first.
+pub struct EquipItem {
+    pub creature: Entity,
+    pub item: Entity,
+}
+
+/// Puts the item into its slot, replacing whatever was there. The replaced item is still carried.
+pub fn equip_item(
+    mut equip_reader: EventReader<EquipItem>,
+    mut creature_query: Query<(Option<&mut EquippedWeapons>, Option<&mut EquippedArmor>)>,
+    weapon_query: Query<(), With<Weapon>>,
+    armor_query: Query<&Armor>,
+) {
+    let debug = false;
+    for equip in equip_reader.iter() {
+        let Ok((equipped_weapons, equipped_armor)) = creature_query.get_mut(equip.creature) else {
+            continue;
+        };
+        if weapon_query.contains(equip.item) {
+            if let Some(mut equipped_weapons) = equipped_weapons {
+                equipped_weapons.main_hand = Some(equip.item);
+            }
+        } else if let Ok(armor) = armor_query.get(equip.item) {
+            if let Some(mut equipped_armor) = equipped_armor {
+                match armor.category {
+                    ArmorCategory::Shield => equipped_armor.shield = Some(equip.item),
+                    _ => equipped_armor.armor = Some(equip.item),
+                }
+            }
+        } else if debug {
+            println!(
+                "debug | equipment::equip_item | {:?} is neither a weapon nor armor",
+                equip.item
+            );
+        }
+    }
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Component, Serialize, Deserialize, PartialEq, Clone)]
 pub struct EquippedWeapons {
-    pub main_hand: Entity,
+    /// The weapon held in the main hand, if any. This is `None` after being disarmed.
+    pub main_hand: Option<Entity>,
     pub off_hand: Vec<Entity>,
 }
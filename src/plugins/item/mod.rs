@@ -2,10 +2,24 @@
 
 use bevy::prelude::*;
 
+use crate::scenes::SceneState;
+
+use self::equipment::EquipItem;
+
 pub mod equipment;
 
 pub struct ItemPlugin;
 
 impl Plugin for ItemPlugin {
-    fn build(&self, app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        app.add_event::<EquipItem>().add_systems(
+            Update,
+            (
+                equipment::equip_item,
+                equipment::armor::update_armor_penalties,
+            )
+                .chain()
+                .run_if(in_state(SceneState::InGameClassicMode)),
+        );
+    }
 }
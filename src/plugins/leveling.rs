@@ -0,0 +1,335 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::{
+        attributes::{
+            ability_bonus, Ability, Attribute, Charisma, Constitution, Dexterity, Intelligence,
+            Strength, Wisdom,
+        },
+        class_features::ClassChoices,
+        conditions::Dead,
+        creature::Faction,
+        experience::{ChallengeRating, Experience, ExperienceAwarded},
+        feats::Feats,
+        hitpoints::HitPoints,
+        level::CharacterLevel,
+        racial_traits::RacialTraits,
+    },
+    plugins::{feats::feat_slots, item::equipment::weapon::EquippedWeapons},
+    resources::{
+        class_progression::ClassProgressionLibrary,
+        dice::Dice,
+        equipment::weapon::{Weapon, WeaponName},
+        feat::{FeatCandidate, FeatLibrary, FeatSelection},
+        profile::Profile,
+    },
+    scenes::SceneState,
+};
+
+pub struct LevelingPlugin;
+
+impl Plugin for LevelingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<LevelUpEvent>().add_systems(
+            Update,
+            (award_experience, check_level_up, apply_level_up)
+                .chain()
+                .run_if(in_state(SceneState::InGameClassicMode)),
+        );
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// How the hit points gained at a new level are found: rolling the class hit die, or taking its
+/// average, rounded up.
+pub enum HitPointChoice {
+    Roll,
+    Average,
+}
+
+impl HitPointChoice {
+    pub fn average(hit_die: Dice) -> usize {
+        hit_die.max() / 2 + 1
+    }
+}
+
+#[derive(Clone, Debug)]
+/// The choices available to a character reaching a new level.
+pub struct LevelUpOptions {
+    pub level: usize,
+    pub hit_die: Dice,
+    /// The number of feats to pick, from general feat slots and class bonus feats.
+    pub feat_picks: usize,
+    /// The feats whose prerequisites the character meets at the new level.
+    pub feats: Vec<FeatSelection>,
+    pub ability_increase: bool,
+}
+
+#[derive(Component, Clone, Debug, Deref)]
+/// A character with enough experience for its next level, which is gained once its choices are
+/// made, see `LevelUpEvent`.
+pub struct PendingLevelUp(pub LevelUpOptions);
+
+#[derive(Event, Clone, Debug)]
+/// Raises the level of a character with a `PendingLevelUp` using the choices made for it. The
+/// new class features, base attack bonus and saving throws then come from the class progression
+/// table, see `classes::apply_class_progression`.
+pub struct LevelUpEvent {
+    pub character: Entity,
+    pub hit_points: HitPointChoice,
+    pub feats: Vec<FeatSelection>,
+    /// The ability score raised by 1 at every 4th level.
+    pub ability: Option<Ability>,
+}
+
+/// Awards the experience of each defeated creature, split evenly between the heroes.
+pub fn award_experience(
+    mut commands: Commands,
+    defeated_query: Query<(Entity, &ChallengeRating), (With<Dead>, Without<ExperienceAwarded>)>,
+    mut hero_query: Query<(&mut Experience, &Faction)>,
+    mut profile: ResMut<Profile>,
+) {
+    let debug = false;
+    for (defeated, challenge_rating) in defeated_query.iter() {
+        commands.entity(defeated).insert(ExperienceAwarded);
+        let mut heroes: Vec<Mut<Experience>> = hero_query
+            .iter_mut()
+            .filter(|(_, faction)| faction.is_ally(Faction::Heroes))
+            .map(|(experience, _)| experience)
+            .collect();
+        if heroes.is_empty() {
+            continue;
+        }
+        let share = challenge_rating.experience() / heroes.len();
+        for experience in heroes.iter_mut() {
+            ***experience += share;
+            profile.experience = ***experience;
+        }
+        if debug {
+            println!(
+                "debug | leveling::award_experience | {:?} defeated, {} experience to each hero",
+                defeated, share
+            );
+        }
+    }
+}
+
+/// Marks characters with enough experience for their next level with a `PendingLevelUp`, along
+/// with the choices they have for it.
+pub fn check_level_up(
+    mut commands: Commands,
+    character_query: Query<
+        (
+            Entity,
+            &Experience,
+            &CharacterLevel,
+            &Feats,
+            (Option<&Strength>, Option<&Dexterity>, Option<&Intelligence>),
+            (Option<&ClassChoices>, Option<&RacialTraits>),
+            Option<&EquippedWeapons>,
+        ),
+        (
+            Without<PendingLevelUp>,
+            Or<(Changed<Experience>, Changed<CharacterLevel>)>,
+        ),
+    >,
+    weapon_query: Query<&Weapon>,
+    progression: Res<ClassProgressionLibrary>,
+    feat_library: Res<FeatLibrary>,
+) {
+    for (
+        entity,
+        experience,
+        character_level,
+        feats,
+        (strength, dexterity, intelligence),
+        (choices, racial_traits),
+        equipped_weapons,
+    ) in character_query.iter()
+    {
+        if experience.level() <= character_level.level {
+            continue;
+        }
+        let class = &character_level.class;
+        let level = character_level.level + 1;
+        let Some(entry) = progression.entry(class, level) else {
+            continue;
+        };
+
+        let feat_picks = (feat_slots(level)
+            + progression.bonus_feats(class, level, choices)
+            + racial_traits.map_or(0, |racial_traits| racial_traits.bonus_feats()))
+        .saturating_sub(feats.len());
+        let new_level = CharacterLevel {
+            class: class.clone(),
+            level,
+        };
+        let taken: Vec<_> = feats.iter().map(|feat| feat.name).collect();
+        let candidate = FeatCandidate {
+            base_attack_bonus: entry.base_attack_bonus,
+            strength: strength.map(|strength| **strength),
+            dexterity: dexterity.map(|dexterity| **dexterity),
+            intelligence: intelligence.map(|intelligence| **intelligence),
+            level: Some(&new_level),
+            feats: &taken,
+        };
+        let weapon = equipped_weapons
+            .and_then(|equipped| equipped.main_hand)
+            .and_then(|main_hand| weapon_query.get(main_hand).ok())
+            .map(|weapon| weapon.weapon_name);
+
+        commands
+            .entity(entity)
+            .insert(PendingLevelUp(LevelUpOptions {
+                level,
+                hit_die: class.hit_die(),
+                feat_picks,
+                feats: eligible_feats(&feat_library, &candidate, feats, weapon),
+                ability_increase: level % 4 == 0,
+            }));
+    }
+}
+
+/// The feats a character could take, in alphabetical order. Feats which apply to a chosen weapon
+/// are offered for the wielded weapon, and can be taken again for a different weapon.
+fn eligible_feats(
+    feat_library: &FeatLibrary,
+    candidate: &FeatCandidate,
+    feats: &Feats,
+    weapon: Option<WeaponName>,
+) -> Vec<FeatSelection> {
+    let mut eligible: Vec<FeatSelection> = feat_library
+        .values()
+        .filter(|feat| {
+            feat_library
+                .unmet_prerequisites(feat.name, candidate)
+                .is_ok_and(|unmet| unmet.is_empty())
+        })
+        .filter_map(|feat| {
+            let selection = FeatSelection {
+                name: feat.name,
+                weapon: if feat.weapon_choice { weapon } else { None },
+            };
+            let available = if feat.weapon_choice {
+                weapon.is_some() && !feats.as_slice().contains(&selection)
+            } else {
+                !feats.contains(feat.name)
+            };
+            available.then_some(selection)
+        })
+        .collect();
+    eligible.sort_by_key(|selection| format!("{:?}", selection.name));
+    eligible
+}
+
+/// The hit points gained at a level from a roll of the hit die and the Constitution bonus. A
+/// level always grants at least 1 hit point.
+pub fn hit_points_gained(roll: usize, constitution_bonus: isize) -> usize {
+    (roll as isize + constitution_bonus).max(1) as usize
+}
+
+/// The hit points of a character at 1st level, which always gets the highest roll of its hit die.
+pub fn first_level_hit_points(hit_die: Dice, constitution_bonus: isize) -> usize {
+    hit_points_gained(hit_die.max(), constitution_bonus)
+}
+
+type AbilityScoresMut<'a> = (
+    Option<Mut<'a, Strength>>,
+    Option<Mut<'a, Dexterity>>,
+    Option<Mut<'a, Constitution>>,
+    Option<Mut<'a, Intelligence>>,
+    Option<Mut<'a, Wisdom>>,
+    Option<Mut<'a, Charisma>>,
+);
+
+/// Raises an ability score by 1, if the creature has it.
+fn increase_ability(ability: Ability, scores: AbilityScoresMut) {
+    let (strength, dexterity, constitution, intelligence, wisdom, charisma) = scores;
+    let score: Option<&mut usize> = match ability {
+        Ability::Strength => strength.map(|score| &mut **score.into_inner()),
+        Ability::Dexterity => dexterity.map(|score| &mut **score.into_inner()),
+        Ability::Constitution => constitution.map(|score| &mut **score.into_inner()),
+        Ability::Intelligence => intelligence.map(|score| &mut **score.into_inner()),
+        Ability::Wisdom => wisdom.map(|score| &mut **score.into_inner()),
+        Ability::Charisma => charisma.map(|score| &mut **score.into_inner()),
+    };
+    if let Some(score) = score {
+        *score += 1;
+    }
+}
+
+/// Raises the level of a character with the choices from its `LevelUpEvent`.
+pub fn apply_level_up(
+    mut commands: Commands,
+    mut level_up_reader: EventReader<LevelUpEvent>,
+    mut character_query: Query<(
+        &PendingLevelUp,
+        &mut CharacterLevel,
+        &mut HitPoints,
+        &mut Feats,
+        (
+            Option<&mut Strength>,
+            Option<&mut Dexterity>,
+            Option<&mut Constitution>,
+            Option<&mut Intelligence>,
+            Option<&mut Wisdom>,
+            Option<&mut Charisma>,
+        ),
+    )>,
+) {
+    let debug = false;
+    let mut rng = rand::thread_rng();
+    for level_up in level_up_reader.iter() {
+        let Ok((pending, mut character_level, mut hit_points, mut feats, scores)) =
+            character_query.get_mut(level_up.character)
+        else {
+            continue;
+        };
+
+        let old_constitution_bonus = scores
+            .2
+            .as_ref()
+            .map_or(0, |constitution| constitution.bonus());
+        let new_constitution_bonus = match level_up.ability {
+            Some(Ability::Constitution) if pending.ability_increase => scores
+                .2
+                .as_ref()
+                .map_or(0, |constitution| ability_bonus(***constitution + 1)),
+            _ => old_constitution_bonus,
+        };
+        if pending.ability_increase {
+            if let Some(ability) = level_up.ability {
+                increase_ability(ability, scores);
+            }
+        }
+
+        let roll = match level_up.hit_points {
+            HitPointChoice::Roll => pending.hit_die.roll_once(&mut rng),
+            HitPointChoice::Average => HitPointChoice::average(pending.hit_die),
+        };
+        let mut gained_hit_points = hit_points_gained(roll, new_constitution_bonus);
+        // A higher Constitution bonus also adds hit points for every earlier level.
+        let earlier_levels = character_level.level as isize;
+        gained_hit_points = (gained_hit_points as isize
+            + (new_constitution_bonus - old_constitution_bonus) * earlier_levels)
+            .max(0) as usize;
+        hit_points.increase_max(gained_hit_points);
+
+        for feat in level_up.feats.iter().take(pending.feat_picks) {
+            feats.add(*feat);
+        }
+
+        character_level.level = pending.level;
+        commands
+            .entity(level_up.character)
+            .remove::<PendingLevelUp>();
+
+        if debug {
+            println!(
+                "debug | leveling::apply_level_up | {:?} reaches level {} with {} hit points, \
+                feats: {:?}",
+                level_up.character, character_level.level, gained_hit_points, level_up.feats
+            );
+        }
+    }
+}
@@ -1,12 +1,17 @@
 pub mod actions;
 pub mod camera;
+pub mod classes;
 pub mod classic_mode;
 pub mod combat;
 pub mod combat_mode;
 pub mod debug;
+pub mod feats;
 pub mod game_ui;
+pub mod health;
 pub mod input;
 pub mod interact;
 pub mod item;
+pub mod leveling;
 pub mod monster;
 pub mod player;
+pub mod spells;
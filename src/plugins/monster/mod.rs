@@ -1,3 +1,4 @@
+use crate::plugins::classic_mode::dungeon::navigation::{room_cleared, EnterRoomEvent};
 use crate::scenes::SceneState;
 use bevy::prelude::*;
 
@@ -13,5 +14,14 @@ impl Plugin for MonsterPlugin {
             OnEnter(SceneState::InGameClassicMode),
             spawn::spawn_training_dummy,
         );
+
+        app.add_systems(
+            Update,
+            spawn::spawn_training_dummy.run_if(
+                in_state(SceneState::InGameClassicMode)
+                    .and_then(on_event::<EnterRoomEvent>())
+                    .and_then(not(room_cleared)),
+            ),
+        );
     }
 }
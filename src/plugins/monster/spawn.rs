@@ -1,3 +1,4 @@
+use crate::components::creature::Faction;
 use crate::config::TILE_SIZE;
 use crate::plugins::combat::attack_damage::damage_reduction::{DRSource, DRTypes, DamageReduction};
 use crate::plugins::combat::attack_damage::damage_reduction_modifier::BarbarianDR;
@@ -6,7 +7,6 @@ use crate::plugins::monster::animation::MonsterAnimationComponent;
 use crate::plugins::monster::collisions::MonsterBox;
 use crate::resources::monster::MonsterLibrary;
 use bevy::prelude::*;
-use bevy::sprite::Anchor;
 
 use crate::materials::ingame::InGameMaterials;
 use crate::resources::animation_state::AnimationState;
@@ -35,17 +35,18 @@ pub fn spawn_training_dummy(
     let x_spawn_pos = TILE_SIZE + TILE_SIZE / 2.0;
     let y_spawn_pos = -TILE_SIZE;
 
-    let interactable_box_lower =
-        Vec2::new(x_spawn_pos - TILE_SIZE / 2.0, y_spawn_pos - TILE_SIZE / 2.0);
-    let interactable_box_upper =
-        Vec2::new(x_spawn_pos + TILE_SIZE / 2.0, y_spawn_pos + TILE_SIZE / 2.0);
+    // Larger monsters can be clicked anywhere in their space, and their sprites cover all of it.
+    let size = training_dummy.size;
+    let (interactable_box_lower, interactable_box_upper) =
+        size.space_bounds(Vec2::new(x_spawn_pos, y_spawn_pos));
+    let sprite_size = Vec2::new(origin_width, origin_height) * 3.5 * size.sprite_scale();
 
-    commands
+    let monster = commands
         .spawn(SpriteSheetBundle {
             texture_atlas: texture_atlas_handle,
             sprite: TextureAtlasSprite {
-                custom_size: Some(Vec2::new(origin_width * 3.5, origin_height * 3.5)),
-                anchor: Anchor::BottomCenter,
+                custom_size: Some(sprite_size),
+                anchor: size.sprite_anchor(sprite_size),
                 ..Default::default()
             },
             transform: Transform {
@@ -56,8 +57,8 @@ pub fn spawn_training_dummy(
         })
         .insert(training_dummy.clone())
         .insert(MonsterBox {
-            width: origin_width * 3.5,
-            height: origin_height * 3.5,
+            width: sprite_size.x,
+            height: sprite_size.y,
         })
         .insert(MonsterAnimationComponent {
             total_tiles: 8,
@@ -69,13 +70,19 @@ pub fn spawn_training_dummy(
             interactable_box_upper,
             InteractingType::Enemy,
         ))
+        .insert(Faction::Monsters)
         .insert(Name::new(component_name))
         .insert(BarbarianDR::new(DamageReduction {
             dr_val: 3,
             reduction_source: DRSource::Barbarian,
             damage_types: DRTypes::all(),
             overcome: None,
-        }));
+        }))
+        .id();
+
+    if let Some(concealment) = training_dummy.concealment {
+        commands.entity(monster).insert(concealment);
+    }
 }
 
 fn get_texture(monster: &Monster, ingame_materials: &InGameMaterials) -> TextureAtlas {
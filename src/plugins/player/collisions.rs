@@ -1,63 +1,43 @@
 use bevy::prelude::*;
-use bevy::sprite::collide_aabb::collide;
-// use rand::Rng;
-// use std::time::Duration;
 
-use crate::components::player::PlayerComponent;
-// use crate::components::player_animation::PlayerAnimation;
 use crate::config::*;
-use crate::plugins::player::{PLAYER_SIZE_HEIGHT, PLAYER_SIZE_WIDTH};
-// use crate::resources::animation_state::AnimationState;
-use crate::resources::dungeon::block_type::BlockType;
-use crate::resources::player::player_available_movement::PlayerAvailableMovement;
+use crate::resources::dungeon::grid_occupancy::GridOccupancy;
+use crate::resources::dungeon::grid_pos::GridPos;
+use crate::resources::player::player_available_movement::{MoveDirection, PlayerAvailableMovement};
 
 /// Checks if there is a collision with a wall.
 /// This function creates a PlayerAvailableMovement struct used in
 /// player_movement_handle_system to restrict player movement if there is a
-/// wall in that direction.
+/// wall in that direction. Walls are looked up in the `GridOccupancy`, and a
+/// direction is blocked once a square centred on the player would touch a wall.
 // TODO: This function currently only checks for walls, but should be altered
 // to account for anything that should stop player movement, both in and out
 // of combat.
 pub fn wall_collision_check(
     player_position: Vec3,
-    block_type_query: &Query<(&BlockType, &Transform), Without<PlayerComponent>>,
+    occupancy: &GridOccupancy,
 ) -> PlayerAvailableMovement {
     let mut player_available_movement = PlayerAvailableMovement::new_all_true();
 
-    let player_size = Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT);
-
-    for (block_type, block_transform) in block_type_query.iter() {
-        // sets the block_position of the wall to the bottom of the wall for
-        // WallTop walls. This lets the player move "behind" the wall,
-        // making it seems as though the player is colliding with the wall
-        // base.
-        let block_position = match *block_type {
-            BlockType::WallTop => block_transform.translation + Vec3::new(0.0, 64.0, 0.0),
-            _ => block_transform.translation,
-        };
-
-        let block_size = match *block_type {
-            BlockType::WallTop => Vec2::new(TILE_SIZE, TILE_SIZE),
-            BlockType::WallRight => Vec2::new(TILE_SIZE, TILE_SIZE),
-            BlockType::WallLeft => Vec2::new(TILE_SIZE, TILE_SIZE),
-            BlockType::WallBottom => Vec2::new(TILE_SIZE, TILE_SIZE),
-            BlockType::None => Vec2::new(0.0, 0.0),
-        };
-
-        if *block_type == BlockType::None {
+    for (move_dir, can_move) in player_available_movement.iter_mut() {
+        if !matches!(
+            move_dir,
+            MoveDirection::Up | MoveDirection::Right | MoveDirection::Down | MoveDirection::Left
+        ) {
             continue;
         }
-
-        if collide(player_position, player_size, block_position, block_size).is_some() {
-            match *block_type {
-                BlockType::WallTop => player_available_movement[0].1 = false,
-                BlockType::WallRight => player_available_movement[2].1 = false,
-                BlockType::WallLeft => player_available_movement[4].1 = false,
-                BlockType::WallBottom => player_available_movement[6].1 = false,
-                BlockType::None => {}
-            }
-            player_available_movement.update_diagonals();
-        }
+        // The point just past the edge of a square centred on the player. Rows of the grid count
+        // down the screen.
+        let offset = move_dir.to_offset();
+        let direction = Vec2::new(offset.x as f32, -offset.y as f32);
+        let ahead = player_position.truncate() + direction * (TILE_SIZE / 2.0 + 1.0);
+        let square = GridPos::from_world(ahead);
+        // Squares outside the room, e.g. doorways, do not stop the player.
+        let blocked = occupancy
+            .terrain(square)
+            .is_some_and(|terrain| !terrain.is_walkable());
+        *can_move = !blocked;
     }
+    player_available_movement.update_diagonals();
     player_available_movement
 }
@@ -1,20 +1,31 @@
 // use std::time::Duration;
 
 use bevy::prelude::*;
-use bevy::sprite::Anchor;
 
-use crate::components::creature::Creature;
-use crate::components::feats::combat_feats::{ImprovedCritical, WeaponFocus};
+use crate::components::attributes::Ability;
+use crate::components::creature::{BaseSpeed, Creature, Faction};
+use crate::components::experience::Experience;
+use crate::components::feats::Feats;
+use crate::components::hitpoints::HitPoints;
+use crate::components::level::CharacterLevel;
 use crate::components::player::PlayerComponent;
 use crate::components::player_animation::PlayerAnimation;
+use crate::components::racial_traits::{Darkvision, RacialTraits, WeaponFamiliarity};
 use crate::materials::ingame::InGameMaterials;
-use crate::plugins::combat::attack_of_opportunity::aoo_round_modifier::CombatReflexes;
+use crate::plugins::combat::stance::ActiveStances;
+use crate::plugins::game_ui::proficiency_warning::EquipRequest;
+use crate::plugins::item::equipment::armor::EquippedArmor;
 use crate::plugins::item::equipment::weapon::EquippedWeapons;
+use crate::plugins::leveling::first_level_hit_points;
 use crate::plugins::player::{PLAYER_SIZE_HEIGHT, PLAYER_SIZE_WIDTH};
+use crate::resources::equipment::armor::{ArmorBundle, ArmorName};
 use crate::resources::equipment::weapon::{WeaponBundle, WeaponName};
-use crate::resources::equipment::Armory;
+use crate::resources::equipment::weapon_property::WeaponProperties;
+use crate::resources::equipment::{ArmorLibrary, Armory};
 use crate::resources::game_data::GameData;
+use crate::resources::hero::hero_class::HeroClass;
 use crate::resources::profile::Profile;
+use crate::resources::race::RaceLibrary;
 
 use super::control::ActionPriority;
 // use crate::resources::upgrade::upgrade_controller::UpgradeController;
@@ -38,21 +49,61 @@ pub fn initiate_player(
     mut commands: Commands,
     profile: Res<Profile>,
     armory: Res<Armory>,
+    armor_library: Res<ArmorLibrary>,
+    race_library: Res<RaceLibrary>,
+    mut equip_writer: EventWriter<EquipRequest>,
 ) {
     let class = profile.hero_class.clone();
     let gender = profile.gender.clone();
     let hero = game_data.get_hero(class.clone());
+    let race = race_library
+        .get(&profile.race)
+        .unwrap_or_else(|| panic!("{:?} is missing from the race catalog", profile.race));
 
-    let longsword = armory.get(&WeaponName::Longsword).unwrap().clone();
-    let weapon_focus = WeaponFocus::new(1, vec![longsword.weapon_name]);
-
-    let improved_critical = ImprovedCritical::new(vec![WeaponName::Longsword]);
+    // TODO: Move starting equipment into the hero data once there is more than one loadout.
+    let (starting_weapon, starting_armor, starting_shield) = match class {
+        HeroClass::Fighter => (
+            WeaponName::Longsword,
+            armor_library.get(&ArmorName::Chainmail).cloned(),
+            armor_library.get(&ArmorName::HeavySteelShield).cloned(),
+        ),
+        HeroClass::Wizard => (WeaponName::Dagger, None, None),
+        HeroClass::Rogue => (
+            WeaponName::ShortSword,
+            armor_library.get(&ArmorName::Leather).cloned(),
+            None,
+        ),
+        HeroClass::Barbarian => (
+            WeaponName::Longsword,
+            armor_library.get(&ArmorName::Hide).cloned(),
+            None,
+        ),
+        HeroClass::Cleric => (
+            WeaponName::HeavyMace,
+            armor_library.get(&ArmorName::ScaleMail).cloned(),
+            None,
+        ),
+    };
+    let starting_weapon = armory
+        .get(&starting_weapon)
+        .unwrap_or_else(|| panic!("{:?} is missing from the armory", starting_weapon))
+        .clone();
 
     // let skill = game_data.get_skill(class.clone());
 
     let player = PlayerComponent::new(class.clone(), game_data.clone());
-    let player_attributes = hero.attributes;
-    let player_bab = hero.base_attack_bonus;
+    // The scores from character creation, or the hero's defaults if it was skipped.
+    let player_attributes = profile.attributes.unwrap_or(hero.attributes);
+    let player_hit_points = HitPoints::new(first_level_hit_points(
+        class.hit_die(),
+        player_attributes.bonus(Ability::Constitution),
+    ) as isize);
+    let player_feats = Feats::new(hero.feats);
+    let class_choices = hero.class_choices;
+    let proficiencies = hero
+        .proficiencies
+        .clone()
+        .with_weapons(&race.weapon_proficiencies);
 
     let hero_tileset = ingame_materials
         .heroes_materials
@@ -68,14 +119,17 @@ pub fn initiate_player(
     );
 
     let texture_atlas_handle = texture_atlases.add(texture_atlas);
+    let sprite_size = Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT) * race.size.sprite_scale();
 
-    let mut weapon_entity: Option<Entity> = None;
+    // The starting gear is carried by the player, and equipped through `EquipRequest` like any
+    // other gear.
+    let mut starting_gear: Vec<Entity> = Vec::new();
     let player_entity = commands
         .spawn(SpriteSheetBundle {
             texture_atlas: texture_atlas_handle,
             sprite: TextureAtlasSprite {
-                custom_size: Some(Vec2::new(PLAYER_SIZE_WIDTH, PLAYER_SIZE_HEIGHT)),
-                anchor: Anchor::BottomCenter,
+                custom_size: Some(sprite_size),
+                anchor: race.size.sprite_anchor(sprite_size),
                 ..Default::default()
             },
             transform: Transform {
@@ -96,24 +150,59 @@ pub fn initiate_player(
             });
         })
         .with_children(|builder| {
-            weapon_entity = Some(builder.spawn(WeaponBundle { weapon: longsword }).id());
+            starting_gear.push(
+                builder
+                    .spawn(WeaponBundle {
+                        weapon: starting_weapon,
+                        properties: WeaponProperties::default(),
+                    })
+                    .id(),
+            );
+            for armor in [starting_armor, starting_shield].into_iter().flatten() {
+                starting_gear.push(builder.spawn(ArmorBundle { armor }).id());
+            }
         })
         .insert(player)
-        .insert(CombatReflexes)
         .insert(player_attributes)
-        .insert(player_bab)
-        .insert(weapon_focus)
-        .insert(improved_critical)
+        .insert(player_hit_points)
+        .insert(player_feats)
+        .insert(CharacterLevel::new(class.clone()))
+        .insert(Experience::default())
+        .insert(ActiveStances::default())
+        .insert(class_choices)
         .insert(EquippedWeapons {
-            main_hand: weapon_entity.unwrap(),
+            main_hand: None,
             off_hand: vec![],
         })
+        .insert(EquippedArmor::default())
+        .insert(profile.race)
+        .insert(race.size)
+        .insert(BaseSpeed(race.speed))
+        .insert(RacialTraits(race.traits.clone()))
+        .insert(proficiencies)
         .insert(Creature)
+        .insert(Faction::Heroes)
         .insert(ActionPriority)
         .insert(PlayerAnimation::new())
         .insert(Name::new("Player"))
         .id();
 
+    for item in starting_gear {
+        equip_writer.send(EquipRequest {
+            creature: player_entity,
+            item,
+        });
+    }
+
+    if let Some(range) = race.darkvision {
+        commands.entity(player_entity).insert(Darkvision(range));
+    }
+    if let Some(group) = race.weapon_familiarity {
+        commands
+            .entity(player_entity)
+            .insert(WeaponFamiliarity(group));
+    }
+
     // commands.insert_resource(UpgradeController::new());
     // TODO: Decide whether there is sufficient justification for a PlayerEntity
     // resource to exist. If there is only ever one Player, then it could just
@@ -0,0 +1,386 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::{
+    components::{
+        attributes::{AbilityAdjustments, Attribute, Charisma, Constitution, Dexterity, Wisdom},
+        class_features::{Bravery, Domains, Evasion, SpontaneousCasting, Superstition},
+        creature::{Creature, Faction, Undead},
+        level::CharacterLevel,
+        racial_traits::RacialTraits,
+        saving_throw::{Save, SaveBonuses, SaveDescriptor, SavingThrows},
+        spell_slots::SpellSlots,
+    },
+    plugins::{
+        combat::{
+            area::{self, Area, FEET_PER_SQUARE},
+            rage::Raging,
+        },
+        health::{ApplyHealth, DamageEvent, DamageSource, HealEvent, HealSource, HealType},
+    },
+    resources::{
+        dice::Dice,
+        spell::{SpellEffect, SpellLibrary, SpellName, SpellRange},
+    },
+    scenes::SceneState,
+};
+
+pub struct SpellPlugin;
+
+impl Plugin for SpellPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CastSpell>()
+            .add_event::<ChannelEnergyEvent>()
+            .add_systems(
+                Update,
+                (cast_spell, channel_energy)
+                    .before(ApplyHealth)
+                    .run_if(in_state(SceneState::InGameClassicMode)),
+            );
+    }
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+/// Casts a spell from one of the caster's spell slots at the target.
+pub struct CastSpell {
+    pub caster: Entity,
+    pub spell: SpellName,
+    pub target: Entity,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// A Cleric channels positive energy either to heal the living or to harm the undead.
+pub enum ChannelMode {
+    HealLiving,
+    HarmUndead,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ChannelEnergyEvent {
+    pub caster: Entity,
+    pub mode: ChannelMode,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+/// The Cleric's channel energy, a burst of positive energy centered on the cleric, which can be
+/// used a limited number of times per day.
+pub struct ChannelEnergy {
+    /// The number of d6 of healing or damage.
+    dice: usize,
+    uses_per_day: usize,
+    uses_left: usize,
+}
+
+impl ChannelEnergy {
+    pub const AREA: Area = Area::Burst { radius: 30 };
+
+    pub fn new(dice: usize, charisma_bonus: isize) -> Self {
+        let uses_per_day = Self::uses_per_day(charisma_bonus);
+        Self {
+            dice,
+            uses_per_day,
+            uses_left: uses_per_day,
+        }
+    }
+
+    /// 3 uses, plus the Charisma bonus.
+    pub fn uses_per_day(charisma_bonus: isize) -> usize {
+        (3 + charisma_bonus).max(0) as usize
+    }
+
+    /// Gaining a level may add dice, and any uses gained from a higher Charisma are added to those
+    /// left today.
+    pub fn set_level(&mut self, dice: usize, charisma_bonus: isize) {
+        let uses_per_day = Self::uses_per_day(charisma_bonus);
+        self.uses_left += uses_per_day.saturating_sub(self.uses_per_day);
+        self.uses_left = self.uses_left.min(uses_per_day);
+        self.uses_per_day = uses_per_day;
+        self.dice = dice;
+    }
+
+    pub fn uses_left(&self) -> usize {
+        self.uses_left
+    }
+
+    pub fn save_dc(level: usize, charisma_bonus: isize) -> usize {
+        (10 + level as isize / 2 + charisma_bonus).max(0) as usize
+    }
+}
+
+type SaverQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Option<&'static SavingThrows>,
+        Option<&'static Dexterity>,
+        Option<&'static AbilityAdjustments>,
+        Option<&'static Constitution>,
+        Option<&'static Wisdom>,
+        Option<&'static Bravery>,
+        Option<&'static Raging>,
+        Option<&'static Superstition>,
+        Option<&'static RacialTraits>,
+    ),
+>;
+
+/// Rolls a saving throw for the target against the DC. A natural 20 always succeeds and a natural
+/// 1 always fails.
+fn roll_save<R: Rng + ?Sized>(
+    rng: &mut R,
+    saver_query: &SaverQuery,
+    target: Entity,
+    save: Save,
+    descriptors: &[SaveDescriptor],
+    dc: usize,
+) -> bool {
+    let Ok((
+        saving_throws,
+        dexterity,
+        adjustments,
+        constitution,
+        wisdom,
+        bravery,
+        raging,
+        superstition,
+        racial_traits,
+    )) = saver_query.get(target)
+    else {
+        return false;
+    };
+    let bonuses = SaveBonuses {
+        dexterity,
+        adjustments,
+        constitution,
+        wisdom,
+        bravery,
+        raging,
+        superstition,
+        racial_traits,
+    };
+    let total = saving_throws
+        .copied()
+        .unwrap_or_default()
+        .total(save, descriptors, bonuses);
+    match Dice::D20.roll_once(rng) {
+        20 => true,
+        1 => false,
+        roll => roll as isize + total >= dc as isize,
+    }
+}
+
+/// The damage taken from an effect which deals half damage on a successful saving throw. A
+/// creature with `Evasion` takes no damage on a successful Reflex save.
+fn damage_after_save(damage: usize, save: Save, saved: bool, evasion: Option<&Evasion>) -> usize {
+    match (save, saved) {
+        (Save::Reflex, _) => Evasion::reflex_damage(evasion, damage, saved),
+        (_, true) => damage / 2,
+        (_, false) => damage,
+    }
+}
+
+/// Whether the caster can reach the target with a spell of the given range. A personal spell only
+/// affects the caster, while a touch spell reaches the caster and the creatures adjacent to it.
+fn in_range(
+    range: SpellRange,
+    caster: Entity,
+    target: Entity,
+    position_query: &Query<&Transform, With<Creature>>,
+) -> bool {
+    match range {
+        SpellRange::Personal => caster == target,
+        SpellRange::Touch => {
+            caster == target
+                || position_query
+                    .get_many([caster, target])
+                    .is_ok_and(|[caster, target]| {
+                        area::distance(caster.translation.truncate(), target.translation.truncate())
+                            <= FEET_PER_SQUARE
+                    })
+        }
+    }
+}
+
+/// Casts a spell if the target is in range and the caster has a slot of its level left. Cure
+/// spells are resolved as positive energy, which heals the living and damages the undead, who may
+/// save for half.
+// TODO: Check the caster has prepared the spell once spells can be prepared. For now only cure
+// spells are in the catalog, which a Cleric casts spontaneously.
+pub fn cast_spell(
+    mut cast_reader: EventReader<CastSpell>,
+    mut caster_query: Query<(
+        &mut SpellSlots,
+        Option<&SpontaneousCasting>,
+        Option<&CharacterLevel>,
+        Option<&Domains>,
+        Option<&Wisdom>,
+    )>,
+    undead_query: Query<(), With<Undead>>,
+    saver_query: SaverQuery,
+    evasion_query: Query<&Evasion>,
+    position_query: Query<&Transform, With<Creature>>,
+    spell_library: Res<SpellLibrary>,
+    (mut damage_writer, mut heal_writer): (EventWriter<DamageEvent>, EventWriter<HealEvent>),
+) {
+    let debug = false;
+    let mut rng = rand::thread_rng();
+    for cast in cast_reader.iter() {
+        let Some(spell) = spell_library.get(&cast.spell) else {
+            if debug {
+                println!(
+                    "debug | spells::cast_spell | {:?} is missing from the spell catalog",
+                    cast.spell
+                );
+            }
+            continue;
+        };
+        let Ok((mut slots, spontaneous, level, domains, wisdom)) =
+            caster_query.get_mut(cast.caster)
+        else {
+            continue;
+        };
+        if spell.name.is_cure() && spontaneous.is_none() {
+            continue;
+        }
+        if !in_range(spell.range, cast.caster, cast.target, &position_query) {
+            if debug {
+                println!(
+                    "debug | spells::cast_spell | {:?} is out of {:?} range of {:?}",
+                    cast.target, spell.range, cast.caster
+                );
+            }
+            continue;
+        }
+        if !slots.expend(spell.level) {
+            if debug {
+                println!(
+                    "debug | spells::cast_spell | {:?} has no level {} slots left",
+                    cast.caster, spell.level
+                );
+            }
+            continue;
+        }
+
+        let caster_level = level.map_or(1, |level| level.level);
+        match spell.effect {
+            SpellEffect::PositiveEnergy { .. } => {
+                let amount = spell.effect.roll(&mut rng, caster_level);
+                if undead_query.contains(cast.target) {
+                    let damage = spell.save.map_or(amount, |save| {
+                        let saved = roll_save(
+                            &mut rng,
+                            &saver_query,
+                            cast.target,
+                            save,
+                            &[SaveDescriptor::Spell],
+                            spell.save_dc(wisdom.map_or(0, |wisdom| wisdom.bonus())),
+                        );
+                        damage_after_save(amount, save, saved, evasion_query.get(cast.target).ok())
+                    });
+                    damage_writer.send(DamageEvent {
+                        target: cast.target,
+                        amount: damage,
+                        nonlethal: false,
+                        source: DamageSource::Spell,
+                    });
+                } else {
+                    heal_writer.send(HealEvent {
+                        target: cast.target,
+                        amount: domains
+                            .map_or(amount, |domains| domains.cure_amount(amount, caster_level)),
+                        heal_type: HealType::Normal,
+                        source: HealSource::Spell,
+                    });
+                }
+                if debug {
+                    println!(
+                        "debug | spells::cast_spell | {:?} casts {:?} on {:?} for {}",
+                        cast.caster, spell.name, cast.target, amount
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Channels positive energy in a 30ft burst around the caster. Healing affects the caster's
+/// living allies, while harming affects every undead creature in the burst, which may make a Will
+/// save for half damage.
+pub fn channel_energy(
+    mut channel_reader: EventReader<ChannelEnergyEvent>,
+    mut caster_query: Query<(
+        &mut ChannelEnergy,
+        &Transform,
+        &Faction,
+        Option<&CharacterLevel>,
+        Option<&Domains>,
+        Option<&Charisma>,
+    )>,
+    creature_query: Query<(Entity, &Transform), With<Creature>>,
+    target_query: Query<(Option<&Faction>, Option<&Undead>)>,
+    saver_query: SaverQuery,
+    (mut damage_writer, mut heal_writer): (EventWriter<DamageEvent>, EventWriter<HealEvent>),
+) {
+    let debug = false;
+    let mut rng = rand::thread_rng();
+    for channel in channel_reader.iter() {
+        let Ok((mut channel_energy, transform, faction, level, domains, charisma)) =
+            caster_query.get_mut(channel.caster)
+        else {
+            continue;
+        };
+        if channel_energy.uses_left == 0 {
+            continue;
+        }
+        channel_energy.uses_left -= 1;
+
+        let level = level.map_or(1, |level| level.level);
+        let amount = Dice::D6.roll_n(&mut rng, channel_energy.dice);
+        let center = transform.translation.truncate();
+        for target in ChannelEnergy::AREA.creatures(center, &creature_query) {
+            let Ok((target_faction, undead)) = target_query.get(target) else {
+                continue;
+            };
+            match (channel.mode, undead.is_some()) {
+                (ChannelMode::HealLiving, false)
+                    if target_faction.is_some_and(|other| faction.is_ally(*other)) =>
+                {
+                    heal_writer.send(HealEvent {
+                        target,
+                        amount,
+                        heal_type: HealType::Normal,
+                        source: HealSource::ChannelEnergy,
+                    });
+                }
+                (ChannelMode::HarmUndead, true) => {
+                    let damage = amount
+                        + domains.map_or(0, |domains| domains.channel_bonus_vs_undead(level));
+                    let saved = roll_save(
+                        &mut rng,
+                        &saver_query,
+                        target,
+                        Save::Will,
+                        &[],
+                        ChannelEnergy::save_dc(
+                            level,
+                            charisma.map_or(0, |charisma| charisma.bonus()),
+                        ),
+                    );
+                    damage_writer.send(DamageEvent {
+                        target,
+                        amount: damage_after_save(damage, Save::Will, saved, None),
+                        nonlethal: false,
+                        source: DamageSource::ChannelEnergy,
+                    });
+                }
+                _ => (),
+            }
+        }
+
+        if debug {
+            println!(
+                "debug | spells::channel_energy | {:?} channels {:?} for {}d6 ({}), {} uses left",
+                channel.caster, channel.mode, channel_energy.dice, amount, channel_energy.uses_left
+            );
+        }
+    }
+}
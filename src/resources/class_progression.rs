@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+use bevy::utils::hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::BufReader;
+
+use crate::{
+    components::{
+        class_features::{ClassChoices, RogueTalents},
+        saving_throw::SavingThrows,
+    },
+    resources::hero::hero_class::HeroClass,
+};
+
+pub const CLASS_PROGRESSION_DATA: &str = "assets/classes/class_progression.ron";
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+/// A class feature gained at a level. Features which appear at several levels gain a rank each
+/// time, e.g. Bravery is +1 at level 2 and +2 at level 6.
+pub enum ClassFeature {
+    BonusFeat,
+    Bravery,
+    ArmorTraining,
+    WeaponTraining,
+    SneakAttack,
+    Trapfinding,
+    Evasion,
+    RogueTalent,
+    FastMovement,
+    Rage,
+    RagePower,
+    UncannyDodge,
+    DamageReduction,
+    ChannelEnergy,
+    Domains,
+    SpontaneousCasting,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+/// One row of a class progression table.
+pub struct ClassLevelEntry {
+    pub level: usize,
+    pub base_attack_bonus: isize,
+    pub fortitude: isize,
+    pub reflex: isize,
+    pub will: isize,
+    pub features: Vec<ClassFeature>,
+    /// The spells per day for each spell level, starting at level 0, for spellcasting classes.
+    #[serde(default)]
+    pub spells_per_day: Vec<usize>,
+}
+
+impl ClassLevelEntry {
+    pub fn saving_throws(&self) -> SavingThrows {
+        SavingThrows {
+            fortitude: self.fortitude,
+            reflex: self.reflex,
+            will: self.will,
+        }
+    }
+}
+
+#[derive(Deref, DerefMut, Resource, Serialize, Deserialize)]
+/// The progression table of each class, loaded from `CLASS_PROGRESSION_DATA`.
+pub struct ClassProgressionLibrary(HashMap<HeroClass, Vec<ClassLevelEntry>>);
+
+impl ClassProgressionLibrary {
+    pub fn new() -> Self {
+        match File::open(CLASS_PROGRESSION_DATA) {
+            Ok(file) => {
+                let reader = BufReader::new(file);
+                ron::de::from_reader(reader).unwrap()
+            }
+            Err(err) => panic!("Can't find class progression file: {}", err),
+        }
+    }
+
+    pub fn entry(&self, class: &HeroClass, level: usize) -> Option<&ClassLevelEntry> {
+        self.get(class)?.iter().find(|entry| entry.level == level)
+    }
+
+    /// The rank of a class feature at the given level, which is the number of times it has been
+    /// gained at or below that level.
+    pub fn rank(&self, class: &HeroClass, level: usize, feature: ClassFeature) -> usize {
+        self.get(class).map_or(0, |table| {
+            table
+                .iter()
+                .filter(|entry| entry.level <= level)
+                .flat_map(|entry| entry.features.iter())
+                .filter(|gained| **gained == feature)
+                .count()
+        })
+    }
+
+    /// The rogue talents gained by the given level, taken in order from the character's choices.
+    pub fn rogue_talents(
+        &self,
+        class: &HeroClass,
+        level: usize,
+        choices: Option<&ClassChoices>,
+    ) -> RogueTalents {
+        let rank = self.rank(class, level, ClassFeature::RogueTalent);
+        RogueTalents(choices.map_or(Vec::new(), |choices| {
+            let talents = &choices.rogue_talents;
+            talents[..rank.min(talents.len())].to_vec()
+        }))
+    }
+
+    /// The bonus feats gained from class features by the given level, e.g. the Fighter's bonus
+    /// feats and the Rogue's Combat Trick talent.
+    pub fn bonus_feats(
+        &self,
+        class: &HeroClass,
+        level: usize,
+        choices: Option<&ClassChoices>,
+    ) -> usize {
+        self.rank(class, level, ClassFeature::BonusFeat)
+            + self.rogue_talents(class, level, choices).bonus_feats()
+    }
+}
@@ -12,6 +12,7 @@ pub enum Dice {
     D10,
     D12,
     D20,
+    D100,
 }
 
 impl From<Dice> for Uniform<usize> {
@@ -26,11 +27,28 @@ impl From<Dice> for Uniform<usize> {
             D10 => Uniform::new_inclusive(1, 10),
             D12 => Uniform::new_inclusive(1, 12),
             D20 => Uniform::new_inclusive(1, 20),
+            D100 => Uniform::new_inclusive(1, 100),
         }
     }
 }
 
 impl Dice {
+    /// The highest number the die can roll.
+    pub fn max(self) -> usize {
+        use Dice::*;
+        match self {
+            D2 => 2,
+            D3 => 3,
+            D4 => 4,
+            D6 => 6,
+            D8 => 8,
+            D10 => 10,
+            D12 => 12,
+            D20 => 20,
+            D100 => 100,
+        }
+    }
+
     pub fn roll_once<R: Rng + ?Sized>(self, rng: &mut R) -> usize {
         let die_range: Uniform<usize> = self.into();
         let mut roll_die = rng.sample_iter(die_range);
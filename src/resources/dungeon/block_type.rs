@@ -9,3 +9,16 @@ pub enum BlockType {
     WallLeft,
     WallRight,
 }
+
+impl BlockType {
+    /// The centre of the square this block occupies on the grid, or `None` if the block does not
+    /// block anything. `WallTop` walls are drawn one tile below their base, so their square is
+    /// offset upwards.
+    pub fn blocked_square(&self, translation: Vec3) -> Option<Vec2> {
+        match *self {
+            BlockType::None => None,
+            BlockType::WallTop => Some(translation.truncate() + Vec2::new(0.0, 64.0)),
+            _ => Some(translation.truncate()),
+        }
+    }
+}
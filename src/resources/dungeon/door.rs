@@ -2,6 +2,13 @@ use bevy::prelude::*;
 use bevy_inspector_egui::InspectorOptions;
 use std::slice::Iter;
 
+use crate::resources::dungeon::grid_pos::GridPos;
+
+/// The row of the left and right doors.
+const SIDE_DOOR_ROW: i32 = 4;
+/// The columns of the opening in the top and bottom doors.
+const MIDDLE_DOOR_COLUMNS: [i32; 2] = [7, 8];
+
 #[derive(Component, PartialEq, Eq, Clone, InspectorOptions, Debug)]
 pub enum Door {
     Left,
@@ -14,6 +21,63 @@ impl Door {
     pub fn iterator() -> Iter<'static, Door> {
         [Door::Left, Door::Right, Door::Top, Door::Bottom].iter()
     }
+
+    /// The door on the other side of the room, which the player comes in through after leaving
+    /// by this one.
+    pub fn opposite(&self) -> Door {
+        match self {
+            Door::Left => Door::Right,
+            Door::Right => Door::Left,
+            Door::Top => Door::Bottom,
+            Door::Bottom => Door::Top,
+        }
+    }
+
+    /// The change in row and column of the floor map when leaving by this door.
+    pub fn room_offset(&self) -> (isize, isize) {
+        match self {
+            Door::Left => (0, -1),
+            Door::Right => (0, 1),
+            Door::Top => (-1, 0),
+            Door::Bottom => (1, 0),
+        }
+    }
+
+    /// The squares of the room in the doorway. The player leaves the room by walking into one of
+    /// them while the door is open.
+    pub fn threshold(&self) -> Vec<GridPos> {
+        match self {
+            Door::Left => vec![GridPos::new(1, SIDE_DOOR_ROW)],
+            Door::Right => vec![GridPos::new(14, SIDE_DOOR_ROW)],
+            Door::Top => MIDDLE_DOOR_COLUMNS
+                .iter()
+                .map(|column| GridPos::new(*column, 1))
+                .collect(),
+            Door::Bottom => MIDDLE_DOOR_COLUMNS
+                .iter()
+                .map(|column| GridPos::new(*column, 7))
+                .collect(),
+        }
+    }
+
+    /// The square the player arrives on in the next room after leaving through `square` of this
+    /// door's threshold: one step into the room from the matching square of the opposite door, so
+    /// they do not walk straight back out.
+    pub fn arrival(&self, square: GridPos) -> GridPos {
+        let entrance = self.opposite();
+        let index = self
+            .threshold()
+            .iter()
+            .position(|threshold| *threshold == square)
+            .unwrap_or_default();
+        let inward = match entrance {
+            Door::Left => GridPos::new(1, 0),
+            Door::Right => GridPos::new(-1, 0),
+            Door::Top => GridPos::new(0, 1),
+            Door::Bottom => GridPos::new(0, -1),
+        };
+        entrance.threshold()[index] + inward
+    }
 }
 
 #[derive(PartialEq, Eq, Component, Clone, Debug)]
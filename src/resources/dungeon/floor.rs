@@ -3,6 +3,7 @@ use std::fs::File;
 use std::io::BufRead;
 use std::io::BufReader;
 
+use crate::resources::dungeon::door::Door;
 use crate::resources::dungeon::position::Position;
 
 #[derive(Clone)]
@@ -86,4 +87,25 @@ impl Floor {
             is_last_floor,
         }
     }
+
+    /// The room behind a door of the current room, or `None` if there is no room there.
+    pub fn room_through(&self, door: &Door) -> Option<Position> {
+        let (row_offset, column_offset) = door.room_offset();
+        let row_index = self
+            .current_position
+            .row_index
+            .checked_add_signed(row_offset)?;
+        let column_index = self
+            .current_position
+            .column_index
+            .checked_add_signed(column_offset)?;
+        let room_id = self.map.get(row_index)?.get(column_index)?;
+        if *room_id == 0.0 {
+            return None;
+        }
+        Some(Position {
+            row_index,
+            column_index,
+        })
+    }
 }
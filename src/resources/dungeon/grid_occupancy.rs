@@ -0,0 +1,202 @@
+use bevy::prelude::*;
+use bevy::utils::hashbrown::HashMap;
+
+use crate::{plugins::combat::geometry, resources::dungeon::grid_pos::GridPos};
+
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq)]
+/// What a square of the room is made of. Terrain features other than walls are spawned from their
+/// own tile codes in the room's tilemap, see `Terrain::from_tile`.
+pub enum Terrain {
+    #[default]
+    Floor,
+    /// Blocks movement and lines of sight.
+    Wall,
+    /// Difficult terrain.
+    Rubble,
+    /// Difficult terrain.
+    ShallowWater,
+    /// Cannot be moved into, but does not block lines of sight or the corners of diagonal moves.
+    Pit,
+    /// Blocks movement, lines of sight and the corners of diagonal moves, like a wall.
+    Pillar,
+    /// Costs as much to cross as difficult terrain, and gives cover to creatures behind it.
+    LowWall,
+}
+
+impl Terrain {
+    /// The terrain for a tile code of a room's tilemap, or `None` if the tile is the floor or a
+    /// wall.
+    pub fn from_tile(value: i32) -> Option<Self> {
+        match value {
+            9 => Some(Terrain::Rubble),
+            10 => Some(Terrain::ShallowWater),
+            11 => Some(Terrain::Pit),
+            12 => Some(Terrain::Pillar),
+            13 => Some(Terrain::LowWall),
+            _ => None,
+        }
+    }
+
+    pub fn is_walkable(self) -> bool {
+        matches!(
+            self,
+            Terrain::Floor | Terrain::Rubble | Terrain::ShallowWater | Terrain::LowWall
+        )
+    }
+
+    /// Whether moving into the square costs twice as much, which also rules out five-foot steps,
+    /// runs and charges into it.
+    pub fn is_difficult(self) -> bool {
+        matches!(
+            self,
+            Terrain::Rubble | Terrain::ShallowWater | Terrain::LowWall
+        )
+    }
+
+    /// Whether the terrain is solid, blocking lines of sight and diagonal moves past its corners.
+    pub fn blocks_line(self) -> bool {
+        matches!(self, Terrain::Wall | Terrain::Pillar)
+    }
+
+    /// Whether the terrain gives cover without blocking lines of sight.
+    pub fn gives_cover(self) -> bool {
+        self == Terrain::LowWall
+    }
+}
+
+#[derive(Resource, Clone, Debug, Default)]
+/// Every square of the room, indexed by `GridPos`, along with the creatures standing in it.
+/// Squares missing from `terrain` are outside the room. Kept up to date by the systems in
+/// `classic_mode::dungeon::occupancy`.
+pub struct GridOccupancy {
+    terrain: HashMap<GridPos, Terrain>,
+    occupants: HashMap<GridPos, Vec<Entity>>,
+    /// The squares each creature takes up, so a creature can be moved without searching the
+    /// whole grid.
+    spaces: HashMap<Entity, Vec<GridPos>>,
+}
+
+impl GridOccupancy {
+    /// Adds a floor square, unless something else was already placed there.
+    pub fn add_floor(&mut self, square: GridPos) {
+        self.terrain.entry(square).or_insert(Terrain::Floor);
+    }
+
+    pub fn set_terrain(&mut self, square: GridPos, terrain: Terrain) {
+        self.terrain.insert(square, terrain);
+    }
+
+    /// Forgets the squares of the room, e.g. before the next room is spawned. The creatures are
+    /// kept.
+    pub fn clear_terrain(&mut self) {
+        self.terrain.clear();
+    }
+
+    /// The terrain of the square, or `None` if the square is outside the room.
+    pub fn terrain(&self, square: GridPos) -> Option<Terrain> {
+        self.terrain.get(&square).copied()
+    }
+
+    /// Moves a creature to a new set of squares. Only the squares which changed are updated.
+    pub fn place(&mut self, entity: Entity, squares: Vec<GridPos>) {
+        let previous = self.spaces.get(&entity).cloned().unwrap_or_default();
+        if previous == squares {
+            return;
+        }
+        for square in previous.iter().filter(|square| !squares.contains(square)) {
+            self.remove_occupant(*square, entity);
+        }
+        for square in squares.iter().filter(|square| !previous.contains(square)) {
+            self.occupants.entry(*square).or_default().push(entity);
+        }
+        self.spaces.insert(entity, squares);
+    }
+
+    /// Removes a creature from the grid, e.g. when it is despawned.
+    pub fn remove(&mut self, entity: Entity) {
+        if let Some(squares) = self.spaces.remove(&entity) {
+            for square in squares {
+                self.remove_occupant(square, entity);
+            }
+        }
+    }
+
+    fn remove_occupant(&mut self, square: GridPos, entity: Entity) {
+        if let Some(occupants) = self.occupants.get_mut(&square) {
+            occupants.retain(|occupant| *occupant != entity);
+            if occupants.is_empty() {
+                self.occupants.remove(&square);
+            }
+        }
+    }
+
+    /// The squares taken up by a creature.
+    pub fn space(&self, entity: Entity) -> &[GridPos] {
+        self.spaces.get(&entity).map_or(&[], |squares| squares)
+    }
+
+    /// The squares whose terrain matches `filter`.
+    pub fn squares_where(&self, filter: impl Fn(Terrain) -> bool) -> Vec<GridPos> {
+        self.terrain
+            .iter()
+            .filter(|(_, terrain)| filter(**terrain))
+            .map(|(square, _)| *square)
+            .collect()
+    }
+
+    /// The squares with at least one creature standing in them, other than the creatures in
+    /// `ignored`.
+    pub fn occupied_squares(&self, ignored: &[Entity]) -> Vec<GridPos> {
+        self.occupants
+            .iter()
+            .filter(|(_, occupants)| occupants.iter().any(|occupant| !ignored.contains(occupant)))
+            .map(|(square, _)| *square)
+            .collect()
+    }
+
+    /// The creatures standing in the square.
+    pub fn occupants(&self, square: GridPos) -> &[Entity] {
+        self.occupants
+            .get(&square)
+            .map_or(&[], |occupants| occupants)
+    }
+
+    /// Whether a creature can enter the square: it must be inside the room, the terrain must not
+    /// block movement, and no creature other than `mover` may be standing in it.
+    pub fn is_passable(&self, square: GridPos, mover: Option<Entity>) -> bool {
+        self.terrain(square).is_some_and(Terrain::is_walkable)
+            && self
+                .occupants(square)
+                .iter()
+                .all(|occupant| Some(*occupant) == mover)
+    }
+
+    /// The creatures standing in the eight squares around the square, not counting the creatures
+    /// in the square itself.
+    pub fn creatures_adjacent_to(&self, square: GridPos) -> Vec<Entity> {
+        let here = self.occupants(square);
+        let mut creatures: Vec<Entity> = square
+            .neighbours()
+            .into_iter()
+            .flat_map(|neighbour| self.occupants(neighbour))
+            .copied()
+            .filter(|creature| !here.contains(creature))
+            .collect();
+        creatures.sort();
+        creatures.dedup();
+        creatures
+    }
+
+    /// Whether the line between the centres of two squares is free of terrain which blocks it.
+    /// Lines which only touch a border or a corner of a wall are not blocked.
+    pub fn line_clear(&self, from: GridPos, to: GridPos) -> bool {
+        let start = from.to_world();
+        let end = to.to_world();
+        let (lower, upper) = (from.min(to), from.max(to));
+        !(lower.x..=upper.x)
+            .flat_map(|x| (lower.y..=upper.y).map(move |y| GridPos::new(x, y)))
+            .filter(|square| *square != from && *square != to)
+            .filter(|square| self.terrain(*square).is_some_and(Terrain::blocks_line))
+            .any(|square| geometry::clip_to_square(start, end, square.to_world()).is_some())
+    }
+}
@@ -0,0 +1,83 @@
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use std::ops::{Add, Sub};
+
+use crate::plugins::game_ui::translate::{
+    grid_to_trans, trans_to_grid, trans_to_window, window_to_trans,
+};
+
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// A square of the room, as `x` columns to the right of and `y` rows down from the top left
+/// square. Tile logic should compare `GridPos`s rather than translations, which are only exact
+/// while nothing is moving.
+pub struct GridPos {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl GridPos {
+    pub const fn new(x: i32, y: i32) -> Self {
+        GridPos { x, y }
+    }
+
+    /// The square containing the translation.
+    pub fn from_world(position: Vec2) -> Self {
+        let (x, y) = trans_to_grid(position.x, position.y);
+        GridPos { x, y }
+    }
+
+    /// The translation of the centre of the square.
+    pub fn to_world(self) -> Vec2 {
+        grid_to_trans(self.x, self.y).into()
+    }
+
+    /// The square containing the window position, e.g. the cursor.
+    pub fn from_window(position: Vec2) -> Self {
+        let (x_tr, y_tr) = window_to_trans(position.x, position.y);
+        Self::from_world(Vec2::new(x_tr, y_tr))
+    }
+
+    /// The window position of the centre of the square.
+    pub fn to_window(self) -> Vec2 {
+        let position = self.to_world();
+        trans_to_window(position.x, position.y).into()
+    }
+
+    /// The eight squares around this one, clockwise from the square above.
+    pub fn neighbours(self) -> [GridPos; 8] {
+        [
+            GridPos::new(0, -1),
+            GridPos::new(1, -1),
+            GridPos::new(1, 0),
+            GridPos::new(1, 1),
+            GridPos::new(0, 1),
+            GridPos::new(-1, 1),
+            GridPos::new(-1, 0),
+            GridPos::new(-1, -1),
+        ]
+        .map(|offset| self + offset)
+    }
+
+    pub fn min(self, other: Self) -> Self {
+        GridPos::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        GridPos::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+
+impl Add for GridPos {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        GridPos::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for GridPos {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        GridPos::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
@@ -11,9 +11,12 @@ pub mod door;
 pub mod doors;
 pub mod end_point;
 pub mod floor;
+pub mod grid_occupancy;
+pub mod grid_pos;
 pub mod grid_square;
 pub mod ground;
 pub mod layer;
+pub mod pathfinding;
 pub mod position;
 pub mod room;
 pub mod rooms;
@@ -0,0 +1,399 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::resources::dungeon::{
+    grid_occupancy::{GridOccupancy, Terrain},
+    grid_pos::GridPos,
+};
+
+/// The cost in feet of moving one square orthogonally, and of every other diagonal.
+const STEP_COST: u32 = 5;
+/// The cost in feet of every second diagonal under the alternating 5-10-5 rule, and of moving
+/// orthogonally into difficult terrain.
+const DOUBLE_STEP_COST: u32 = 10;
+/// The cost in feet of moving diagonally into difficult terrain.
+const TRIPLE_STEP_COST: u32 = 15;
+/// The shortest distance in feet a charge must cover.
+const MIN_CHARGE_DISTANCE: u32 = 10;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+/// How much further a creature may move, so that a move can be planned in several legs.
+pub struct MoveBudget {
+    /// The distance left to move in feet, or `None` if the move is not limited, e.g. out of
+    /// combat.
+    pub feet: Option<u32>,
+    /// Whether an odd number of diagonals has already been moved, so the next diagonal costs
+    /// 10 feet rather than 5.
+    pub odd_diagonal: bool,
+    /// Whether the move may not enter difficult terrain, as for a five-foot step.
+    pub avoid_difficult_terrain: bool,
+}
+
+impl MoveBudget {
+    pub fn unlimited() -> Self {
+        MoveBudget::default()
+    }
+
+    pub fn feet(feet: u32) -> Self {
+        MoveBudget {
+            feet: Some(feet),
+            ..default()
+        }
+    }
+
+    /// A single square, which may not be difficult terrain.
+    pub fn five_foot_step() -> Self {
+        MoveBudget {
+            feet: Some(STEP_COST),
+            avoid_difficult_terrain: true,
+            ..default()
+        }
+    }
+
+    /// The budget left after moving along the path.
+    pub fn after(self, cost: u32, odd_diagonal: bool) -> Self {
+        MoveBudget {
+            feet: self.feet.map(|feet| feet.saturating_sub(cost)),
+            odd_diagonal: self.odd_diagonal != odd_diagonal,
+            avoid_difficult_terrain: self.avoid_difficult_terrain,
+        }
+    }
+
+    fn allows(self, cost: u32) -> bool {
+        match self.feet {
+            Some(feet) => cost <= feet,
+            None => true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GridPath {
+    /// Every square along the path, from the start to the goal.
+    pub squares: Vec<GridPos>,
+    /// The cost of the path in feet.
+    pub cost: u32,
+    /// Whether the path moved an odd number of diagonals.
+    pub odd_diagonal: bool,
+}
+
+impl GridPath {
+    /// The steps between each square of the path, with the last step first, as used by
+    /// `MovementPath`.
+    pub fn steps(&self) -> Vec<(GridPos, GridPos)> {
+        self.squares
+            .iter()
+            .copied()
+            .zip(self.squares.iter().copied().skip(1))
+            .rev()
+            .collect()
+    }
+}
+
+/// A square reached by the search, along with whether it was reached after an odd number of
+/// diagonals, since that changes the cost of every later diagonal.
+type State = (GridPos, bool);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Node {
+    /// The cost so far plus the estimated cost to the goal.
+    estimate: u32,
+    cost: u32,
+    state: State,
+}
+
+impl Ord for Node {
+    /// Reversed, so the `BinaryHeap` pops the lowest estimate first. Ties go to the node which
+    /// has moved furthest, as it is likely closer to the goal.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then(self.cost.cmp(&other.cost))
+            .then(self.state.cmp(&other.state))
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn is_difficult(occupancy: &GridOccupancy, square: GridPos) -> bool {
+    occupancy.terrain(square).is_some_and(Terrain::is_difficult)
+}
+
+/// The cost in feet of a step between two adjacent squares, along with whether an odd number of
+/// diagonals has been moved after it. Moving into difficult terrain counts as two squares, or
+/// three for a diagonal, which does not count towards the alternating diagonals.
+fn step_cost(
+    occupancy: &GridOccupancy,
+    from: GridPos,
+    to: GridPos,
+    odd_diagonal: bool,
+) -> (u32, bool) {
+    let diagonal = from.x != to.x && from.y != to.y;
+    match (diagonal, is_difficult(occupancy, to), odd_diagonal) {
+        (false, false, _) => (STEP_COST, odd_diagonal),
+        (false, true, _) => (DOUBLE_STEP_COST, odd_diagonal),
+        (true, true, _) => (TRIPLE_STEP_COST, odd_diagonal),
+        (true, false, false) => (STEP_COST, true),
+        (true, false, true) => (DOUBLE_STEP_COST, false),
+    }
+}
+
+/// The cost of the shortest path between two squares with nothing in the way. Terrain only ever
+/// makes a step more expensive, so this never overestimates and A* still finds the cheapest path.
+pub fn estimate(from: GridPos, to: GridPos, odd_diagonal: bool) -> u32 {
+    let dx = from.x.abs_diff(to.x);
+    let dy = from.y.abs_diff(to.y);
+    let diagonals = dx.min(dy);
+    let straights = dx.max(dy) - diagonals;
+    STEP_COST * (straights + diagonals) + STEP_COST * ((diagonals + odd_diagonal as u32) / 2)
+}
+
+/// Whether a creature can step between two adjacent squares. Enemies block the square, while
+/// allies can be moved through. A diagonal step may not cut the corner of a wall or a pillar, but
+/// may pass the corner of a pit.
+fn can_step(
+    occupancy: &GridOccupancy,
+    from: GridPos,
+    to: GridPos,
+    is_enemy: &impl Fn(Entity) -> bool,
+    avoid_difficult_terrain: bool,
+) -> bool {
+    let open_corner = |square: GridPos| {
+        occupancy
+            .terrain(square)
+            .is_some_and(|terrain| !terrain.blocks_line())
+    };
+    let diagonal = from.x != to.x && from.y != to.y;
+    occupancy.terrain(to).is_some_and(Terrain::is_walkable)
+        && !(avoid_difficult_terrain && is_difficult(occupancy, to))
+        && !occupancy
+            .occupants(to)
+            .iter()
+            .any(|occupant| is_enemy(*occupant))
+        && (!diagonal
+            || (open_corner(GridPos::new(to.x, from.y)) && open_corner(GridPos::new(from.x, to.y))))
+}
+
+/// Finds the cheapest path for `mover` from `start` to `goal` with A*, following the Pathfinder
+/// movement rules: diagonals alternate between costing 5 and 10 feet, difficult terrain costs
+/// double, corners of walls cannot be cut, enemies block movement and allies can be moved through
+/// but not ended on. Returns `None` if there is no path within the budget.
+pub fn find_path(
+    occupancy: &GridOccupancy,
+    mover: Entity,
+    is_enemy: impl Fn(Entity) -> bool,
+    start: GridPos,
+    goal: GridPos,
+    budget: MoveBudget,
+) -> Option<GridPath> {
+    let debug = false;
+    if occupancy
+        .occupants(goal)
+        .iter()
+        .any(|occupant| *occupant != mover)
+    {
+        return None;
+    }
+
+    let start_state: State = (start, budget.odd_diagonal);
+    let mut costs: HashMap<State, u32> = HashMap::default();
+    let mut came_from: HashMap<State, State> = HashMap::default();
+    let mut open = BinaryHeap::new();
+    costs.insert(start_state, 0);
+    open.push(Node {
+        estimate: estimate(start, goal, budget.odd_diagonal),
+        cost: 0,
+        state: start_state,
+    });
+
+    while let Some(Node { cost, state, .. }) = open.pop() {
+        let (square, odd_diagonal) = state;
+        if square == goal {
+            let mut squares = vec![square];
+            let mut current = state;
+            while let Some(previous) = came_from.get(&current) {
+                squares.push(previous.0);
+                current = *previous;
+            }
+            squares.reverse();
+            if debug {
+                println!(
+                    "debug | pathfinding::find_path | found path costing {} feet: {:?}",
+                    cost, squares
+                );
+            }
+            return Some(GridPath {
+                squares,
+                cost,
+                odd_diagonal: odd_diagonal != budget.odd_diagonal,
+            });
+        }
+        if costs.get(&state).is_some_and(|best| cost > *best) {
+            continue;
+        }
+        for next in square.neighbours() {
+            if !can_step(
+                occupancy,
+                square,
+                next,
+                &is_enemy,
+                budget.avoid_difficult_terrain,
+            ) {
+                continue;
+            }
+            let (step, next_odd_diagonal) = step_cost(occupancy, square, next, odd_diagonal);
+            let next_cost = cost + step;
+            let next_state = (next, next_odd_diagonal);
+            if !budget.allows(next_cost)
+                || costs
+                    .get(&next_state)
+                    .is_some_and(|best| next_cost >= *best)
+            {
+                continue;
+            }
+            costs.insert(next_state, next_cost);
+            came_from.insert(next_state, state);
+            open.push(Node {
+                estimate: next_cost + estimate(next, goal, next_odd_diagonal),
+                cost: next_cost,
+                state: next_state,
+            });
+        }
+    }
+    None
+}
+
+/// Every square `mover` can end its move in from `start` within the budget, along with the cost
+/// in feet of the cheapest path to it. This follows the same rules as `find_path`, so squares
+/// holding an ally can be moved through but are not included. The start square is not included.
+pub fn reachable(
+    occupancy: &GridOccupancy,
+    mover: Entity,
+    is_enemy: impl Fn(Entity) -> bool,
+    start: GridPos,
+    budget: MoveBudget,
+) -> HashMap<GridPos, u32> {
+    let start_state: State = (start, budget.odd_diagonal);
+    let mut costs: HashMap<State, u32> = HashMap::default();
+    let mut open = BinaryHeap::new();
+    costs.insert(start_state, 0);
+    open.push(Node {
+        estimate: 0,
+        cost: 0,
+        state: start_state,
+    });
+
+    while let Some(Node { cost, state, .. }) = open.pop() {
+        let (square, odd_diagonal) = state;
+        if costs.get(&state).is_some_and(|best| cost > *best) {
+            continue;
+        }
+        for next in square.neighbours() {
+            if !can_step(
+                occupancy,
+                square,
+                next,
+                &is_enemy,
+                budget.avoid_difficult_terrain,
+            ) {
+                continue;
+            }
+            let (step, next_odd_diagonal) = step_cost(occupancy, square, next, odd_diagonal);
+            let next_cost = cost + step;
+            let next_state = (next, next_odd_diagonal);
+            if !budget.allows(next_cost)
+                || costs
+                    .get(&next_state)
+                    .is_some_and(|best| next_cost >= *best)
+            {
+                continue;
+            }
+            costs.insert(next_state, next_cost);
+            open.push(Node {
+                estimate: next_cost,
+                cost: next_cost,
+                state: next_state,
+            });
+        }
+    }
+
+    let mut squares: HashMap<GridPos, u32> = HashMap::default();
+    for ((square, _), cost) in costs {
+        let occupied = occupancy
+            .occupants(square)
+            .iter()
+            .any(|occupant| *occupant != mover);
+        if square == start || occupied {
+            continue;
+        }
+        squares
+            .entry(square)
+            .and_modify(|best| *best = (*best).min(cost))
+            .or_insert(cost);
+    }
+    squares
+}
+
+/// The path of a charge by `mover` from `start` to `goal`, the square it attacks from, or `None`
+/// if the charge is not allowed. A charge moves in a straight line of at least 10 feet and at
+/// most twice the mover's speed, and every square along it must be free of difficult terrain,
+/// obstacles and other creatures, allies included.
+#[allow(dead_code)]
+pub fn charge_path(
+    occupancy: &GridOccupancy,
+    mover: Entity,
+    start: GridPos,
+    goal: GridPos,
+    speed: u32,
+) -> Option<GridPath> {
+    let debug = false;
+    let offset = goal - start;
+    let distance = offset.x.abs().max(offset.y.abs());
+    if distance == 0 {
+        return None;
+    }
+
+    // The square on the line between the centres of `start` and `goal` after each step.
+    let squares: Vec<GridPos> = (0..=distance)
+        .map(|step| {
+            let fraction = step as f32 / distance as f32;
+            start
+                + GridPos::new(
+                    (offset.x as f32 * fraction).round() as i32,
+                    (offset.y as f32 * fraction).round() as i32,
+                )
+        })
+        .collect();
+
+    let mut cost = 0;
+    let mut odd_diagonal = false;
+    for (from, to) in squares.iter().zip(squares.iter().skip(1)) {
+        if !can_step(occupancy, *from, *to, &|occupant| occupant != mover, true) {
+            if debug {
+                println!(
+                    "debug | pathfinding::charge_path | charge blocked at {:?}",
+                    to
+                );
+            }
+            return None;
+        }
+        let (step, next_odd_diagonal) = step_cost(occupancy, *from, *to, odd_diagonal);
+        cost += step;
+        odd_diagonal = next_odd_diagonal;
+    }
+
+    (MIN_CHARGE_DISTANCE..=2 * speed)
+        .contains(&cost)
+        .then_some(GridPath {
+            squares,
+            cost,
+            odd_diagonal,
+        })
+}
@@ -3,12 +3,17 @@ use std::fs::File;
 use std::io::BufRead;
 use std::io::BufReader;
 
+use crate::plugins::combat::concealment::Lighting;
+
 const PREFIX: &str = "./assets/new_rooms/";
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Room {
     pub id: f32,
     pub tilemap: Vec<Vec<i32>>,
+    /// Rooms are brightly lit unless their file says otherwise.
+    #[serde(default)]
+    pub lighting: Lighting,
 }
 
 impl Room {
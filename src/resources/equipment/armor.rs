@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::plugins::combat::{
+    attack::armor_class_modifier::ACMod,
+    bonus::{BonusSource, BonusType},
+    AttackData,
+};
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum ArmorName {
+    // Light armor
+    Padded,
+    Leather,
+    StuddedLeather,
+    ChainShirt,
+    // Medium armor
+    Hide,
+    ScaleMail,
+    Chainmail,
+    Breastplate,
+    // Heavy armor
+    SplintMail,
+    BandedMail,
+    HalfPlate,
+    FullPlate,
+    // Shields
+    Buckler,
+    LightWoodenShield,
+    LightSteelShield,
+    HeavyWoodenShield,
+    HeavySteelShield,
+    TowerShield,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+/// The weight category of armor. Shields are their own category, as they go in a separate
+/// equipment slot and provide a Shield bonus instead of an Armor bonus.
+pub enum ArmorCategory {
+    Light,
+    Medium,
+    Heavy,
+    Shield,
+}
+
+impl ArmorCategory {
+    /// Medium and heavy armor reduce the speed of the wearer, see `armored_speed`.
+    pub fn slows_wearer(self) -> bool {
+        matches!(self, ArmorCategory::Medium | ArmorCategory::Heavy)
+    }
+}
+
+/// The speed in feet of a creature slowed by its armor, from the armor speed table: 30ft becomes
+/// 20ft and 20ft becomes 15ft. Each full 30ft of speed is reduced to 20ft, and what is left over
+/// is reduced by the same table.
+pub fn armored_speed(speed: usize) -> usize {
+    let remainder = match speed % 30 {
+        0 => 0,
+        5 => 5,
+        10 | 15 => 10,
+        20 => 15,
+        _ => 20,
+    };
+    speed / 30 * 20 + remainder
+}
+
+#[derive(Component, Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Armor {
+    pub armor_name: ArmorName,
+    pub category: ArmorCategory,
+    armor_bonus: isize,
+    /// The highest Dexterity bonus to AC the wearer can have, if any.
+    max_dex: Option<isize>,
+    /// The penalty applied to Strength and Dexterity based skill checks. This is a negative
+    /// number, e.g. -5 for Chainmail.
+    armor_check_penalty: isize,
+    /// The percent chance for an arcane spell with a somatic component to fail.
+    arcane_spell_failure: usize,
+}
+
+impl Armor {
+    pub fn armor_bonus(&self) -> isize {
+        self.armor_bonus
+    }
+
+    pub fn max_dex(&self) -> Option<isize> {
+        self.max_dex
+    }
+
+    pub fn armor_check_penalty(&self) -> isize {
+        self.armor_check_penalty
+    }
+
+    pub fn arcane_spell_failure(&self) -> usize {
+        self.arcane_spell_failure
+    }
+
+    pub fn is_shield(&self) -> bool {
+        self.category == ArmorCategory::Shield
+    }
+
+    pub fn to_ac_mod(&self, attack_data: AttackData) -> ACMod {
+        let (source, bonus_type) = if self.is_shield() {
+            (BonusSource::Shield, BonusType::Shield)
+        } else {
+            (BonusSource::Armor, BonusType::Armor)
+        };
+        ACMod {
+            val: self.armor_bonus,
+            source,
+            bonus_type,
+            attack_data,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct ArmorBundle {
+    pub armor: Armor,
+    // more here, e.g. enchantments
+}
@@ -4,11 +4,17 @@ use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::BufReader;
 
+use self::armor::{Armor, ArmorName};
 use self::weapon::{Weapon, WeaponName};
+use self::weapon_property::{PropertyEffect, WeaponProperty, WeaponPropertyName};
 
+pub mod armor;
 pub mod weapon;
+pub mod weapon_property;
 
 pub const WEAPON_DATA: &str = "assets/equipment/weapons/weapon_data.ron";
+pub const ARMOR_DATA: &str = "assets/equipment/armor/armor_data.ron";
+pub const WEAPON_PROPERTY_DATA: &str = "assets/equipment/weapons/weapon_properties.ron";
 
 #[derive(Deref, DerefMut, Resource, Serialize, Deserialize)]
 pub struct Armory(HashMap<WeaponName, Weapon>);
@@ -25,6 +31,44 @@ impl Armory {
     }
 }
 
+#[derive(Deref, DerefMut, Resource, Serialize, Deserialize)]
+pub struct ArmorLibrary(HashMap<ArmorName, Armor>);
+
+impl ArmorLibrary {
+    pub fn new() -> Self {
+        match File::open(ARMOR_DATA) {
+            Ok(file) => {
+                let reader = BufReader::new(file);
+                ron::de::from_reader(reader).unwrap()
+            }
+            Err(err) => panic!("Can't find armor file: {}", err),
+        }
+    }
+}
+
+#[derive(Deref, DerefMut, Resource, Serialize, Deserialize)]
+pub struct WeaponPropertyLibrary(HashMap<WeaponPropertyName, WeaponProperty>);
+
+impl WeaponPropertyLibrary {
+    pub fn new() -> Self {
+        match File::open(WEAPON_PROPERTY_DATA) {
+            Ok(file) => {
+                let reader = BufReader::new(file);
+                ron::de::from_reader(reader).unwrap()
+            }
+            Err(err) => panic!("Can't find weapon property file: {}", err),
+        }
+    }
+
+    /// Returns the effects of a weapon property, or an empty slice if the property has no
+    /// definition in the data file.
+    pub fn effects(&self, name: &WeaponPropertyName) -> &[PropertyEffect] {
+        self.get(name)
+            .map(|property| property.effects.as_slice())
+            .unwrap_or_default()
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum Enhancement {
     Plus1,
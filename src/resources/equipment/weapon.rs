@@ -6,20 +6,25 @@ use bevy::prelude::*;
 use rand::prelude::*;
 
 use crate::{
+    components::{racial_traits::WeaponFamiliarity, size::Size},
     config::TILE_SIZE,
     plugins::{
         combat::{
+            area::FEET_PER_SQUARE,
             attack::crit_multiplier::CritMultiplier,
             attack_damage::{damage_reduction::DRTypes, damage_reduction_modifier::DRModList},
         },
         item::equipment::weapon,
     },
-    resources::dice::Dice,
+    resources::{dice::Dice, equipment::weapon_property::WeaponProperties},
 };
 
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
 pub enum WeaponName {
     Longsword,
+    Dagger,
+    ShortSword,
+    HeavyMace,
 }
 
 #[derive(Component, Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -34,6 +39,10 @@ pub struct Weapon {
     pub crit_multiplier: CritMultiplier,
     melee: bool,
     thrown: bool,
+    /// Weapons such as the sap deal nonlethal damage. Other weapons can deal nonlethal damage
+    /// with a -4 penalty on the attack roll.
+    #[serde(default)]
+    nonlethal: bool,
     // TODO: maybe change this to an array with bool values to make Weapon `Copy`
     racial_group: Option<RacialWeapon>,
     martial_group: Proficiency,
@@ -50,6 +59,33 @@ impl Weapon {
     pub fn crit_threat_lower(&self) -> usize {
         self.crit_threat_range[1] - self.crit_threat_range[0] + 1
     }
+
+    pub fn reach(&self) -> Reach {
+        self.reach
+    }
+
+    pub fn is_melee(&self) -> bool {
+        self.melee
+    }
+
+    pub fn is_nonlethal(&self) -> bool {
+        self.nonlethal
+    }
+
+    pub fn weapon_groups(&self) -> &[WeaponGroup] {
+        self.weapon_groups.as_deref().unwrap_or_default()
+    }
+
+    /// The proficiency needed to wield the weapon well. Exotic weapons of a wielder's race are
+    /// martial weapons for them, see `WeaponFamiliarity`.
+    pub fn proficiency(&self, familiarity: Option<&WeaponFamiliarity>) -> Proficiency {
+        match (self.martial_group, self.racial_group, familiarity) {
+            (Proficiency::Exotic, Some(group), Some(familiarity)) if group == **familiarity => {
+                Proficiency::Martial
+            }
+            (proficiency, _, _) => proficiency,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Deref)]
@@ -91,6 +127,8 @@ impl DamageType {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
 pub enum WeaponGroup {
     HeavyBlades,
+    LightBlades,
+    Hammers,
     // more here
 }
 
@@ -101,6 +139,8 @@ pub enum WeaponGroup {
 /// For example, Elves gain proficiency in a group of weapons (Longsword, Longbow, etc)
 pub enum RacialWeapon {
     Elf,
+    Dwarf,
+    Halfling,
     Orc,
     // More here
 }
@@ -122,10 +162,24 @@ impl Reach {
     pub fn to_pixels(self) -> f32 {
         (*self / 5_usize) as f32 * TILE_SIZE
     }
+
+    /// The reach of the weapon in the hands of a wielder of the given size. Weapons use the
+    /// wielder's natural reach, and reach weapons double it, e.g. a Large creature with a reach
+    /// weapon has 20 feet of reach. Tiny and smaller creatures with a reach weapon can attack
+    /// adjacent squares.
+    pub fn for_size(self, size: Size) -> Reach {
+        let natural_reach = size.natural_reach();
+        match (*self > FEET_PER_SQUARE, natural_reach) {
+            (false, _) => Reach(natural_reach),
+            (true, 0) => Reach(FEET_PER_SQUARE),
+            (true, natural_reach) => Reach(natural_reach * 2),
+        }
+    }
 }
 
 #[derive(Bundle)]
 pub struct WeaponBundle {
     pub weapon: Weapon,
+    pub properties: WeaponProperties,
     // more here, e.g. enchantments
 }
@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    components::alignment::{Alignment, Moral},
+    plugins::combat::{
+        attack::critical_range_modifier::{
+            CritRangeMod, CritThreatBonusSource, CritThreatBonusType,
+        },
+        attack_damage::{
+            damage::DamageBonusSource,
+            damage_modifier::{AttackDamageMod, DamageDice, OnCrit},
+        },
+        bonus::BonusType,
+        damage::DamageType,
+        AttackData,
+    },
+    resources::{dice::Dice, equipment::weapon::Weapon},
+};
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Deref)]
+#[serde(transparent)]
+/// The name of a weapon special ability, e.g. "Flaming" or "Keen". Properties are defined only by
+/// their entry in `WEAPON_PROPERTY_DATA`, so a new one can be added without changing the code.
+pub struct WeaponPropertyName(pub String);
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+/// The definition of a weapon special ability, as loaded from `WEAPON_PROPERTY_DATA`.
+pub struct WeaponProperty {
+    /// The equivalent enhancement bonus used to price the property, e.g. +1 for Flaming.
+    pub enhancement_equivalent: usize,
+    pub effects: Vec<PropertyEffect>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+/// The ways in which a weapon special ability can modify an attack. Each variant feeds one of the
+/// existing modifier channels, so new properties can be added to the data file without adding
+/// new systems.
+pub enum PropertyEffect {
+    /// Extra damage dice added to every hit, e.g. the 1d6 fire of Flaming. When `versus` is set,
+    /// the damage only applies to defenders of that moral alignment, e.g. Holy vs Evil.
+    ExtraDamage {
+        dice: Dice,
+        dice_rolls: usize,
+        damage_type: DamageType,
+        on_crit: OnCrit,
+        versus: Option<Moral>,
+    },
+    /// Extra damage dice only added on a critical hit. One die is rolled for each step of the
+    /// weapon's critical multiplier above x1, e.g. 1d10 for x2 or 2d10 for x3.
+    BurstDamage { dice: Dice, damage_type: DamageType },
+    /// Doubles the threat range of the weapon. Does not stack with other effects which double
+    /// the threat range, e.g. `ImprovedCritical`.
+    DoubleCritRange,
+}
+
+impl PropertyEffect {
+    pub fn to_damage_mod(
+        self,
+        attack_data: AttackData,
+        weapon: &Weapon,
+        defender_alignment: Option<&Alignment>,
+    ) -> Option<AttackDamageMod> {
+        let (damage_dice, damage_type, on_crit) = match self {
+            PropertyEffect::ExtraDamage {
+                dice,
+                dice_rolls,
+                damage_type,
+                on_crit,
+                versus,
+            } => {
+                if let Some(moral) = versus {
+                    if !defender_alignment.is_some_and(|alignment| alignment.moral == moral) {
+                        return None;
+                    }
+                }
+                (DamageDice::new(dice, dice_rolls, 0), damage_type, on_crit)
+            }
+            PropertyEffect::BurstDamage { dice, damage_type } => {
+                let dice_rolls = weapon.crit_multiplier.size() - 1;
+                (
+                    DamageDice::new(dice, dice_rolls, 0),
+                    damage_type,
+                    OnCrit::OnlyOn,
+                )
+            }
+            PropertyEffect::DoubleCritRange => return None,
+        };
+        Some(AttackDamageMod {
+            val: 0,
+            attack_data,
+            bonus_type: BonusType::Untyped,
+            damage_type,
+            bonus_source: DamageBonusSource::WeaponProperty,
+            on_crit,
+            damage_dice: Some(damage_dice),
+        })
+    }
+
+    pub fn to_crit_range_mod(
+        self,
+        attack_data: AttackData,
+        weapon: &Weapon,
+    ) -> Option<CritRangeMod> {
+        match self {
+            PropertyEffect::DoubleCritRange => Some(CritRangeMod {
+                val: weapon.crit_threat_lower(),
+                source: CritThreatBonusSource::Keen,
+                bonus_type: CritThreatBonusType::DoubleRange,
+                attack_data,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone, Default, Deref, DerefMut)]
+/// The special abilities on a weapon entity. The effects of each ability are looked up in the
+/// `WeaponPropertyLibrary` when an attack is made.
+pub struct WeaponProperties(Vec<WeaponPropertyName>);
@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+use bevy::utils::hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::BufReader;
+
+use crate::{
+    components::level::CharacterLevel,
+    resources::{equipment::weapon::WeaponName, hero::hero_class::HeroClass},
+};
+
+pub const FEAT_DATA: &str = "assets/feats/feat_data.ron";
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum FeatName {
+    CombatExpertise,
+    CombatReflexes,
+    Dodge,
+    ImprovedCritical,
+    ImprovedInitiative,
+    PowerAttack,
+    Toughness,
+    WeaponFocus,
+    WeaponSpecialization,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+/// A requirement a creature must meet before it can take a feat.
+pub enum Prerequisite {
+    BaseAttackBonus(isize),
+    Strength(usize),
+    Dexterity(usize),
+    Intelligence(usize),
+    Feat(FeatName),
+    ClassLevel(HeroClass, usize),
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Feat {
+    pub name: FeatName,
+    pub prerequisites: Vec<Prerequisite>,
+    /// Whether the feat applies to a single chosen weapon, e.g. Weapon Focus.
+    #[serde(default)]
+    pub weapon_choice: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+/// A feat taken by a creature, along with the weapon it applies to for feats like Weapon Focus.
+pub struct FeatSelection {
+    pub name: FeatName,
+    #[serde(default)]
+    pub weapon: Option<WeaponName>,
+}
+
+/// The parts of a creature which are checked against the prerequisites of a feat. Ability scores
+/// the creature does not have never meet a prerequisite.
+pub struct FeatCandidate<'a> {
+    pub base_attack_bonus: isize,
+    pub strength: Option<usize>,
+    pub dexterity: Option<usize>,
+    pub intelligence: Option<usize>,
+    pub level: Option<&'a CharacterLevel>,
+    /// The feats the creature already has.
+    pub feats: &'a [FeatName],
+}
+
+impl Prerequisite {
+    pub fn is_met(&self, candidate: &FeatCandidate) -> bool {
+        match self {
+            Prerequisite::BaseAttackBonus(bab) => candidate.base_attack_bonus >= *bab,
+            Prerequisite::Strength(score) => candidate.strength.is_some_and(|val| val >= *score),
+            Prerequisite::Dexterity(score) => candidate.dexterity.is_some_and(|val| val >= *score),
+            Prerequisite::Intelligence(score) => {
+                candidate.intelligence.is_some_and(|val| val >= *score)
+            }
+            Prerequisite::Feat(feat) => candidate.feats.contains(feat),
+            Prerequisite::ClassLevel(class, level) => candidate
+                .level
+                .is_some_and(|char_level| char_level.class == *class && char_level.level >= *level),
+        }
+    }
+}
+
+#[derive(Deref, DerefMut, Resource, Serialize, Deserialize)]
+/// The catalog of every feat, loaded from `FEAT_DATA`.
+pub struct FeatLibrary(HashMap<FeatName, Feat>);
+
+impl FeatLibrary {
+    pub fn new() -> Self {
+        match File::open(FEAT_DATA) {
+            Ok(file) => {
+                let reader = BufReader::new(file);
+                ron::de::from_reader(reader).unwrap()
+            }
+            Err(err) => panic!("Can't find feat file: {}", err),
+        }
+    }
+
+    /// The prerequisites of `feat` which the candidate does not meet. A feat missing from the
+    /// catalog can never be taken.
+    pub fn unmet_prerequisites(
+        &self,
+        feat: FeatName,
+        candidate: &FeatCandidate,
+    ) -> Result<Vec<Prerequisite>, &'static str> {
+        let feat = self
+            .get(&feat)
+            .ok_or("Feat is missing from the feat catalog")?;
+        Ok(feat
+            .prerequisites
+            .iter()
+            .filter(|prerequisite| !prerequisite.is_met(candidate))
+            .cloned()
+            .collect())
+    }
+}
@@ -14,7 +14,7 @@ pub struct PauseSceneData {
 
 #[derive(Resource, Serialize, Deserialize, Debug, Clone)]
 pub struct GameData {
-    heroes: [Hero; 2],
+    heroes: [Hero; 5],
     // weapons: [Weapon; 11],
     // skills: [Skill; 4],
     // player_list_effects_information: [Effect; 8],
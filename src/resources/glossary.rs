@@ -17,6 +17,14 @@ pub struct Glossary {
     pub action_bar: ActionBar,
     pub attack_submenu: AttackSubMenu,
     pub move_submenu: MoveSubMenu,
+    pub stances: Stances,
+    pub cleric_actions: ClericActions,
+    pub level_up: LevelUpText,
+    pub abilities: AbilityText,
+    pub character_creation: CharacterCreationText,
+    pub races: RaceText,
+    pub proficiency: ProficiencyText,
+    pub victory: VictoryText,
 }
 
 /// This trait is for enums which have a corresponding translation, and is
@@ -37,6 +45,97 @@ pub struct MoveSubMenu {
 pub struct AttackSubMenu {
     pub single_attack: String,
     pub full_attack: String,
+    pub trip: String,
+    pub disarm: String,
+    pub bull_rush: String,
+    pub grapple: String,
+    pub sunder: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Stances {
+    pub power_attack: String,
+    pub combat_expertise: String,
+    pub rage: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LevelUpText {
+    pub level_up: String,
+    pub hit_points: String,
+    pub roll: String,
+    pub average: String,
+    pub feats: String,
+    pub ability_score: String,
+    pub confirm: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CharacterCreationText {
+    pub title: String,
+    pub point_buy: String,
+    pub roll: String,
+    pub reroll: String,
+    pub points_left: String,
+    pub swap_hint: String,
+    pub race: String,
+    pub racial_bonus: String,
+    pub speed: String,
+    pub hit_points: String,
+    pub armor_class: String,
+    pub initiative: String,
+    pub fortitude: String,
+    pub reflex: String,
+    pub will: String,
+    pub melee_attack: String,
+    pub combat_maneuver_bonus: String,
+    pub combat_maneuver_defense: String,
+    pub back: String,
+    pub confirm: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VictoryText {
+    pub title: String,
+    pub floors: String,
+    pub rooms_cleared: String,
+    pub monsters_defeated: String,
+    pub experience: String,
+    pub main_menu: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProficiencyText {
+    pub not_proficient: String,
+    pub weapon_penalty: String,
+    pub armor_penalty: String,
+    pub confirm_equip: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RaceText {
+    pub human: String,
+    pub elf: String,
+    pub dwarf: String,
+    pub halfling: String,
+    pub half_orc: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AbilityText {
+    pub strength: String,
+    pub dexterity: String,
+    pub constitution: String,
+    pub intelligence: String,
+    pub wisdom: String,
+    pub charisma: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ClericActions {
+    pub channel_heal: String,
+    pub channel_harm: String,
+    pub cure_light_wounds: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2,14 +2,36 @@ use bevy_inspector_egui::InspectorOptions;
 use serde::{Deserialize, Serialize};
 use std::slice::Iter;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, InspectorOptions)]
+use crate::resources::dice::Dice;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, InspectorOptions)]
 pub enum HeroClass {
     Fighter,
     Wizard,
+    Rogue,
+    Barbarian,
+    Cleric,
 }
 
 impl HeroClass {
     pub fn iterator() -> Iter<'static, HeroClass> {
-        [HeroClass::Fighter, HeroClass::Wizard].iter()
+        [
+            HeroClass::Fighter,
+            HeroClass::Wizard,
+            HeroClass::Rogue,
+            HeroClass::Barbarian,
+            HeroClass::Cleric,
+        ]
+        .iter()
+    }
+
+    /// The die rolled for the hit points gained at each level after the first.
+    pub fn hit_die(&self) -> Dice {
+        match self {
+            HeroClass::Wizard => Dice::D6,
+            HeroClass::Rogue | HeroClass::Cleric => Dice::D8,
+            HeroClass::Fighter => Dice::D10,
+            HeroClass::Barbarian => Dice::D12,
+        }
     }
 }
@@ -6,12 +6,25 @@ pub mod stats;
 
 use hero_class::HeroClass;
 
-use crate::components::{attack_bonus::BaseAttackBonus, attributes::AttributeBundle};
+use crate::{
+    components::{
+        attributes::AttributeBundle, class_features::ClassChoices, proficiencies::Proficiencies,
+    },
+    resources::feat::FeatSelection,
+};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Hero {
     pub hero_class: HeroClass,
     pub stats: stats::Stats,
     pub attributes: AttributeBundle,
-    pub base_attack_bonus: BaseAttackBonus,
+    /// The feats the hero starts with, which are checked against their prerequisites in the
+    /// `FeatLibrary` once the hero is spawned.
+    #[serde(default)]
+    pub feats: Vec<FeatSelection>,
+    #[serde(default)]
+    pub class_choices: ClassChoices,
+    /// The weapons and armor the class is proficient with.
+    #[serde(default)]
+    pub proficiencies: Proficiencies,
 }
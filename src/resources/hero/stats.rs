@@ -3,7 +3,6 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, InspectorOptions)]
 pub struct Stats {
-    pub health_points: f32,
     pub speed: f32,
     // pub strength: f32,
     // pub intelligence: f32,
@@ -1,8 +1,10 @@
 pub mod animation_state;
+pub mod class_progression;
 pub mod dice;
 pub mod dictionary;
 pub mod dungeon;
 pub mod equipment;
+pub mod feat;
 pub mod game_data;
 pub mod game_mode;
 pub mod glossary;
@@ -11,4 +13,6 @@ pub mod language;
 pub mod monster;
 pub mod player;
 pub mod profile;
+pub mod race;
 pub mod setting;
+pub mod spell;
@@ -4,7 +4,6 @@ use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::BufReader;
 
-use crate::components::armor_class::ArmorClass;
 use crate::components::attributes::AttributeBundle;
 use crate::resources::monster::monster_stats::MonsterStats;
 
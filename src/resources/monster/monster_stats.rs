@@ -1,9 +1,11 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::components::creature::Creature;
+use crate::components::creature::{Creature, Subtypes};
+use crate::components::experience::ChallengeRating;
 use crate::components::hitpoints::HitPoints;
-use crate::resources::monster::ArmorClass;
+use crate::components::size::Size;
+use crate::plugins::combat::concealment::Concealment;
 use crate::resources::monster::AttributeBundle;
 use crate::resources::monster::Monster;
 
@@ -13,5 +15,13 @@ pub struct MonsterStats {
     pub hp: HitPoints,
     pub attributes: AttributeBundle,
     pub label: Creature,
-    // pub armor_class: ArmorClass,
+    pub challenge_rating: ChallengeRating,
+    #[serde(default)]
+    pub subtypes: Subtypes,
+    #[serde(default)]
+    pub size: Size,
+    /// Inserted as a component on spawn when the monster is always concealed.
+    #[bundle(ignore)]
+    #[serde(default)]
+    pub concealment: Option<Concealment>,
 }
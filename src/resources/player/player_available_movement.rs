@@ -1,8 +1,7 @@
 use bevy::prelude::*;
-use std::ops::Neg;
 use std::slice::Iter;
 
-use crate::{config::TILE_SIZE, plugins::input::movement::click_move::MoveNode};
+use crate::resources::dungeon::grid_pos::GridPos;
 
 #[derive(Debug, Clone, Copy, Deref, DerefMut)]
 pub struct PlayerAvailableMovement([(MoveDirection, bool); 8]);
@@ -56,16 +55,17 @@ impl MoveDirection {
         .iter()
     }
 
-    pub fn to_offset(self) -> Vec3 {
+    /// The change in `GridPos` from moving one square in the direction.
+    pub fn to_offset(self) -> GridPos {
         match self {
-            Self::Up => Vec3::new(0.0, TILE_SIZE, 0.0),
-            Self::UpRight => Vec3::new(TILE_SIZE, TILE_SIZE, 0.0),
-            Self::Right => Vec3::new(TILE_SIZE, 0.0, 0.0),
-            Self::DownRight => Vec3::new(TILE_SIZE, -TILE_SIZE, 0.0),
-            Self::Down => Vec3::new(0.0, -TILE_SIZE, 0.0),
-            Self::DownLeft => Vec3::new(-TILE_SIZE, -TILE_SIZE, 0.0),
-            Self::Left => Vec3::new(-TILE_SIZE, 0.0, 0.0),
-            Self::UpLeft => Vec3::new(-TILE_SIZE, TILE_SIZE, 0.0),
+            Self::Up => GridPos::new(0, -1),
+            Self::UpRight => GridPos::new(1, -1),
+            Self::Right => GridPos::new(1, 0),
+            Self::DownRight => GridPos::new(1, 1),
+            Self::Down => GridPos::new(0, 1),
+            Self::DownLeft => GridPos::new(-1, 1),
+            Self::Left => GridPos::new(-1, 0),
+            Self::UpLeft => GridPos::new(-1, -1),
         }
     }
 }
@@ -75,33 +75,6 @@ impl PlayerAvailableMovement {
         let mut true_self = self.into_iter().filter(|(_, val)| *val).map(|(dir, _)| dir);
         true_self.any(|dir| dir == direction)
     }
-    pub fn open_nodes(self, current_node: &MoveNode, dest: Vec3) -> Vec<MoveNode> {
-        let debug = false;
-        if debug {
-            println!("debug | PlayerAvailableMovement::open_nodes | start");
-        }
-        let mut open_paths: Vec<MoveNode> = Vec::with_capacity(8);
-        for (move_direction, _) in self.iter().filter(|(_move_dir, can_move)| *can_move) {
-            let new_move = match *move_direction {
-                MoveDirection::Up => Vec3::new(0.0, TILE_SIZE, 0.0),
-                MoveDirection::UpRight => Vec3::new(TILE_SIZE, TILE_SIZE, 0.0),
-                MoveDirection::Right => Vec3::new(TILE_SIZE, 0.0, 0.0),
-                MoveDirection::DownRight => Vec3::new(TILE_SIZE, TILE_SIZE.neg(), 0.0),
-                MoveDirection::Down => Vec3::new(0.0, TILE_SIZE.neg(), 0.0),
-                MoveDirection::DownLeft => Vec3::new(TILE_SIZE.neg(), TILE_SIZE.neg(), 0.0),
-                MoveDirection::Left => Vec3::new(TILE_SIZE.neg(), 0.0, 0.0),
-                MoveDirection::UpLeft => Vec3::new(TILE_SIZE.neg(), TILE_SIZE, 0.0),
-            };
-            open_paths.push(current_node.to_new_pos(new_move, dest));
-        }
-        if debug {
-            println!(
-                "debug | PlayerAvailableMovement::open_nodes | open_paths: {:?}",
-                open_paths
-            );
-        }
-        open_paths
-    }
 
     pub fn new_all_true() -> Self {
         PlayerAvailableMovement([
@@ -116,13 +89,6 @@ impl PlayerAvailableMovement {
         ])
     }
 
-    pub fn merge(&mut self, other: Self) -> &mut Self {
-        for ((_dir, val), (_other_dir, other_val)) in self.iter_mut().zip(other.iter()) {
-            *val = *val && *other_val;
-        }
-        self
-    }
-
     // TODO: This is currently buggy - e.g. it will return can_move_up_right
     // true when the up and right are clear, but there still may be something
     // occupying that square.
@@ -8,4 +8,8 @@ pub struct PlayerDungeonStats {
     pub current_floor_index: usize,
     pub current_room_position: Position,
     pub is_room_cleared: bool,
+    /// How many rooms have been cleared of monsters this run, shown when the player wins.
+    pub rooms_cleared: usize,
+    /// How many monsters have been killed this run, shown when the player wins.
+    pub monsters_defeated: usize,
 }
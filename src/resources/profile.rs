@@ -1,9 +1,11 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::components::attributes::AttributeBundle;
 use crate::resources::game_mode::GameMode;
 use crate::resources::hero::gender::Gender;
 use crate::resources::hero::hero_class::HeroClass;
+use crate::resources::race::RaceName;
 use crate::scenes::hero_select_scene::ButtonComponent;
 
 #[derive(Resource, Serialize, Deserialize, Debug, Clone)]
@@ -12,6 +14,9 @@ pub struct Profile {
     pub game_mode: GameMode,
     pub hero_class: HeroClass,
     pub gender: Gender,
+    pub race: RaceName,
+    /// The ability scores chosen in character creation, with racial adjustments applied.
+    pub attributes: Option<AttributeBundle>,
 }
 
 impl Profile {
@@ -21,6 +26,8 @@ impl Profile {
             game_mode: GameMode::ClassicMode,
             hero_class: HeroClass::Fighter,
             gender: Gender::Male,
+            race: RaceName::default(),
+            attributes: None,
         }
     }
 
@@ -28,7 +35,9 @@ impl Profile {
         self.game_mode = game_mode;
     }
 
+    /// Picks the hero's class and gender, clearing any ability scores chosen for a previous hero.
     pub fn set_hero(&mut self, button: ButtonComponent) {
+        self.attributes = None;
         match button {
             ButtonComponent::MaleFighter => {
                 self.hero_class = HeroClass::Fighter;
@@ -46,6 +55,30 @@ impl Profile {
                 self.hero_class = HeroClass::Wizard;
                 self.gender = Gender::Female;
             }
+            ButtonComponent::MaleRogue => {
+                self.hero_class = HeroClass::Rogue;
+                self.gender = Gender::Male;
+            }
+            ButtonComponent::FemaleRogue => {
+                self.hero_class = HeroClass::Rogue;
+                self.gender = Gender::Female;
+            }
+            ButtonComponent::MaleBarbarian => {
+                self.hero_class = HeroClass::Barbarian;
+                self.gender = Gender::Male;
+            }
+            ButtonComponent::FemaleBarbarian => {
+                self.hero_class = HeroClass::Barbarian;
+                self.gender = Gender::Female;
+            }
+            ButtonComponent::MaleCleric => {
+                self.hero_class = HeroClass::Cleric;
+                self.gender = Gender::Male;
+            }
+            ButtonComponent::FemaleCleric => {
+                self.hero_class = HeroClass::Cleric;
+                self.gender = Gender::Female;
+            }
         }
     }
 }
@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+use bevy::utils::hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::BufReader;
+use std::slice::Iter;
+
+use crate::{
+    components::{
+        attributes::{Ability, AttributeBundle},
+        racial_traits::RacialTrait,
+        size::Size,
+    },
+    resources::{
+        equipment::weapon::{RacialWeapon, WeaponName},
+        glossary::{Glossary, Translation},
+    },
+};
+
+pub const RACE_DATA: &str = "assets/races/race_data.ron";
+
+#[derive(Component, Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy, Default)]
+pub enum RaceName {
+    #[default]
+    Human,
+    Elf,
+    Dwarf,
+    Halfling,
+    HalfOrc,
+}
+
+impl RaceName {
+    pub fn iterator() -> Iter<'static, Self> {
+        [
+            RaceName::Human,
+            RaceName::Elf,
+            RaceName::Dwarf,
+            RaceName::Halfling,
+            RaceName::HalfOrc,
+        ]
+        .iter()
+    }
+}
+
+impl Translation for RaceName {
+    fn to_string_glossary(self, glossary: &Glossary) -> String {
+        let races = &glossary.races;
+        match self {
+            RaceName::Human => races.human.clone(),
+            RaceName::Elf => races.elf.clone(),
+            RaceName::Dwarf => races.dwarf.clone(),
+            RaceName::Halfling => races.halfling.clone(),
+            RaceName::HalfOrc => races.half_orc.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Race {
+    pub name: RaceName,
+    pub ability_adjustments: Vec<(Ability, isize)>,
+    /// Humans and half-orcs add this bonus to one ability score of their choice instead of
+    /// having fixed adjustments.
+    #[serde(default)]
+    pub floating_bonus: Option<isize>,
+    pub size: Size,
+    /// The base speed, in feet.
+    pub speed: usize,
+    /// The range of darkvision, in feet, if any.
+    #[serde(default)]
+    pub darkvision: Option<usize>,
+    /// Members of the race treat the exotic weapons of this group as martial weapons.
+    #[serde(default)]
+    pub weapon_familiarity: Option<RacialWeapon>,
+    /// Weapons every member of the race is proficient with, whatever their class.
+    #[serde(default)]
+    pub weapon_proficiencies: Vec<WeaponName>,
+    #[serde(default)]
+    pub traits: Vec<RacialTrait>,
+}
+
+impl Race {
+    /// The adjustment to an ability score from this race, including the floating bonus if it was
+    /// given to that ability.
+    pub fn adjustment(&self, ability: Ability, floating_bonus: Option<Ability>) -> isize {
+        let fixed: isize = self
+            .ability_adjustments
+            .iter()
+            .filter(|(adjusted, _)| *adjusted == ability)
+            .map(|(_, adjustment)| adjustment)
+            .sum();
+        let floating = match (self.floating_bonus, floating_bonus) {
+            (Some(bonus), Some(chosen)) if chosen == ability => bonus,
+            _ => 0,
+        };
+        fixed + floating
+    }
+
+    /// Applies the racial adjustments to a set of scores. Scores never fall below 3.
+    pub fn adjusted(&self, scores: [usize; 6], floating_bonus: Option<Ability>) -> AttributeBundle {
+        let mut adjusted = scores;
+        for (score, ability) in adjusted.iter_mut().zip(Ability::iterator()) {
+            *score = (*score as isize + self.adjustment(*ability, floating_bonus)).max(3) as usize;
+        }
+        AttributeBundle::new(adjusted)
+    }
+}
+
+#[derive(Deref, DerefMut, Resource, Serialize, Deserialize)]
+/// The catalog of playable races, loaded from `RACE_DATA`.
+pub struct RaceLibrary(HashMap<RaceName, Race>);
+
+impl RaceLibrary {
+    pub fn new() -> Self {
+        match File::open(RACE_DATA) {
+            Ok(file) => {
+                let reader = BufReader::new(file);
+                ron::de::from_reader(reader).unwrap()
+            }
+            Err(err) => panic!("Can't find race file: {}", err),
+        }
+    }
+}
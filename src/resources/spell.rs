@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use bevy::utils::hashbrown::HashMap;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::BufReader;
+
+use crate::{components::saving_throw::Save, plugins::combat::area::Area, resources::dice::Dice};
+
+pub const SPELL_DATA: &str = "assets/spells/spell_data.ron";
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Copy)]
+// Only the cure spells are in the catalog so far.
+#[allow(clippy::enum_variant_names)]
+pub enum SpellName {
+    CureLightWounds,
+    CureModerateWounds,
+    CureSeriousWounds,
+    CureCriticalWounds,
+}
+
+impl SpellName {
+    /// Cure spells can be cast spontaneously by a Cleric in place of any prepared spell.
+    pub fn is_cure(self) -> bool {
+        matches!(
+            self,
+            SpellName::CureLightWounds
+                | SpellName::CureModerateWounds
+                | SpellName::CureSeriousWounds
+                | SpellName::CureCriticalWounds
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+pub enum SpellRange {
+    Personal,
+    Touch,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+pub enum SpellEffect {
+    /// Heals living creatures and damages undead by `rolls`d`dice`, plus the caster level up to
+    /// `max_caster_level_bonus`.
+    PositiveEnergy {
+        dice: Dice,
+        rolls: usize,
+        max_caster_level_bonus: usize,
+    },
+}
+
+impl SpellEffect {
+    pub fn roll<R: Rng + ?Sized>(self, rng: &mut R, caster_level: usize) -> usize {
+        match self {
+            SpellEffect::PositiveEnergy {
+                dice,
+                rolls,
+                max_caster_level_bonus,
+            } => dice.roll_n(rng, rolls) + caster_level.min(max_caster_level_bonus),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Spell {
+    pub name: SpellName,
+    pub level: usize,
+    pub range: SpellRange,
+    #[serde(default)]
+    pub area: Option<Area>,
+    pub effect: SpellEffect,
+    /// The saving throw the target may make, if any. A successful save halves the effect.
+    #[serde(default)]
+    pub save: Option<Save>,
+}
+
+impl Spell {
+    /// The DC to resist the spell, which rises with the bonus of the caster's casting attribute,
+    /// e.g. Wisdom for a Cleric.
+    pub fn save_dc(&self, attribute_bonus: isize) -> usize {
+        (10 + self.level as isize + attribute_bonus).max(0) as usize
+    }
+}
+
+#[derive(Deref, DerefMut, Resource, Serialize, Deserialize)]
+/// The catalog of spells, loaded from `SPELL_DATA`.
+pub struct SpellLibrary(HashMap<SpellName, Spell>);
+
+impl SpellLibrary {
+    pub fn new() -> Self {
+        match File::open(SPELL_DATA) {
+            Ok(file) => {
+                let reader = BufReader::new(file);
+                ron::de::from_reader(reader).unwrap()
+            }
+            Err(err) => panic!("Can't find spell file: {}", err),
+        }
+    }
+}
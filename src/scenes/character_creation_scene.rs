@@ -0,0 +1,571 @@
+use bevy::prelude::*;
+use std::slice::Iter;
+
+use crate::components::attributes::{ability_bonus, Ability, AttributeBundle};
+use crate::materials::font::FontMaterials;
+use crate::materials::scenes::ScenesMaterials;
+use crate::plugins::leveling::first_level_hit_points;
+use crate::resources::class_progression::ClassProgressionLibrary;
+use crate::resources::dice::Dice;
+use crate::resources::dictionary::Dictionary;
+use crate::resources::glossary::{CharacterCreationText, Translation};
+use crate::resources::profile::Profile;
+use crate::resources::race::{Race, RaceLibrary, RaceName};
+use crate::scenes::SceneState;
+
+const FONT_SIZE: f32 = 24.0;
+/// The score every ability starts at for a point buy, which costs no points.
+const POINT_BUY_BASE_SCORE: usize = 10;
+const POINT_BUY_MIN_SCORE: usize = 7;
+const POINT_BUY_MAX_SCORE: usize = 18;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// How the ability scores of a new hero are generated.
+pub enum GenerationMethod {
+    /// Buy scores from a budget of points, see `point_buy_cost`.
+    PointBuy(usize),
+    /// Roll 4d6 for each score and drop the lowest die.
+    Roll,
+}
+
+impl GenerationMethod {
+    pub fn iterator() -> Iter<'static, GenerationMethod> {
+        [
+            GenerationMethod::PointBuy(15),
+            GenerationMethod::PointBuy(20),
+            GenerationMethod::PointBuy(25),
+            GenerationMethod::Roll,
+        ]
+        .iter()
+    }
+}
+
+/// The points a score costs in a point buy, or `None` if it can't be bought.
+pub fn point_buy_cost(score: usize) -> Option<isize> {
+    match score {
+        7 => Some(-4),
+        8 => Some(-2),
+        9 => Some(-1),
+        10 => Some(0),
+        11 => Some(1),
+        12 => Some(2),
+        13 => Some(3),
+        14 => Some(5),
+        15 => Some(7),
+        16 => Some(10),
+        17 => Some(13),
+        18 => Some(17),
+        _ => None,
+    }
+}
+
+/// Rolls 4d6 and adds up the highest three.
+pub fn roll_ability_score() -> usize {
+    let mut rng = rand::thread_rng();
+    let rolls: [usize; 4] = Dice::D6.roll_const(&mut rng);
+    rolls.iter().sum::<usize>() - rolls.iter().min().unwrap()
+}
+
+#[derive(Resource, Clone, Debug)]
+/// The race and ability scores of the hero being created.
+struct CharacterCreation {
+    method: GenerationMethod,
+    /// The scores before racial adjustments.
+    scores: [usize; 6],
+    /// The rolled score picked to be swapped with the next one clicked.
+    selected: Option<Ability>,
+    race: RaceName,
+    /// The ability given the floating racial bonus of humans and half-orcs.
+    racial_bonus: Option<Ability>,
+}
+
+impl CharacterCreation {
+    fn new(method: GenerationMethod, race: RaceName) -> Self {
+        let mut creation = Self {
+            method,
+            scores: [POINT_BUY_BASE_SCORE; 6],
+            selected: None,
+            race,
+            racial_bonus: None,
+        };
+        creation.set_method(method);
+        creation
+    }
+
+    fn set_method(&mut self, method: GenerationMethod) {
+        self.scores = match method {
+            GenerationMethod::PointBuy(_) => [POINT_BUY_BASE_SCORE; 6],
+            GenerationMethod::Roll => [(); 6].map(|_| roll_ability_score()),
+        };
+        self.method = method;
+        self.selected = None;
+    }
+
+    fn points_spent(&self) -> isize {
+        self.scores
+            .iter()
+            .filter_map(|score| point_buy_cost(*score))
+            .sum()
+    }
+
+    fn points_left(&self) -> Option<isize> {
+        match self.method {
+            GenerationMethod::PointBuy(budget) => Some(budget as isize - self.points_spent()),
+            GenerationMethod::Roll => None,
+        }
+    }
+
+    fn can_increase(&self, ability: Ability) -> bool {
+        let score = self.scores[ability as usize];
+        if score >= POINT_BUY_MAX_SCORE {
+            return false;
+        }
+        match (
+            self.points_left(),
+            point_buy_cost(score),
+            point_buy_cost(score + 1),
+        ) {
+            (Some(points_left), Some(cost), Some(next_cost)) => next_cost - cost <= points_left,
+            _ => false,
+        }
+    }
+
+    fn can_decrease(&self, ability: Ability) -> bool {
+        self.points_left().is_some() && self.scores[ability as usize] > POINT_BUY_MIN_SCORE
+    }
+
+    /// The scores with the racial adjustments applied.
+    fn attributes(&self, race: &Race) -> AttributeBundle {
+        race.adjusted(self.scores, self.racial_bonus)
+    }
+
+    /// A race with a floating bonus needs an ability picked for it.
+    fn is_complete(&self, race: &Race) -> bool {
+        race.floating_bonus.is_none() || self.racial_bonus.is_some()
+    }
+}
+
+#[derive(Component, Copy, Clone, Debug, PartialEq, Eq)]
+enum CreationButton {
+    Method(GenerationMethod),
+    Decrease(Ability),
+    Increase(Ability),
+    /// Picks a rolled score to swap with another.
+    Select(Ability),
+    Reroll,
+    Race(RaceName),
+    RacialBonus(Ability),
+    Return,
+    Confirm,
+}
+
+#[derive(Component, Copy, Clone, Debug, PartialEq, Eq)]
+/// Text which is updated as the scores change.
+enum CreationText {
+    Score(Ability),
+    PointsLeft,
+    Preview,
+}
+
+pub struct CharacterCreationScenePlugin;
+
+#[derive(Resource)]
+struct CharacterCreationSceneData {
+    user_interface_root: Entity,
+}
+
+impl Plugin for CharacterCreationScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(SceneState::CharacterCreationScene), setup);
+        app.add_systems(
+            Update,
+            (
+                button_handle,
+                button_color_handle,
+                update_text.run_if(resource_changed::<CharacterCreation>()),
+            )
+                .chain()
+                .run_if(in_state(SceneState::CharacterCreationScene)),
+        );
+        app.add_systems(OnExit(SceneState::CharacterCreationScene), cleanup);
+    }
+}
+
+fn setup(
+    scenes_materials: Res<ScenesMaterials>,
+    font_materials: Res<FontMaterials>,
+    dictionary: Res<Dictionary>,
+    mut commands: Commands,
+) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let full_glossary = dictionary.get_glossary();
+    let glossary = full_glossary.character_creation.clone();
+    let text_style = TextStyle {
+        font,
+        font_size: FONT_SIZE,
+        color: Color::WHITE,
+    };
+
+    let user_interface_root = commands
+        .spawn(ImageBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(8.0),
+                ..Default::default()
+            },
+            image: UiImage::new(scenes_materials.sub_background_image.clone()),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                glossary.title.clone(),
+                TextStyle {
+                    font_size: 50.0,
+                    color: Color::BLACK,
+                    ..text_style.clone()
+                },
+            ));
+
+            row(parent, |row| {
+                for method in GenerationMethod::iterator() {
+                    let text = match method {
+                        GenerationMethod::PointBuy(budget) => {
+                            format!("{} {}", glossary.point_buy, budget)
+                        }
+                        GenerationMethod::Roll => glossary.roll.clone(),
+                    };
+                    button(row, CreationButton::Method(*method), text, &text_style);
+                }
+                button(
+                    row,
+                    CreationButton::Reroll,
+                    glossary.reroll.clone(),
+                    &text_style,
+                );
+            });
+
+            row(parent, |row| {
+                row.spawn(TextBundle::from_section(
+                    format!("{}:", glossary.race),
+                    text_style.clone(),
+                ));
+                for race in RaceName::iterator() {
+                    button(
+                        row,
+                        CreationButton::Race(*race),
+                        race.to_string_glossary(&full_glossary),
+                        &text_style,
+                    );
+                }
+            });
+
+            for ability in Ability::iterator() {
+                row(parent, |row| {
+                    button(
+                        row,
+                        CreationButton::Decrease(*ability),
+                        "-".to_string(),
+                        &text_style,
+                    );
+                    button(
+                        row,
+                        CreationButton::Select(*ability),
+                        ability.to_string_glossary(&full_glossary),
+                        &text_style,
+                    );
+                    row.spawn(TextBundle::from_section("", text_style.clone()))
+                        .insert(CreationText::Score(*ability));
+                    button(
+                        row,
+                        CreationButton::Increase(*ability),
+                        "+".to_string(),
+                        &text_style,
+                    );
+                    button(
+                        row,
+                        CreationButton::RacialBonus(*ability),
+                        glossary.racial_bonus.clone(),
+                        &text_style,
+                    );
+                });
+            }
+
+            parent
+                .spawn(TextBundle::from_section("", text_style.clone()))
+                .insert(CreationText::PointsLeft);
+            parent
+                .spawn(TextBundle::from_section("", text_style.clone()))
+                .insert(CreationText::Preview);
+
+            row(parent, |row| {
+                button(
+                    row,
+                    CreationButton::Return,
+                    glossary.back.clone(),
+                    &text_style,
+                );
+                button(
+                    row,
+                    CreationButton::Confirm,
+                    glossary.confirm.clone(),
+                    &text_style,
+                );
+            });
+        })
+        .insert(Name::new("UIRoot"))
+        .id();
+
+    commands.insert_resource(CharacterCreationSceneData {
+        user_interface_root,
+    });
+    commands.insert_resource(CharacterCreation::new(
+        GenerationMethod::PointBuy(20),
+        RaceName::default(),
+    ));
+}
+
+fn cleanup(mut commands: Commands, character_creation_scene_data: Res<CharacterCreationSceneData>) {
+    commands
+        .entity(character_creation_scene_data.user_interface_root)
+        .despawn_recursive();
+    commands.remove_resource::<CharacterCreation>();
+}
+
+fn row(parent: &mut ChildBuilder, spawn_children: impl FnOnce(&mut ChildBuilder)) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .with_children(spawn_children);
+}
+
+fn button(parent: &mut ChildBuilder, button: CreationButton, text: String, text_style: &TextStyle) {
+    parent
+        .spawn(ButtonBundle {
+            style: Style {
+                border: UiRect::all(Val::Px(3.0)),
+                padding: UiRect::all(Val::Px(5.0)),
+                margin: UiRect::all(Val::Px(5.0)),
+                ..Default::default()
+            },
+            background_color: Color::DARK_GRAY.into(),
+            border_color: Color::BLACK.into(),
+            ..Default::default()
+        })
+        .insert(button)
+        .insert(Name::new(format!("{:?} Button", button)))
+        .with_children(|parent| {
+            parent.spawn(TextBundle {
+                text: Text::from_section(text, text_style.clone())
+                    .with_alignment(TextAlignment::Center)
+                    .with_no_wrap(),
+                ..Default::default()
+            });
+        });
+}
+
+fn button_handle(
+    button_query: Query<(&Interaction, &CreationButton), Changed<Interaction>>,
+    mut creation: ResMut<CharacterCreation>,
+    mut profile: ResMut<Profile>,
+    mut state: ResMut<NextState<SceneState>>,
+    race_library: Res<RaceLibrary>,
+) {
+    let Some(race) = race_library.get(&creation.race) else {
+        return;
+    };
+    for (_, button) in button_query
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+    {
+        match *button {
+            CreationButton::Method(method) => {
+                if creation.method != method {
+                    creation.set_method(method);
+                }
+            }
+            CreationButton::Decrease(ability) => {
+                if creation.can_decrease(ability) {
+                    creation.scores[ability as usize] -= 1;
+                }
+            }
+            CreationButton::Increase(ability) => {
+                if creation.can_increase(ability) {
+                    creation.scores[ability as usize] += 1;
+                }
+            }
+            CreationButton::Select(ability) => {
+                if creation.method != GenerationMethod::Roll {
+                    continue;
+                }
+                match creation.selected.take() {
+                    Some(selected) => creation.scores.swap(selected as usize, ability as usize),
+                    None => creation.selected = Some(ability),
+                }
+            }
+            CreationButton::Reroll => {
+                if creation.method == GenerationMethod::Roll {
+                    creation.set_method(GenerationMethod::Roll);
+                }
+            }
+            CreationButton::Race(race_name) => {
+                creation.race = race_name;
+                if race_library
+                    .get(&race_name)
+                    .is_some_and(|race| race.floating_bonus.is_none())
+                {
+                    creation.racial_bonus = None;
+                }
+                // The buttons now refer to the new race.
+                return;
+            }
+            CreationButton::RacialBonus(ability) => {
+                if race.floating_bonus.is_some() {
+                    creation.racial_bonus = Some(ability);
+                }
+            }
+            CreationButton::Return => state.set(SceneState::HeroSelectScene),
+            CreationButton::Confirm => {
+                if !creation.is_complete(race) {
+                    continue;
+                }
+                profile.race = creation.race;
+                profile.attributes = Some(creation.attributes(race));
+                state.set(SceneState::PreClassicMode);
+            }
+        }
+    }
+}
+
+fn button_color_handle(
+    mut button_query: Query<(&Interaction, &CreationButton, &mut BackgroundColor)>,
+    creation: Res<CharacterCreation>,
+    race_library: Res<RaceLibrary>,
+) {
+    let Some(race) = race_library.get(&creation.race) else {
+        return;
+    };
+    let rolling = creation.method == GenerationMethod::Roll;
+    for (interaction, button, mut bg_color) in button_query.iter_mut() {
+        let (available, selected) = match *button {
+            CreationButton::Method(method) => (true, creation.method == method),
+            CreationButton::Decrease(ability) => (creation.can_decrease(ability), false),
+            CreationButton::Increase(ability) => (creation.can_increase(ability), false),
+            CreationButton::Select(ability) => (rolling, creation.selected == Some(ability)),
+            CreationButton::Reroll => (rolling, false),
+            CreationButton::Race(race_name) => (true, creation.race == race_name),
+            CreationButton::RacialBonus(ability) => (
+                race.floating_bonus.is_some(),
+                creation.racial_bonus == Some(ability),
+            ),
+            CreationButton::Confirm => (creation.is_complete(race), false),
+            CreationButton::Return => (true, false),
+        };
+        *bg_color = match (available, selected, interaction) {
+            (false, _, _) => Color::DARK_GRAY.into(),
+            (true, true, _) => Color::DARK_GREEN.into(),
+            (true, false, Interaction::Hovered) => Color::GRAY.into(),
+            (true, false, _) => Color::rgb(0.25, 0.25, 0.25).into(),
+        };
+    }
+}
+
+/// Shows the scores, the points left to spend, and a preview of the 1st level hero's statistics
+/// with those scores and race.
+fn update_text(
+    mut text_query: Query<(&CreationText, &mut Text)>,
+    creation: Res<CharacterCreation>,
+    profile: Res<Profile>,
+    progression: Res<ClassProgressionLibrary>,
+    race_library: Res<RaceLibrary>,
+    dictionary: Res<Dictionary>,
+) {
+    let glossary = dictionary.get_glossary().character_creation;
+    let Some(race) = race_library.get(&creation.race) else {
+        return;
+    };
+    let attributes = creation.attributes(race);
+    for (creation_text, mut text) in text_query.iter_mut() {
+        text.sections[0].value = match creation_text {
+            CreationText::Score(ability) => {
+                let score = attributes.score(*ability);
+                match race.adjustment(*ability, creation.racial_bonus) {
+                    0 => format!("{:>2} ({:+})", score, ability_bonus(score)),
+                    adjustment => format!(
+                        "{:>2} {:+} = {:>2} ({:+})",
+                        creation.scores[*ability as usize],
+                        adjustment,
+                        score,
+                        ability_bonus(score)
+                    ),
+                }
+            }
+            CreationText::PointsLeft => match creation.points_left() {
+                Some(points_left) => format!("{}: {}", glossary.points_left, points_left),
+                None => glossary.swap_hint.clone(),
+            },
+            CreationText::Preview => preview(&attributes, race, &profile, &progression, &glossary),
+        };
+    }
+}
+
+fn preview(
+    attributes: &AttributeBundle,
+    race: &Race,
+    profile: &Profile,
+    progression: &ClassProgressionLibrary,
+    glossary: &CharacterCreationText,
+) -> String {
+    let debug = false;
+    let class = &profile.hero_class;
+    let Some(entry) = progression.entry(class, 1) else {
+        if debug {
+            println!(
+                "debug | character_creation_scene::preview | {:?} has no 1st level progression entry",
+                class
+            );
+        }
+        return String::new();
+    };
+    let strength = attributes.bonus(Ability::Strength);
+    let dexterity = attributes.bonus(Ability::Dexterity);
+    let hit_points =
+        first_level_hit_points(class.hit_die(), attributes.bonus(Ability::Constitution));
+    let bab = entry.base_attack_bonus;
+    let special_size_modifier = race.size.special_modifier();
+    let size_modifier = race.size.size_modifier();
+
+    format!(
+        "{}: {}   {}: {}   {}: {:+}   {}: {}ft\n{}: {:+}   {}: {:+}   {}: {:+}\n{}: {:+}   {}: {:+}   {}: {}",
+        glossary.hit_points,
+        hit_points,
+        glossary.armor_class,
+        10 + dexterity + size_modifier,
+        glossary.initiative,
+        dexterity,
+        glossary.speed,
+        race.speed,
+        glossary.fortitude,
+        entry.fortitude + attributes.bonus(Ability::Constitution),
+        glossary.reflex,
+        entry.reflex + dexterity,
+        glossary.will,
+        entry.will + attributes.bonus(Ability::Wisdom),
+        glossary.melee_attack,
+        bab + strength + size_modifier,
+        glossary.combat_maneuver_bonus,
+        bab + strength + special_size_modifier,
+        glossary.combat_maneuver_defense,
+        10 + bab + strength + dexterity + special_size_modifier,
+    )
+}
@@ -24,6 +24,12 @@ pub enum ButtonComponent {
     MaleFighter,
     FemaleWizard,
     MaleWizard,
+    FemaleRogue,
+    MaleRogue,
+    FemaleBarbarian,
+    MaleBarbarian,
+    FemaleCleric,
+    MaleCleric,
 }
 
 impl ButtonComponent {
@@ -33,6 +39,12 @@ impl ButtonComponent {
             ButtonComponent::MaleFighter,
             ButtonComponent::FemaleWizard,
             ButtonComponent::MaleWizard,
+            ButtonComponent::FemaleRogue,
+            ButtonComponent::MaleRogue,
+            ButtonComponent::FemaleBarbarian,
+            ButtonComponent::MaleBarbarian,
+            ButtonComponent::FemaleCleric,
+            ButtonComponent::MaleCleric,
         ]
         .iter()
     }
@@ -53,6 +65,12 @@ enum HeroImageComponent {
     MaleWizard,
     FemaleFighter,
     FemaleWizard,
+    MaleRogue,
+    FemaleRogue,
+    MaleBarbarian,
+    FemaleBarbarian,
+    MaleCleric,
+    FemaleCleric,
 }
 
 #[derive(Resource)]
@@ -174,16 +192,17 @@ fn heroes_images(
     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
 ) {
     let mut index = 0;
-    let hero_image_positions: [[f32; 2]; 4] = [
-        [-75.0, 75.0],
-        [-250.0, 75.0],
-        [275.0, 75.0],
-        [100.0, 75.0],
-        // for more heroes
-        // [-250.0, -100.0],
-        // [-75.0, -100.0],
-        // [100.0, -100.0],
-        // [275.0, -100.0],
+    let hero_image_positions: [[f32; 2]; 10] = [
+        [-75.0, 110.0],
+        [-250.0, 110.0],
+        [275.0, 110.0],
+        [100.0, 110.0],
+        [-75.0, -40.0],
+        [-250.0, -40.0],
+        [275.0, -40.0],
+        [100.0, -40.0],
+        [100.0, -190.0],
+        [-75.0, -190.0],
     ];
 
     for hero_class in HeroClass::iterator() {
@@ -217,6 +236,42 @@ fn heroes_images(
                         component_name = format!("{}_{}", "Wizard", "Female");
                     }
                 },
+                HeroClass::Rogue => match gender {
+                    Gender::Male => {
+                        hero_tileset = ingame_materials.heroes_materials.male_rogue.clone();
+                        hero_image = HeroImageComponent::MaleRogue;
+                        component_name = format!("{}_{}", "Rogue", "Male");
+                    }
+                    Gender::Female => {
+                        hero_tileset = ingame_materials.heroes_materials.female_rogue.clone();
+                        hero_image = HeroImageComponent::FemaleRogue;
+                        component_name = format!("{}_{}", "Rogue", "Female");
+                    }
+                },
+                HeroClass::Barbarian => match gender {
+                    Gender::Male => {
+                        hero_tileset = ingame_materials.heroes_materials.male_barbarian.clone();
+                        hero_image = HeroImageComponent::MaleBarbarian;
+                        component_name = format!("{}_{}", "Barbarian", "Male");
+                    }
+                    Gender::Female => {
+                        hero_tileset = ingame_materials.heroes_materials.female_barbarian.clone();
+                        hero_image = HeroImageComponent::FemaleBarbarian;
+                        component_name = format!("{}_{}", "Barbarian", "Female");
+                    }
+                },
+                HeroClass::Cleric => match gender {
+                    Gender::Male => {
+                        hero_tileset = ingame_materials.heroes_materials.male_cleric.clone();
+                        hero_image = HeroImageComponent::MaleCleric;
+                        component_name = format!("{}_{}", "Cleric", "Male");
+                    }
+                    Gender::Female => {
+                        hero_tileset = ingame_materials.heroes_materials.female_cleric.clone();
+                        hero_image = HeroImageComponent::FemaleCleric;
+                        component_name = format!("{}_{}", "Cleric", "Female");
+                    }
+                },
             };
 
             let texture_atlas =
@@ -292,16 +347,17 @@ fn return_button(root: &mut ChildBuilder, scenes_materials: &ScenesMaterials) {
 }
 
 fn heroes_buttons(root: &mut ChildBuilder) {
-    let button_positions: [[f32; 2]; 4] = [
-        [210.0, 170.0],
-        [380.0, 170.0],
-        [560.0, 170.0],
-        [740.0, 170.0],
-        // for more heroes
-        // [210.0, 350.0],
-        // [380.0, 350.0],
-        // [560.0, 350.0],
-        // [740.0, 350.0],
+    let button_positions: [[f32; 2]; 10] = [
+        [210.0, 135.0],
+        [380.0, 135.0],
+        [560.0, 135.0],
+        [740.0, 135.0],
+        [210.0, 285.0],
+        [380.0, 285.0],
+        [560.0, 285.0],
+        [740.0, 285.0],
+        [380.0, 435.0],
+        [560.0, 435.0],
     ];
 
     for (index, value) in ButtonComponent::iterator().enumerate() {
@@ -309,6 +365,12 @@ fn heroes_buttons(root: &mut ChildBuilder) {
             1 => "MaleFighter",
             2 => "FemaleFighter",
             3 => "MaleWizard",
+            4 => "FemaleRogue",
+            5 => "MaleRogue",
+            6 => "FemaleBarbarian",
+            7 => "MaleBarbarian",
+            8 => "FemaleCleric",
+            9 => "MaleCleric",
             _ => "FemaleWizard",
         };
 
@@ -355,12 +417,30 @@ fn hero_select_handle(
                     ButtonComponent::FemaleWizard => {
                         animation_controller.hero_image = HeroImageComponent::FemaleWizard
                     }
+                    ButtonComponent::MaleRogue => {
+                        animation_controller.hero_image = HeroImageComponent::MaleRogue
+                    }
+                    ButtonComponent::FemaleRogue => {
+                        animation_controller.hero_image = HeroImageComponent::FemaleRogue
+                    }
+                    ButtonComponent::MaleBarbarian => {
+                        animation_controller.hero_image = HeroImageComponent::MaleBarbarian
+                    }
+                    ButtonComponent::FemaleBarbarian => {
+                        animation_controller.hero_image = HeroImageComponent::FemaleBarbarian
+                    }
+                    ButtonComponent::MaleCleric => {
+                        animation_controller.hero_image = HeroImageComponent::MaleCleric
+                    }
+                    ButtonComponent::FemaleCleric => {
+                        animation_controller.hero_image = HeroImageComponent::FemaleCleric
+                    }
                 };
             }
             Interaction::Pressed => {
                 profile.set_hero(button.clone());
                 if profile.game_mode == GameMode::ClassicMode {
-                    state.set(SceneState::PreClassicMode);
+                    state.set(SceneState::CharacterCreationScene);
                 } else {
                     // state.set(SceneState::PreSurvivalMode);
                 }
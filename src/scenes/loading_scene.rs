@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 
 use crate::config::*;
-use crate::resources::equipment::Armory;
+use crate::resources::equipment::{ArmorLibrary, Armory, WeaponPropertyLibrary};
 use crate::scenes::SceneState;
 
 use crate::materials::dungeon::DungeonMaterials;
@@ -14,11 +14,15 @@ use crate::materials::menu_box::MenuBoxMaterials;
 use crate::materials::monsters::MonstersMaterials;
 use crate::materials::scenes::ScenesMaterials;
 
+use crate::resources::class_progression::ClassProgressionLibrary;
 use crate::resources::dictionary::Dictionary;
 use crate::resources::dungeon::rooms::Rooms;
+use crate::resources::feat::FeatLibrary;
 use crate::resources::game_data::GameData;
 use crate::resources::language::Language;
 use crate::resources::monster::MonsterLibrary;
+use crate::resources::race::RaceLibrary;
+use crate::resources::spell::SpellLibrary;
 
 const LOADING_TEXT_FONT_SIZE: f32 = 30.0;
 const TEXT_FONT_SIZE: f32 = 40.0;
@@ -257,6 +261,12 @@ fn load_materials(mut commands: Commands, asset_server: Res<AssetServer>) {
             male_wizard: asset_server.load("scenes/heroes/male_wizard.png"),
             female_fighter: asset_server.load("scenes/heroes/female_fighter.png"),
             female_wizard: asset_server.load("scenes/heroes/female_wizard.png"),
+            male_rogue: asset_server.load("scenes/heroes/male_rogue.png"),
+            female_rogue: asset_server.load("scenes/heroes/female_rogue.png"),
+            male_barbarian: asset_server.load("scenes/heroes/male_barbarian.png"),
+            female_barbarian: asset_server.load("scenes/heroes/female_barbarian.png"),
+            male_cleric: asset_server.load("scenes/heroes/male_cleric.png"),
+            female_cleric: asset_server.load("scenes/heroes/female_cleric.png"),
         },
     };
 
@@ -266,6 +276,12 @@ fn load_materials(mut commands: Commands, asset_server: Res<AssetServer>) {
             male_wizard: asset_server.load("ingame/heroes/male_wizard.png"),
             female_fighter: asset_server.load("ingame/heroes/female_fighter.png"),
             female_wizard: asset_server.load("ingame/heroes/female_wizard.png"),
+            male_rogue: asset_server.load("ingame/heroes/male_rogue.png"),
+            female_rogue: asset_server.load("ingame/heroes/female_rogue.png"),
+            male_barbarian: asset_server.load("ingame/heroes/male_barbarian.png"),
+            female_barbarian: asset_server.load("ingame/heroes/female_barbarian.png"),
+            male_cleric: asset_server.load("ingame/heroes/male_cleric.png"),
+            female_cleric: asset_server.load("ingame/heroes/female_cleric.png"),
         },
         dungeon_materials: DungeonMaterials {
             floor: asset_server.load("ingame/dungeon/floor.png"),
@@ -294,6 +310,11 @@ fn load_materials(mut commands: Commands, asset_server: Res<AssetServer>) {
             door_top_part: asset_server.load("ingame/dungeon/door_top_part.png"),
             door_left_part: asset_server.load("ingame/dungeon/door_left_part.png"),
             door_right_part: asset_server.load("ingame/dungeon/door_right_part.png"),
+            rubble: asset_server.load("ingame/dungeon/rubble.png"),
+            shallow_water: asset_server.load("ingame/dungeon/shallow_water.png"),
+            pit: asset_server.load("ingame/dungeon/pit.png"),
+            pillar: asset_server.load("ingame/dungeon/pillar.png"),
+            low_wall: asset_server.load("ingame/dungeon/low_wall.png"),
         },
         map_ui: MapUiMaterials {
             grid_select_box: asset_server.load("ingame/map_ui/grid_select_box.png"),
@@ -313,4 +334,10 @@ fn load_data(mut commands: Commands) {
     commands.insert_resource(Rooms::new());
     commands.insert_resource(MonsterLibrary::new());
     commands.insert_resource(Armory::new());
+    commands.insert_resource(ArmorLibrary::new());
+    commands.insert_resource(WeaponPropertyLibrary::new());
+    commands.insert_resource(FeatLibrary::new());
+    commands.insert_resource(ClassProgressionLibrary::new());
+    commands.insert_resource(SpellLibrary::new());
+    commands.insert_resource(RaceLibrary::new());
 }
@@ -1,9 +1,11 @@
 use bevy::prelude::States;
 
+pub mod character_creation_scene;
 pub mod game_mode_select;
 pub mod hero_select_scene;
 pub mod loading_scene;
 pub mod main_menu_scene;
+pub mod victory_scene;
 
 #[derive(Debug, Clone, Default, Eq, PartialEq, Hash, States)]
 pub enum SceneState {
@@ -12,6 +14,8 @@ pub enum SceneState {
     MainMenuScene,
     GameModeSelectScene,
     HeroSelectScene,
+    CharacterCreationScene,
     PreClassicMode,
     InGameClassicMode,
+    VictoryScene,
 }
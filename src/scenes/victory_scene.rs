@@ -0,0 +1,241 @@
+use bevy::prelude::*;
+
+use crate::config::*;
+use crate::materials::font::FontMaterials;
+use crate::materials::menu_box::MenuBoxMaterials;
+use crate::materials::scenes::ScenesMaterials;
+use crate::resources::dictionary::Dictionary;
+use crate::resources::player::player_dungeon_stats::PlayerDungeonStats;
+use crate::resources::profile::Profile;
+use crate::scenes::SceneState;
+
+const FONT_SIZE: f32 = 35.0;
+
+const BOX_TILE_SIZE: f32 = 60.0;
+const BOX_WIDTH_TILES: f32 = 10.0;
+const BOX_HEIGHT_TILES: f32 = 7.0;
+
+const BOX_LEFT: f32 = (WINDOW_HEIGHT * RESOLUTION - BOX_TILE_SIZE * BOX_WIDTH_TILES) / 2.0;
+const BOX_TOP: f32 = (WINDOW_HEIGHT - BOX_TILE_SIZE * BOX_HEIGHT_TILES) / 2.0;
+
+#[derive(PartialEq, Component, Clone)]
+enum ButtonComponent {
+    MainMenu,
+}
+
+pub struct VictoryScenePlugin;
+
+#[derive(Resource)]
+struct VictorySceneData {
+    user_interface_root: Entity,
+}
+
+impl Plugin for VictoryScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(SceneState::VictoryScene), setup);
+        app.add_systems(
+            Update,
+            button_handle_system.run_if(in_state(SceneState::VictoryScene)),
+        );
+        app.add_systems(OnExit(SceneState::VictoryScene), cleanup);
+    }
+}
+
+/// Shows how far the player got in the run they just won.
+fn setup(
+    scenes_materials: Res<ScenesMaterials>,
+    font_materials: Res<FontMaterials>,
+    dictionary: Res<Dictionary>,
+    player_dungeon_stats: Option<Res<PlayerDungeonStats>>,
+    profile: Option<Res<Profile>>,
+    mut commands: Commands,
+) {
+    let user_interface_root = commands
+        .spawn(ImageBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..Default::default()
+            },
+            image: UiImage::new(scenes_materials.sub_background_image.clone()),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            menu_box(parent, &scenes_materials.menu_box_materials);
+            victory_texts(
+                parent,
+                &font_materials,
+                &dictionary,
+                player_dungeon_stats.as_deref(),
+                profile.as_deref(),
+            );
+            main_menu_button(parent, &font_materials, &dictionary);
+        })
+        .insert(Name::new("UIRoot"))
+        .id();
+
+    commands.insert_resource(VictorySceneData {
+        user_interface_root,
+    });
+}
+
+fn cleanup(mut commands: Commands, victory_scene_data: Res<VictorySceneData>) {
+    commands
+        .entity(victory_scene_data.user_interface_root)
+        .despawn_recursive();
+}
+
+fn menu_box(root: &mut ChildBuilder, menu_box_materials: &MenuBoxMaterials) {
+    let box_images = menu_box_materials
+        .build_box(BOX_WIDTH_TILES as usize, BOX_HEIGHT_TILES as usize)
+        .unwrap();
+
+    for (row_index, row) in box_images.iter().enumerate() {
+        for (column_index, image) in row.iter().enumerate() {
+            root.spawn(ImageBundle {
+                image: UiImage::new(image.clone()),
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(BOX_LEFT + BOX_TILE_SIZE * column_index as f32),
+                    top: Val::Px(BOX_TOP + BOX_TILE_SIZE * row_index as f32),
+                    bottom: Val::Auto,
+                    right: Val::Auto,
+                    width: Val::Px(BOX_TILE_SIZE),
+                    height: Val::Px(BOX_TILE_SIZE),
+                    ..Default::default()
+                },
+                ..Default::default()
+            });
+        }
+    }
+}
+
+fn victory_texts(
+    root: &mut ChildBuilder,
+    font_materials: &FontMaterials,
+    dictionary: &Dictionary,
+    player_dungeon_stats: Option<&PlayerDungeonStats>,
+    profile: Option<&Profile>,
+) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+    let victory_text = glossary.victory;
+
+    let (floors, rooms_cleared, monsters_defeated) =
+        player_dungeon_stats.map_or((0, 0, 0), |stats| {
+            (
+                stats.current_floor_index + 1,
+                stats.rooms_cleared,
+                stats.monsters_defeated,
+            )
+        });
+    let experience = profile.map_or(0, |profile| profile.experience);
+
+    root.spawn(TextBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(BOX_LEFT),
+            top: Val::Px(BOX_TOP + 40.0),
+            width: Val::Px(BOX_TILE_SIZE * BOX_WIDTH_TILES),
+            ..Default::default()
+        },
+        text: Text::from_section(
+            victory_text.title,
+            TextStyle {
+                font: font.clone(),
+                font_size: 50.0,
+                color: Color::BLACK,
+            },
+        )
+        .with_alignment(TextAlignment::Center),
+        ..Default::default()
+    });
+
+    let lines = [
+        (victory_text.floors, floors),
+        (victory_text.rooms_cleared, rooms_cleared),
+        (victory_text.monsters_defeated, monsters_defeated),
+        (victory_text.experience, experience),
+    ];
+    for (index, (label, value)) in lines.into_iter().enumerate() {
+        root.spawn(TextBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(BOX_LEFT + 80.0),
+                top: Val::Px(BOX_TOP + 120.0 + FONT_SIZE * index as f32),
+                ..Default::default()
+            },
+            text: Text::from_section(
+                format!("{}: {}", label, value),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: FONT_SIZE,
+                    color: Color::BLACK,
+                },
+            ),
+            ..Default::default()
+        });
+    }
+}
+
+fn main_menu_button(
+    root: &mut ChildBuilder,
+    font_materials: &FontMaterials,
+    dictionary: &Dictionary,
+) {
+    let font = font_materials.get_font(dictionary.get_current_language());
+    let glossary = dictionary.get_glossary();
+
+    root.spawn(ButtonBundle {
+        style: Style {
+            left: Val::Px((WINDOW_HEIGHT * RESOLUTION - 300.0) / 2.0),
+            top: Val::Px(BOX_TOP + BOX_TILE_SIZE * BOX_HEIGHT_TILES - 90.0),
+            right: Val::Auto,
+            bottom: Val::Auto,
+            width: Val::Px(300.0),
+            height: Val::Px(FONT_SIZE),
+            justify_content: JustifyContent::Center,
+            position_type: PositionType::Absolute,
+            ..Default::default()
+        },
+        background_color: BackgroundColor(Color::NONE),
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        parent.spawn(TextBundle {
+            text: Text::from_section(
+                glossary.victory.main_menu,
+                TextStyle {
+                    font,
+                    font_size: FONT_SIZE,
+                    color: Color::GRAY,
+                },
+            )
+            .with_alignment(TextAlignment::Center),
+            ..Default::default()
+        });
+    })
+    .insert(ButtonComponent::MainMenu);
+}
+
+fn button_handle_system(
+    mut button_query: Query<
+        (&Interaction, &ButtonComponent, &Children),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut text_query: Query<&mut Text>,
+    mut state: ResMut<NextState<SceneState>>,
+) {
+    for (interaction, button, children) in button_query.iter_mut() {
+        let mut text = text_query.get_mut(children[0]).unwrap();
+        match *interaction {
+            Interaction::None => text.sections[0].style.color = Color::GRAY,
+            Interaction::Hovered => text.sections[0].style.color = Color::BLACK,
+            Interaction::Pressed => {
+                if *button == ButtonComponent::MainMenu {
+                    state.set(SceneState::MainMenuScene);
+                }
+            }
+        }
+    }
+}